@@ -0,0 +1,352 @@
+use futures_util::{stream, StreamExt, TryStreamExt};
+use log::{debug, warn};
+use strum::IntoEnumIterator;
+
+#[cfg(feature = "royalroad")]
+use crate::backends::RoyalRoad;
+use crate::backends::{single_volume, Backend, BackendError, Backends, ChapterListElem};
+use crate::utils::{ClientConfig, HttpClient};
+use crate::Chapter;
+
+/// Maximum number of chapters downloaded at the same time by
+/// [`AsyncBackend::get_chapters_async`].
+const MAX_CONCURRENT_ASYNC_CHAPTER_DOWNLOADS: usize = 8;
+
+/// Asynchronous counterpart of [`Backend`], fetching pages with `reqwest`'s
+/// async client instead of blocking the current thread. Everything that does
+/// not need a request (title, authors…) is still read through [`Backend`].
+///
+/// Only [`RoyalRoad`][crate::backends::RoyalRoad] implements it for now:
+/// building a [`Backends`] for any other site with
+/// [`AsyncBackend::new_async`] returns [`BackendError::Unsupported`]. Methods
+/// read through [`Backend`] are blocking, and must not be called from async
+/// code when they send requests.
+///
+/// ```rust,no_run
+/// use libwebnovel::{AsyncBackend, Backend, Backends};
+/// # async fn run() {
+/// let backend = Backends::new_async("https://www.royalroad.com/fiction/21220/mother-of-learning")
+///     .await
+///     .unwrap();
+/// let chapters = backend.get_chapters_async().await.unwrap();
+/// assert_eq!(chapters.len(), backend.get_chapter_count().unwrap());
+/// # }
+/// ```
+// The backends hold parsed pages, which cannot be sent across threads anyway
+#[allow(async_fn_in_trait)]
+pub trait AsyncBackend: Backend {
+    /// Async counterpart of [`Backend::new`].
+    async fn new_async(url: &str) -> Result<Self, BackendError>
+    where
+        Self: Sized;
+
+    /// Async counterpart of [`Backend::new_with_config`]: every request,
+    /// async or not, is sent with a client configured by `config`. The
+    /// default implementation calls [`AsyncBackend::new_async`] for the
+    /// default configuration, and returns [`BackendError::Unsupported`] for
+    /// any other one.
+    async fn new_async_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError>
+    where
+        Self: Sized,
+    {
+        if *config != ClientConfig::default() {
+            return Err(BackendError::Unsupported(
+                "custom HTTP client configurations".to_string(),
+            ));
+        }
+        Self::new_async(url).await
+    }
+
+    /// Async counterpart of [`Backend::get_chapter_list`]. The default
+    /// implementation returns the (synchronous) chapter list, which is enough
+    /// for backends reading it from pages fetched when they are built.
+    async fn get_chapter_list_async(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+        self.get_chapter_list()
+    }
+
+    /// Async counterpart of [`Backend::get_chapter`].
+    async fn get_chapter_async(&self, chapter_number: usize) -> Result<Chapter, BackendError>;
+
+    /// Async counterpart of [`Backend::cover`].
+    async fn cover_async(&self) -> Result<Option<Vec<u8>>, BackendError> {
+        match self.cover_url() {
+            Ok(cover_url) => download_cover_async(self.http_client(), &cover_url).await,
+            Err(BackendError::ParseError(..)) | Err(BackendError::UrlNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Async counterpart of [`Backend::get_chapters`]: chapters are fetched
+    /// concurrently, a few at a time, and returned in the order of their
+    /// indices. Non-story chapters are told apart from their title in
+    /// [`AsyncBackend::get_chapter_list_async`], without any blocking request.
+    async fn get_chapters_async(&self) -> Result<Vec<Chapter>, BackendError> {
+        let chapter_list = self.get_chapter_list_async().await?;
+        self.backend_options()
+            .check_chapter_count(chapter_list.len())?;
+        let skip_non_story_chapters = self.backend_options().skip_non_story_chapters;
+        let indices: Vec<usize> = single_volume(chapter_list)
+            .chapters()
+            .iter()
+            .filter(|info| {
+                let keep = !skip_non_story_chapters || self.is_story_chapter(info);
+                if !keep {
+                    debug!(
+                        "Skipping chapter {} ({:?}), not part of the story",
+                        info.index(),
+                        info.title()
+                    );
+                }
+                keep
+            })
+            .map(|info| *info.index())
+            .collect();
+        let mut chapters: Vec<Chapter> = stream::iter(indices)
+            .map(|i| self.get_chapter_async(i))
+            .buffer_unordered(MAX_CONCURRENT_ASYNC_CHAPTER_DOWNLOADS)
+            .try_collect()
+            .await?;
+        chapters.sort_by_key(|chapter| *chapter.index());
+        Ok(chapters)
+    }
+}
+
+/// Async counterpart of `download_cover`.
+async fn download_cover_async(
+    client: &HttpClient,
+    cover_url: &str,
+) -> Result<Option<Vec<u8>>, BackendError> {
+    match client.get_async(cover_url).await {
+        Ok(resp) => Ok(Some(resp.bytes().await?.to_vec())),
+        Err(BackendError::UrlNotFound) => {
+            warn!("The cover URL {cover_url} is dead, ignoring the cover");
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// The error returned for backends without async support.
+fn unsupported(backend_name: &str) -> BackendError {
+    BackendError::Unsupported(format!("async requests with the {backend_name} backend"))
+}
+
+impl AsyncBackend for Backends {
+    /// Builds the backend matching the given URL, like [`Backends::new`].
+    /// Returns [`BackendError::Unsupported`] if that backend does not support
+    /// async requests.
+    async fn new_async(url: &str) -> Result<Self, BackendError> {
+        Self::new_async_with_config(url, &ClientConfig::default()).await
+    }
+
+    /// Builds the backend matching the given URL, like
+    /// [`Backends::new_with_config`]. Returns [`BackendError::Unsupported`] if
+    /// that backend does not support async requests.
+    async fn new_async_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        for backend_variant in Backends::iter() {
+            if !backend_variant
+                .get_backend_regexps()
+                .iter()
+                .any(|regex| regex.is_match(url))
+            {
+                continue;
+            }
+            return match backend_variant {
+                #[cfg(feature = "royalroad")]
+                Backends::RoyalRoad(_) => Ok(Self::RoyalRoad(Box::new(
+                    RoyalRoad::new_async_with_config(url, config).await?,
+                ))),
+                #[allow(unreachable_patterns)]
+                backend => Err(unsupported(backend.get_backend_name())),
+            };
+        }
+        Err(BackendError::NoMatchingBackendFound(url.to_string()))
+    }
+
+    async fn get_chapter_list_async(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapter_list_async().await,
+            #[allow(unreachable_patterns)]
+            backend => Err(unsupported(backend.get_backend_name())),
+        }
+    }
+
+    async fn get_chapter_async(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapter_async(chapter_number).await,
+            #[allow(unreachable_patterns)]
+            backend => Err(unsupported(backend.get_backend_name())),
+        }
+    }
+
+    async fn cover_async(&self) -> Result<Option<Vec<u8>>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.cover_async().await,
+            #[allow(unreachable_patterns)]
+            backend => Err(unsupported(backend.get_backend_name())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use regex::Regex;
+
+    use crate::backends::async_backend::AsyncBackend;
+    use crate::backends::{Backend, BackendError, BackendOptions, Backends, ChapterListElem};
+    use crate::test_support::MockServer;
+    use crate::Chapter;
+
+    /// A backend whose chapters are the pages `/chapter-{index}` of a server.
+    #[derive(Debug, Default)]
+    struct ServedBackend {
+        url: String,
+        chapter_count: usize,
+        options: BackendOptions,
+    }
+
+    impl Backend for ServedBackend {
+        fn get_backend_regexps() -> Vec<Regex> {
+            Vec::new()
+        }
+
+        fn get_backend_name() -> &'static str {
+            "served"
+        }
+
+        fn new(url: &str) -> Result<Self, BackendError> {
+            Err(BackendError::NoMatchingBackendFound(url.to_string()))
+        }
+
+        fn backend_options(&self) -> &BackendOptions {
+            &self.options
+        }
+
+        fn set_backend_options(&mut self, options: BackendOptions) {
+            self.options = options;
+        }
+
+        fn title(&self) -> Result<String, BackendError> {
+            Ok("Served".to_string())
+        }
+
+        fn immutable_identifier(&self) -> Result<String, BackendError> {
+            Ok("served".to_string())
+        }
+
+        fn url(&self) -> String {
+            self.url.clone()
+        }
+
+        fn cover_url(&self) -> Result<String, BackendError> {
+            Ok(format!("{}/cover.png", self.url))
+        }
+
+        fn get_authors(&self) -> Result<Vec<String>, BackendError> {
+            Ok(Vec::new())
+        }
+
+        fn get_chapter_list(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+            Ok((1..=self.chapter_count)
+                .map(|index| (index, format!("Chapter {index}")))
+                .collect())
+        }
+
+        fn get_chapter_count(&self) -> Result<usize, BackendError> {
+            Ok(self.chapter_count)
+        }
+
+        fn get_chapter(&self, _: usize) -> Result<Chapter, BackendError> {
+            unimplemented!("only fetched asynchronously")
+        }
+    }
+
+    impl AsyncBackend for ServedBackend {
+        async fn new_async(url: &str) -> Result<Self, BackendError> {
+            Self::new(url)
+        }
+
+        async fn get_chapter_async(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
+            let url = format!("{}/chapter-{chapter_number}", self.url);
+            let content = self.http_client().get_async(&url).await?.text().await?;
+            let mut chapter = Chapter::default();
+            chapter.set_index(chapter_number);
+            chapter.set_chapter_url(url);
+            chapter.set_content_raw(&content);
+            Ok(chapter)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_chapters_async() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server = MockServer::start_with_handler({
+            let requests = Arc::clone(&requests);
+            move |path, _| {
+                requests.fetch_add(1, Ordering::Relaxed);
+                let index = path
+                    .strip_prefix("/chapter-")
+                    .map(|index| index.parse().unwrap());
+                match index {
+                    Some(index @ 1..=4) => (200, format!("<p>Chapter {index}</p>")),
+                    None if path == "/cover.png" => (200, "png".to_string()),
+                    _ => (404, "not found".to_string()),
+                }
+            }
+        });
+        let backend = ServedBackend {
+            url: server.url(""),
+            chapter_count: 4,
+            ..Default::default()
+        };
+        let chapters = backend.get_chapters_async().await.unwrap();
+        let indices: Vec<usize> = chapters.iter().map(|chapter| *chapter.index()).collect();
+        assert_eq!(indices, vec![1, 2, 3, 4]);
+        assert_eq!(chapters[2].content(), "<p>Chapter 3</p>");
+        assert_eq!(requests.load(Ordering::Relaxed), 4);
+        assert_eq!(
+            backend.get_chapter_list_async().await.unwrap(),
+            backend.get_chapter_list().unwrap()
+        );
+        assert_eq!(backend.cover_async().await.unwrap(), Some(b"png".to_vec()));
+
+        // a missing chapter fails the whole download
+        let backend = ServedBackend {
+            chapter_count: 5,
+            ..backend
+        };
+        assert!(matches!(
+            backend.get_chapters_async().await,
+            Err(BackendError::UrlNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backends_new_async() {
+        assert!(matches!(
+            Backends::new_async("https://example.com/fiction/1").await,
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+        #[cfg(feature = "lightnovelworld")]
+        assert!(matches!(
+            Backends::new_async("https://www.lightnovelworld.com/novel/the-perfect-run-24071713")
+                .await,
+            Err(BackendError::Unsupported(_))
+        ));
+    }
+}
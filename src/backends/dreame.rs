@@ -0,0 +1,454 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::backends::{
+    Backend, BackendCapabilities, BackendError, BackendOptions, ChapterListElem,
+    AVERAGE_BYTES_PER_WORD, BACKEND_NAME_METADATA_KEY,
+};
+use crate::json::{ChapterJsonFields, ContentFormat};
+use crate::utils::{ClientConfig, HttpClient};
+use crate::{Chapter, ChapterInfo};
+
+/// Base URL of Dreame's JSON API, used by its mobile apps.
+const DREAME_API_URL: &str = "https://api.dreame.com";
+
+/// Headers sent with every API request. The API only answers requests
+/// identifying themselves as coming from the app (`app-id`); the other ones
+/// get a non-zero `code` in the response envelope. `language` selects the
+/// English catalog.
+const DREAME_API_HEADERS: &[(&str, &str)] = &[
+    ("app-id", "dreame"),
+    ("platform", "android"),
+    ("language", "en"),
+    ("accept", "application/json"),
+];
+
+/// Used to identify a fiction URL & extract the book ID from it
+static DREAME_FICTION_URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://(?:www\.|m\.)?dreame\.com/(?:novel|book)/(?<book_id>\d+)").unwrap()
+});
+
+/// Where the fields of a chapter are in the `data` of the chapter content
+/// endpoint.
+static CHAPTER_JSON_FIELDS: LazyLock<ChapterJsonFields> = LazyLock::new(|| ChapterJsonFields {
+    title: Some("/chapterName".to_string()),
+    content: "/content".to_string(),
+    content_format: ContentFormat::PlainText,
+    published_at: Some("/publishTime".to_string()),
+});
+
+/// A chapter, as listed by the chapter list endpoint.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListedChapter {
+    chapter_id: u64,
+    chapter_name: String,
+    /// UNIX timestamp (in seconds)
+    #[serde(default)]
+    publish_time: Option<i64>,
+    /// Whether the chapter must be bought (or unlocked with coins) to be
+    /// read
+    #[serde(default)]
+    is_locked: bool,
+    /// Not listed for every chapter
+    #[serde(default)]
+    word_count: Option<u64>,
+}
+
+/// A backend for [Dreame](https://www.dreame.com), a mobile-first platform.
+/// Instead of scraping pages, it uses the JSON API of Dreame's apps. That API
+/// only answers requests carrying the app-specific `app-id: dreame` header
+/// (along with `platform` & `language`), which this backend always sends:
+///
+/// - `GET /v1/book/{book_id}`: the fiction's title, author & cover;
+/// - `GET /v1/book/{book_id}/chapters`: the chapter list, with locked chapters
+///   flagged;
+/// - `GET /v1/book/{book_id}/chapters/{chapter_id}`: the content of a chapter,
+///   as plain text.
+///
+/// Every response is wrapped in a `{"code": 0, "msg": "…", "data": {…}}`
+/// envelope, a non-zero `code` signaling an error.
+///
+/// Locked chapters are reported by [`Backend::get_chapter_info`], and
+/// [`Backend::get_chapter`] returns [`BackendError::LockedChapter`] for them.
+#[derive(Debug, Default, Clone)]
+pub struct Dreame {
+    url: String,
+    api_url: String,
+    book_id: String,
+    /// `data` of the book endpoint
+    book: Value,
+    chapters: Vec<ListedChapter>,
+    options: BackendOptions,
+    client: HttpClient,
+}
+
+impl Dreame {
+    /// Builds the backend for the fiction at `url`, using the API at `api_url`
+    /// with the given client.
+    fn new_with_api_url(
+        url: &str,
+        api_url: &str,
+        client: HttpClient,
+    ) -> Result<Self, BackendError> {
+        let book_id = DREAME_FICTION_URL_REGEX
+            .captures(url)
+            .ok_or_else(|| BackendError::NoMatchingBackendFound(url.to_string()))?["book_id"]
+            .to_string();
+        let book = api_get(&client, &format!("{api_url}/v1/book/{book_id}"))?;
+        let chapters = api_get(&client, &format!("{api_url}/v1/book/{book_id}/chapters"))?
+            .get_mut("chapters")
+            .map(Value::take)
+            .ok_or_else(|| {
+                BackendError::ParseError(
+                    format!("Could not find the chapter list of book {book_id}"),
+                    None,
+                )
+            })?;
+        let chapters = serde_json::from_value(chapters).map_err(|e| {
+            BackendError::parse_error_with_source(
+                format!("Could not parse the chapter list of book {book_id}"),
+                e,
+            )
+        })?;
+        Ok(Self {
+            url: url.to_string(),
+            api_url: api_url.to_string(),
+            book_id,
+            book,
+            chapters,
+            options: BackendOptions::default(),
+            client,
+        })
+    }
+
+    /// Returns the string at `pointer` in the book's data.
+    fn book_field(&self, pointer: &str) -> Result<String, BackendError> {
+        self.book
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                BackendError::ParseError(
+                    format!(
+                        "Could not find {pointer} in the data of book {}",
+                        self.book_id
+                    ),
+                    None,
+                )
+            })
+    }
+
+    /// Returns the listed chapter with the given index.
+    fn listed_chapter(&self, chapter_number: usize) -> Result<&ListedChapter, BackendError> {
+        chapter_number
+            .checked_sub(1)
+            .and_then(|i| self.chapters.get(i))
+            .ok_or(BackendError::UnknownChapter(chapter_number))
+    }
+
+    /// URL of the content of the given chapter in the API.
+    fn chapter_url(&self, chapter: &ListedChapter) -> String {
+        format!(
+            "{}/v1/book/{}/chapters/{}",
+            self.api_url, self.book_id, chapter.chapter_id
+        )
+    }
+}
+
+/// Fetches the given API URL with `client` & returns the `data` of the
+/// response, or an error if the response's `code` is not `0`.
+fn api_get(client: &HttpClient, url: &str) -> Result<Value, BackendError> {
+    let resp = client.get_with_headers(url, DREAME_API_HEADERS)?;
+    if !resp.status().is_success() {
+        return Err(BackendError::RequestFailed {
+            message: format!("Could not get API URL {url}"),
+            status: resp.status(),
+            content: resp.text()?,
+        });
+    }
+    let mut json: Value = serde_json::from_str(&resp.text()?).map_err(|e| {
+        BackendError::parse_error_with_source(format!("Invalid JSON returned by {url}"), e)
+    })?;
+    match json.get("code").and_then(Value::as_i64) {
+        Some(0) => json
+            .get_mut("data")
+            .map(Value::take)
+            .ok_or_else(|| BackendError::ParseError(format!("No data returned by {url}"), None)),
+        code => Err(BackendError::ParseError(
+            format!(
+                "The API returned error {code:?} for {url}: {}",
+                json.get("msg").and_then(Value::as_str).unwrap_or_default()
+            ),
+            None,
+        )),
+    }
+}
+
+impl Backend for Dreame {
+    fn get_backend_regexps() -> Vec<Regex> {
+        vec![DREAME_FICTION_URL_REGEX.clone()]
+    }
+
+    fn get_backend_name() -> &'static str {
+        "dreame"
+    }
+
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities {
+            has_cover: true,
+            has_dates: true,
+            ..Default::default()
+        }
+    }
+
+    fn new(url: &str) -> Result<Self, BackendError> {
+        Self::new_with_config(url, &ClientConfig::default())
+    }
+
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        Self::new_with_api_url(url, DREAME_API_URL, config.build()?)
+    }
+
+    fn http_client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        &self.options
+    }
+
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        self.options = options;
+    }
+
+    fn title(&self) -> Result<String, BackendError> {
+        self.book_field("/bookName")
+    }
+
+    /// Returns the book ID, as found in the fiction URL.
+    fn immutable_identifier(&self) -> Result<String, BackendError> {
+        Ok(self.book_id.clone())
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn site_icon_url(&self) -> String {
+        "https://www.dreame.com/favicon.ico".to_string()
+    }
+
+    /// The language of the catalog requested from the API.
+    fn declared_language(&self) -> Option<String> {
+        Some("en".to_string())
+    }
+
+    fn cover_url(&self) -> Result<String, BackendError> {
+        self.book_field("/cover")
+    }
+
+    fn get_authors(&self) -> Result<Vec<String>, BackendError> {
+        Ok(vec![self.book_field("/authorName")?])
+    }
+
+    fn get_chapter_list(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+        Ok(self
+            .chapters
+            .iter()
+            .enumerate()
+            .map(|(i, chapter)| (i + 1, chapter.chapter_name.clone()))
+            .collect())
+    }
+
+    fn get_chapter_info(&self, chapter_number: usize) -> Result<ChapterInfo, BackendError> {
+        let listed = self.listed_chapter(chapter_number)?;
+        let mut info = ChapterInfo::default();
+        info.set_index(chapter_number);
+        info.set_title(Some(listed.chapter_name.clone()));
+        info.set_chapter_url(Some(self.chapter_url(listed)));
+        info.set_published_at(
+            listed
+                .publish_time
+                .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0)),
+        );
+        info.set_is_locked(listed.is_locked);
+        Ok(info)
+    }
+
+    fn get_chapter_count(&self) -> Result<usize, BackendError> {
+        Ok(self.chapters.len())
+    }
+
+    /// Estimated from the word counts of the chapter list, chapters without
+    /// one being assumed to be of average length.
+    fn estimated_total_bytes(&self) -> Result<Option<u64>, BackendError> {
+        let word_counts: Vec<u64> = self
+            .chapters
+            .iter()
+            .filter_map(|chapter| chapter.word_count)
+            .collect();
+        if word_counts.is_empty() {
+            return Ok(None);
+        }
+        let average = word_counts.iter().sum::<u64>() / word_counts.len() as u64;
+        Ok(Some(
+            average * self.chapters.len() as u64 * AVERAGE_BYTES_PER_WORD,
+        ))
+    }
+
+    fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
+        let listed = self.listed_chapter(chapter_number)?;
+        if listed.is_locked {
+            return Err(BackendError::LockedChapter(chapter_number));
+        }
+        let url = self.chapter_url(listed);
+        let data = api_get(&self.client, &url)?;
+        // chapters may have been locked since the chapter list was fetched
+        if data.get("isLocked").and_then(Value::as_bool) == Some(true) {
+            return Err(BackendError::LockedChapter(chapter_number));
+        }
+        let mut chapter = Chapter::from_json_fields(&data, &CHAPTER_JSON_FIELDS).map_err(|e| {
+            BackendError::parse_error_with_source(format!("Could not parse chapter {url}"), e)
+        })?;
+        chapter.set_index(chapter_number);
+        chapter.set_chapter_url(url);
+        chapter.set_fiction_url(self.url.clone());
+        chapter.set_raw_content(
+            data.get("content")
+                .and_then(Value::as_str)
+                .and_then(|content| self.options.raw_content(content)),
+        );
+        chapter.add_metadata(BACKEND_NAME_METADATA_KEY, Self::get_backend_name());
+        self.options.clean_chapter(&mut chapter);
+        self.options
+            .check_chapter(&chapter, Self::capabilities().has_dates)?;
+        Ok(chapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use crate::backends::dreame::Dreame;
+    use crate::backends::{BackendError, AVERAGE_BYTES_PER_WORD, BACKEND_NAME_METADATA_KEY};
+    use crate::test_support::MockServer;
+    use crate::utils::HttpClient;
+    use crate::Backend;
+
+    const TEST_URL: &str = "https://www.dreame.com/novel/1234567/the-alphas-runaway-mate";
+
+    /// Serves the recorded API responses, rejecting requests without the
+    /// app-specific header like the actual API does.
+    fn fixture_server() -> MockServer {
+        MockServer::start_with_handler(|path, headers| {
+            if headers.get("app-id").map(String::as_str) != Some("dreame") {
+                return (
+                    200,
+                    r#"{"code": 401, "msg": "missing app-id", "data": null}"#.to_string(),
+                );
+            }
+            let body = match path {
+                "/v1/book/1234567" => include_str!("../../ressources/dreame/book.json"),
+                "/v1/book/1234567/chapters" => {
+                    include_str!("../../ressources/dreame/chapters.json")
+                }
+                "/v1/book/1234567/chapters/9000001" => {
+                    include_str!("../../ressources/dreame/chapter.json")
+                }
+                "/v1/book/1234567/chapters/9000002" => {
+                    include_str!("../../ressources/dreame/chapter_locked.json")
+                }
+                _ => return (404, "not found".to_string()),
+            };
+            (200, body.to_string())
+        })
+    }
+
+    #[test]
+    fn test_dreame() {
+        let server = fixture_server();
+        let backend =
+            Dreame::new_with_api_url(TEST_URL, &server.url(""), HttpClient::default()).unwrap();
+        assert_eq!(backend.title().unwrap(), "The Alpha's Runaway Mate");
+        assert_eq!(backend.get_authors().unwrap(), vec!["Luna Grey"]);
+        assert_eq!(
+            backend.cover_url().unwrap(),
+            "https://cdn.dreame.com/covers/1234567.jpg"
+        );
+        assert_eq!(backend.immutable_identifier().unwrap(), "1234567");
+        // chapter 3 has no word count: it is assumed to be of average length
+        assert_eq!(
+            backend.estimated_total_bytes().unwrap(),
+            Some(1500 * 3 * AVERAGE_BYTES_PER_WORD)
+        );
+        assert_eq!(
+            backend.get_chapter_list().unwrap(),
+            vec![
+                (1, "Chapter 1: The Escape".to_string()),
+                (2, "Chapter 2: Found".to_string()),
+                (3, "Chapter 3: The Deal".to_string()),
+            ]
+        );
+
+        let chapter = backend.get_chapter(1).unwrap();
+        assert_eq!(chapter.title(), &Some("Chapter 1: The Escape".to_string()));
+        assert_eq!(
+            chapter.content(),
+            "<p>The night was cold.<br>She ran.</p>\n<p>\"Stop!\" he shouted.</p>"
+        );
+        assert_eq!(
+            chapter.published_at(),
+            &DateTime::from_timestamp(1700000000, 0)
+        );
+        assert_eq!(chapter.fiction_url(), TEST_URL);
+        assert_eq!(
+            chapter.metadata().get(BACKEND_NAME_METADATA_KEY),
+            Some(&"dreame".to_string())
+        );
+    }
+
+    #[test]
+    fn test_dreame_locked_chapters() {
+        let server = fixture_server();
+        let backend =
+            Dreame::new_with_api_url(TEST_URL, &server.url(""), HttpClient::default()).unwrap();
+        let locked: Vec<bool> = (1..=3)
+            .map(|i| backend.get_chapter_info(i).unwrap().is_locked())
+            .collect();
+        assert_eq!(locked, vec![false, false, true]);
+        assert!(matches!(
+            backend.get_chapter(3),
+            Err(BackendError::LockedChapter(3))
+        ));
+        // listed as free, but locked by the time it is fetched
+        assert!(matches!(
+            backend.get_chapter(2),
+            Err(BackendError::LockedChapter(2))
+        ));
+        assert!(matches!(
+            backend.get_chapter(4),
+            Err(BackendError::UnknownChapter(4))
+        ));
+    }
+
+    #[test]
+    fn test_dreame_api_errors() {
+        let server = MockServer::start(&[(
+            "/v1/book/1234567",
+            200,
+            r#"{"code": 401, "msg": "missing app-id", "data": null}"#,
+        )]);
+        let error =
+            Dreame::new_with_api_url(TEST_URL, &server.url(""), HttpClient::default()).unwrap_err();
+        assert!(error.to_string().contains("missing app-id"));
+        assert!(matches!(
+            Dreame::new("https://www.dreame.com/genre/romance"),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+    }
+}
@@ -1,15 +1,20 @@
 use std::fmt::{Debug, Formatter};
 use std::sync::LazyLock;
 
-use html_escape::decode_html_entities;
-use log::debug;
+use chrono::{DateTime, Utc};
+use html_escape::{decode_html_entities, encode_text};
+use log::{debug, warn};
 use regex::Regex;
 use reqwest::IntoUrl;
 use scraper::{Html, Selector};
 
-use crate::backends::{BackendError, ChapterListElem, ChapterOrderingFn};
-use crate::utils::get;
-use crate::{Backend, Chapter};
+use crate::backends::{
+    check_not_redirected_home, ensure_unique_chapter_urls, BackendCapabilities, BackendError,
+    BackendOptions, ChapterListElem, ChapterOrderingFn, ChapterUrlParts, FictionStatus,
+    BACKEND_NAME_METADATA_KEY,
+};
+use crate::utils::{declared_language, parse_date, slugify, ClientConfig, HttpClient};
+use crate::{Backend, Chapter, ChapterInfo};
 
 pub(crate) static TITLE_SELECTOR: LazyLock<Selector> =
     LazyLock::new(|| Selector::parse("h1.tit").unwrap());
@@ -21,13 +26,64 @@ pub(crate) static CHAPTER_TITLE_SELECTOR: LazyLock<Selector> =
     LazyLock::new(|| Selector::parse("div.top span.chapter").unwrap());
 pub(crate) static CHAPTER_CONTENT_SELECTOR: LazyLock<Selector> =
     LazyLock::new(|| Selector::parse("div.txt div#article").unwrap());
+/// The publication date of a chapter, when the page has a machine-readable
+/// one
+static CHAPTER_TIME_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("time[datetime]").unwrap());
+/// The info block of a chapter page, above its content
+static CHAPTER_INFO_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("div.top").unwrap());
+/// Finds the publication date in the text of the chapter info block, such as
+/// "Updated: 2024-03-01 12:30"
+static CHAPTER_DATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:updated|published|posted)\s*:?\s*(?<date>\d{4}-\d{2}-\d{2}(?:[ T]\d{2}:\d{2}(?::\d{2})?)?)",
+    )
+    .unwrap()
+});
 pub(crate) static FICTION_COVER_IMAGE_URL_SELECTOR: LazyLock<Selector> =
     LazyLock::new(|| Selector::parse("meta[property='og:image']").unwrap());
+/// Matches chapter URLs, such as
+/// `https://freewebnovel.com/the-guide-to-conquering-earthlings/chapter-1`
+static CHAPTER_URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^https?://freewebnovel\.com/(?<novel_slug>[\w-]+)/chapter-(?<chapter_number>\d+)/?$",
+    )
+    .unwrap()
+});
+/// The genre links of the info panel, such as `/genre/Fantasy`
+static GENRE_LINK_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("div.m-imgtxt a[href^='/genre']").unwrap());
+/// The rows of the info panel, each made of an icon titled after what the
+/// row is about ("Author", "Status"…) and of its value
+static INFO_ROW_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("div.m-imgtxt div.item").unwrap());
+static STATUS_ICON_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("span[title='Status']").unwrap());
+static INFO_ROW_VALUE_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("div.right").unwrap());
+/// The synopsis of the fiction, below the info panel
+static DESCRIPTION_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("div.txt div.inner").unwrap());
+/// The description of the page, a shorter version of the synopsis
+static META_DESCRIPTION_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("meta[name='description']").unwrap());
+/// The info panel of the fiction page, next to the cover
+pub(crate) static INFO_PANEL_SELECTOR: LazyLock<Selector> =
+    LazyLock::new(|| Selector::parse("div.m-imgtxt div.txt").unwrap());
+/// Finds the total chapter count stated in the info panel, either as "60
+/// Chapters" or "Chapters: 60"
+static STATED_CHAPTER_COUNT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:(?<before>\d+)\s+chapters\b|\bchapters?\s*:?\s*(?<after>\d+))").unwrap()
+});
 
 /// An implementation of backend for [FreeWebNovel](https://freewebnovel.com)
+#[derive(Clone)]
 pub struct FreeWebNovel {
     url: String,
     page: Html,
+    options: BackendOptions,
+    client: HttpClient,
 }
 
 #[allow(unused_variables, dead_code)]
@@ -37,7 +93,12 @@ impl Debug for FreeWebNovel {
         struct FreeWebNovel<'a> {
             url: &'a String,
         }
-        let Self { url, page: _ } = self;
+        let Self {
+            url,
+            page: _,
+            options: _,
+            client: _,
+        } = self;
         Debug::fmt(&FreeWebNovel { url }, f)
     }
 }
@@ -46,6 +107,8 @@ impl Default for FreeWebNovel {
         Self {
             url: "".to_string(),
             page: Html::new_document(),
+            options: BackendOptions::default(),
+            client: HttpClient::default(),
         }
     }
 }
@@ -68,6 +131,16 @@ impl Backend for FreeWebNovel {
         "freewebnovel"
     }
 
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities {
+            has_cover: true,
+            has_dates: false,
+            supports_description: true,
+            supports_tags: true,
+            ..Default::default()
+        }
+    }
+
     /// returns a function capable of comparing two chapters
     /// ```rust
     /// use libwebnovel::backends::FreeWebNovel;
@@ -111,6 +184,17 @@ impl Backend for FreeWebNovel {
         })
     }
 
+    /// Chapter URLs are made of the fiction slug & the chapter number, which is
+    /// used as the chapter identifier.
+    fn parse_chapter_url(url: &str) -> Option<ChapterUrlParts> {
+        let captures = CHAPTER_URL_REGEX.captures(url)?;
+        Some(ChapterUrlParts {
+            fiction_id: captures["novel_slug"].to_string(),
+            chapter_id: captures["chapter_number"].to_string(),
+            slugs: Vec::new(),
+        })
+    }
+
     /// Creates a new FreeWebNovel backend from the given URL
     /// ```rust
     /// use libwebnovel::backends::FreeWebNovel;
@@ -124,7 +208,12 @@ impl Backend for FreeWebNovel {
     /// );
     /// ```
     fn new(url: &str) -> Result<Self, BackendError> {
-        let req = get(url)?;
+        Self::new_with_config(url, &ClientConfig::default())
+    }
+
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        let client = config.build()?;
+        let req = client.get(url)?;
         if !req.status().is_success() {
             return Err(BackendError::RequestFailed {
                 message: format!("Could not fetch url {url}"),
@@ -135,24 +224,37 @@ impl Backend for FreeWebNovel {
         Ok(Self {
             url: url.to_string(),
             page: Html::parse_document(&req.text()?),
+            options: BackendOptions::default(),
+            client,
         })
     }
 
+    fn http_client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        &self.options
+    }
+
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        self.options = options;
+    }
+
     /// Title of the fiction. See [`FreeWebNovel::new`] for usage.
     fn title(&self) -> Result<String, BackendError> {
         title(&self.page)
     }
 
     fn immutable_identifier(&self) -> Result<String, BackendError> {
-        Ok(self
-            .url
-            .split('/')
-            .last()
-            .unwrap()
-            .to_string()
-            .strip_suffix(".html")
-            .unwrap()
-            .to_string())
+        Ok(slugify(
+            self.url
+                .split('/')
+                .next_back()
+                .unwrap()
+                .strip_suffix(".html")
+                .unwrap(),
+        ))
     }
 
     /// Returns the URL of the fiction
@@ -171,6 +273,14 @@ impl Backend for FreeWebNovel {
         self.url.clone()
     }
 
+    fn site_icon_url(&self) -> String {
+        "https://freewebnovel.com/favicon.ico".to_string()
+    }
+
+    fn declared_language(&self) -> Option<String> {
+        declared_language(&self.page)
+    }
+
     /// Returns the cover URL of the fiction
     ///
     /// ```rust
@@ -205,6 +315,19 @@ impl Backend for FreeWebNovel {
         authors(&self.page)
     }
 
+    /// Returns the genres of the fiction.
+    fn get_tags(&self) -> Result<Vec<String>, BackendError> {
+        Ok(genres(&self.page))
+    }
+
+    fn description(&self) -> Result<String, BackendError> {
+        description(&self.page)
+    }
+
+    fn status(&self) -> Result<FictionStatus, BackendError> {
+        Ok(status(&self.page))
+    }
+
     /// Returns the chapter list as available on the main fiction page
     /// ```rust
     /// use libwebnovel::backends::FreeWebNovel;
@@ -227,6 +350,10 @@ impl Backend for FreeWebNovel {
         get_chapter_list(&self.page)
     }
 
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        chapter_infos(&self.page, "https://freewebnovel.com")
+    }
+
     /// returns a chapter
     /// ```rust
     /// use libwebnovel::backends::FreeWebNovel;
@@ -243,16 +370,18 @@ impl Backend for FreeWebNovel {
         if chapter_number == 0 {
             return Err(BackendError::UnknownChapter(chapter_number));
         }
-        let chapter_url = self
-            .page
-            .select(&CHAPTER_LIST_SELECTOR)
-            .map(|select| select.attr("href").unwrap())
+        let chapter_url = chapter_urls(&self.page)?
+            .into_iter()
             .nth(chapter_number - 1)
             .ok_or(BackendError::UnknownChapter(chapter_number))?;
         let chapter_url = format!("https://freewebnovel.com{}", chapter_url);
-        let mut chapter = get_chapter(chapter_url)?;
+        let mut chapter = get_chapter(&self.client, chapter_url, &self.url, &self.options)?;
         chapter.index = chapter_number;
         chapter.fiction_url = self.url.clone();
+        chapter.add_metadata(BACKEND_NAME_METADATA_KEY, Self::get_backend_name());
+        self.options.clean_chapter(&mut chapter);
+        self.options
+            .check_chapter(&chapter, Self::capabilities().has_dates)?;
         Ok(chapter)
     }
 
@@ -276,17 +405,26 @@ pub(crate) fn get_cover_url(page: &Html) -> Result<String, BackendError> {
         .next()
         .ok_or(BackendError::ParseError(
             "Could not find cover url".to_string(),
+            None,
         ))?
         .attr("content")
         .ok_or(BackendError::ParseError(
             "Could not find cover url: missing \"content\" attribute.".to_string(),
+            None,
         ))?
         .to_string())
 }
 
-pub(crate) fn get_chapter(url: impl IntoUrl) -> Result<Chapter, BackendError> {
+/// Fetches the chapter at `url`, of the fiction at `fiction_url`, with the
+/// given client.
+pub(crate) fn get_chapter(
+    client: &HttpClient,
+    url: impl IntoUrl,
+    fiction_url: &str,
+    options: &BackendOptions,
+) -> Result<Chapter, BackendError> {
     let url_str = url.into_url()?.to_string();
-    let resp = get(&url_str)?;
+    let resp = client.get(&url_str)?;
     if !resp.status().is_success() {
         return Err(BackendError::RequestFailed {
             message: format!("Could not get chapter at URL {url_str}"),
@@ -294,26 +432,56 @@ pub(crate) fn get_chapter(url: impl IntoUrl) -> Result<Chapter, BackendError> {
             content: resp.text()?,
         });
     }
-    let page = Html::parse_document(&resp.text()?);
-    let chapter_title = decode_html_entities(
-        &page
-            .select(&CHAPTER_TITLE_SELECTOR)
-            .next()
-            .unwrap()
-            .inner_html(),
-    )
-    .to_string();
+    check_not_redirected_home(&resp, &url_str, fiction_url)?;
+    parse_chapter(url_str, &resp.text()?, options)
+}
+
+/// Builds a [`Chapter`] from the HTML of its page.
+pub(crate) fn parse_chapter(
+    url: String,
+    page: &str,
+    options: &BackendOptions,
+) -> Result<Chapter, BackendError> {
+    let page = Html::parse_document(page);
+    let chapter_title = page
+        .select(&CHAPTER_TITLE_SELECTOR)
+        .next()
+        .map(|title| decode_html_entities(&title.inner_html()).to_string());
     let chapter_content = page
         .select(&CHAPTER_CONTENT_SELECTOR)
         .next()
-        .unwrap()
-        .inner_html();
+        .map(|content| content.inner_html())
+        .unwrap_or_default();
     let mut chapter = Chapter::default();
-    chapter.set_title(Some(chapter_title));
-    chapter.set_chapter_url(url_str);
+    chapter.set_title(chapter_title);
+    chapter.set_published_at(chapter_date(&page));
+    chapter.set_chapter_url(url);
+    chapter.set_raw_content(options.raw_content(&chapter_content));
     chapter.set_content(chapter_content);
     Ok(chapter)
 }
+
+/// Returns the publication date of a chapter, from its `time` element or from
+/// its info block. `None` if the page has no date at all, or if it cannot be
+/// parsed: a missing date should not prevent reading the chapter.
+fn chapter_date(page: &Html) -> Option<DateTime<Utc>> {
+    let date = match page
+        .select(&CHAPTER_TIME_SELECTOR)
+        .next()
+        .and_then(|time| time.attr("datetime"))
+    {
+        Some(datetime) => datetime.to_string(),
+        None => {
+            let info = page.select(&CHAPTER_INFO_SELECTOR).next()?;
+            let info_text: String = info.text().collect();
+            CHAPTER_DATE_REGEX.captures(&info_text)?["date"].to_string()
+        }
+    };
+    parse_date(&date)
+        .inspect_err(|e| warn!("Could not parse the chapter date {date:?}: {e}"))
+        .ok()
+}
+
 pub(crate) fn title(page: &Html) -> Result<String, BackendError> {
     let title = page
         .select(&TITLE_SELECTOR)
@@ -323,6 +491,7 @@ pub(crate) fn title(page: &Html) -> Result<String, BackendError> {
     if title.is_none() {
         return Err(BackendError::ParseError(
             "Could not get a title".to_string(),
+            None,
         ));
     }
     Ok(title.unwrap())
@@ -342,7 +511,52 @@ pub(crate) fn authors(page: &Html) -> Result<Vec<String>, BackendError> {
     Ok(authors)
 }
 
+/// Returns the genres listed in the info panel of the fiction page.
+pub(crate) fn genres(page: &Html) -> Vec<String> {
+    page.select(&GENRE_LINK_SELECTOR)
+        .map(|a| a.text().collect::<String>().trim().to_string())
+        .filter(|genre| !genre.is_empty())
+        .collect()
+}
+
+/// Returns the status stated in the info panel of the fiction page.
+pub(crate) fn status(page: &Html) -> FictionStatus {
+    page.select(&INFO_ROW_SELECTOR)
+        .find(|row| row.select(&STATUS_ICON_SELECTOR).next().is_some())
+        .and_then(|row| row.select(&INFO_ROW_VALUE_SELECTOR).next())
+        .map(|value| FictionStatus::from_label(&value.text().collect::<String>()))
+        .unwrap_or_default()
+}
+
+/// Returns the synopsis of the fiction as HTML, falling back to the
+/// description of the page.
+pub(crate) fn description(page: &Html) -> Result<String, BackendError> {
+    if let Some(inner) = page.select(&DESCRIPTION_SELECTOR).next() {
+        return Ok(inner.inner_html().trim().to_string());
+    }
+    page.select(&META_DESCRIPTION_SELECTOR)
+        .next()
+        .and_then(|meta| meta.attr("content"))
+        .map(|content| encode_text(content.trim()).to_string())
+        .ok_or(BackendError::ParseError(
+            "Could not find the description of the fiction".to_string(),
+            None,
+        ))
+}
+
+/// Returns the (relative) URLs of all the chapters listed on the fiction page,
+/// making sure none of them appears twice.
+pub(crate) fn chapter_urls(page: &Html) -> Result<Vec<String>, BackendError> {
+    let urls: Vec<String> = page
+        .select(&CHAPTER_LIST_SELECTOR)
+        .map(|select| select.attr("href").unwrap().to_string())
+        .collect();
+    ensure_unique_chapter_urls(&urls)?;
+    Ok(urls)
+}
+
 pub(crate) fn get_chapter_list(page: &Html) -> Result<Vec<ChapterListElem>, BackendError> {
+    chapter_urls(page)?;
     Ok(page
         .select(&CHAPTER_LIST_SELECTOR)
         .enumerate()
@@ -355,26 +569,69 @@ pub(crate) fn get_chapter_list(page: &Html) -> Result<Vec<ChapterListElem>, Back
         .collect())
 }
 
+/// Returns the titles & URLs of the chapters listed on a fiction page, chapter
+/// URLs being relative to `site`.
+pub(crate) fn chapter_infos(page: &Html, site: &str) -> Result<Vec<ChapterInfo>, BackendError> {
+    Ok(get_chapter_list(page)?
+        .into_iter()
+        .zip(chapter_urls(page)?)
+        .map(|((index, title), url)| {
+            let mut info = ChapterInfo::default();
+            info.set_index(index);
+            info.set_title(Some(title));
+            info.set_chapter_url(Some(format!("{site}{url}")));
+            info
+        })
+        .collect())
+}
+
+/// Returns the total chapter count stated in the info panel of the fiction
+/// page, if any.
+pub(crate) fn stated_chapter_count(page: &Html) -> Option<usize> {
+    let panel_text: String = page.select(&INFO_PANEL_SELECTOR).next()?.text().collect();
+    let captures = STATED_CHAPTER_COUNT_REGEX.captures(&panel_text)?;
+    captures
+        .name("before")
+        .or(captures.name("after"))?
+        .as_str()
+        .parse()
+        .ok()
+}
+
+/// Returns the chapter count, cross-checking the chapter list with the total
+/// stated in the info panel. The latter is authoritative, so a mismatch (such
+/// as a truncated chapter list) is a [`BackendError::ParseError`] rather than
+/// a wrong count.
 pub(crate) fn chapter_count(page: &Html) -> Result<usize, BackendError> {
-    let chapter_links: Vec<String> = page
-        .select(&CHAPTER_LIST_SELECTOR)
-        .map(|select| select.attr("href").unwrap().to_string())
-        .collect();
-    Ok(chapter_links.len())
+    let listed = chapter_urls(page)?.len();
+    match stated_chapter_count(page) {
+        Some(stated) if stated != listed => Err(BackendError::ParseError(
+            format!("The fiction page states {stated} chapters, but lists {listed}"),
+            None,
+        )),
+        _ => Ok(listed),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
+    use chrono::DateTime;
+    use indoc::indoc;
+    use scraper::Html;
     use test_log::test;
 
-    use crate::backends::FreeWebNovel;
+    use crate::backends::freewebnovel::{
+        chapter_count, description, genres, get_chapter_list, parse_chapter, status,
+    };
+    use crate::backends::{BackendError, FictionStatus, FreeWebNovel};
     use crate::{Backend, Chapter};
 
     const TEST_URL: &str = "https://freewebnovel.com/the-guide-to-conquering-earthlings.html";
 
     #[test]
+    #[ignore = "needs access to freewebnovel.com"]
     fn test_chapter_to_string_and_back() {
         let b = FreeWebNovel::new(TEST_URL).unwrap();
         let chapter = b.get_chapter(1).unwrap();
@@ -384,6 +641,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs access to freewebnovel.com"]
     fn test_chapter_list_equality() {
         let b = FreeWebNovel::new(TEST_URL).unwrap();
         let chapters: Vec<Chapter> = (1..3)
@@ -397,4 +655,156 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_parse_chapter_date() {
+        let url = "https://freewebnovel.com/the-guide-to-conquering-earthlings/chapter-1";
+        let content = r#"<div class="txt"><div id="article"><p>Hello</p></div></div>"#;
+        let page = format!(
+            r#"<div class="top"><span class="chapter">Chapter 1: 01</span>
+            <time datetime="2021-05-04T10:00:00Z">May 4, 2021</time></div>{content}"#
+        );
+        let chapter = parse_chapter(url.to_string(), &page, &Default::default()).unwrap();
+        assert_eq!(
+            chapter.published_at(),
+            &Some(
+                DateTime::parse_from_rfc3339("2021-05-04T10:00:00Z")
+                    .unwrap()
+                    .to_utc()
+            )
+        );
+
+        let page = format!(
+            r#"<div class="top"><span class="chapter">Chapter 1: 01</span>
+            <p>Updated: 2021-05-04 10:00</p></div>{content}"#
+        );
+        let chapter = parse_chapter(url.to_string(), &page, &Default::default()).unwrap();
+        assert_eq!(
+            chapter.published_at(),
+            &Some(
+                DateTime::parse_from_rfc3339("2021-05-04T10:00:00Z")
+                    .unwrap()
+                    .to_utc()
+            )
+        );
+
+        let page = format!(
+            r#"<div class="top"><span class="chapter">Chapter 1: 01</span>
+            <time datetime="last tuesday">Tuesday</time></div>{content}"#
+        );
+        let chapter = parse_chapter(url.to_string(), &page, &Default::default()).unwrap();
+        assert_eq!(chapter.published_at(), &None);
+
+        let page = format!(
+            r#"<div class="top"><span class="chapter">Chapter 1: 01</span></div>{content}"#
+        );
+        let chapter = parse_chapter(url.to_string(), &page, &Default::default()).unwrap();
+        assert_eq!(chapter.published_at(), &None);
+    }
+
+    #[test]
+    fn test_chapter_list_duplicate_url() {
+        let page = Html::parse_document(indoc! {r#"
+            <div class="m-newest2">
+              <ul id="idData">
+                <li><a class="con" href="/novel/chapter-1" title="Chapter 1">Chapter 1</a></li>
+                <li><a class="con" href="/novel/chapter-2" title="Chapter 2">Chapter 2</a></li>
+                <li><a class="con" href="/novel/chapter-1" title="Read first">Read first</a></li>
+              </ul>
+            </div>
+        "#});
+        match get_chapter_list(&page) {
+            Err(BackendError::ParseError(msg, _)) => assert!(msg.contains("/novel/chapter-1")),
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+    }
+
+    fn fiction_page(info_panel: &str, chapter_count: usize) -> Html {
+        let chapters: String = (1..=chapter_count)
+            .map(|i| {
+                format!(r#"<li><a class="con" href="/novel/chapter-{i}" title="Chapter {i}">Chapter {i}</a></li>"#)
+            })
+            .collect();
+        Html::parse_document(&format!(
+            r#"<div class="m-imgtxt"><div class="txt">{info_panel}</div></div>
+            <div class="m-newest2"><ul id="idData">{chapters}</ul></div>"#
+        ))
+    }
+
+    #[test]
+    fn test_stated_chapter_count() {
+        let info_panel = indoc! {r#"
+            <div class="item"><span class="glyphicon glyphicon-user" title="Author"></span>
+              <div class="right"><a class="a1" href="/authors/ye-fei-ran">Ye Fei Ran</a></div></div>
+            <div class="item"><span class="glyphicon glyphicon-list" title="Chapters"></span>
+              <div class="right"><span class="s1">60 Chapters</span></div></div>
+        "#};
+        assert_eq!(chapter_count(&fiction_page(info_panel, 60)).unwrap(), 60);
+        match chapter_count(&fiction_page(info_panel, 40)) {
+            Err(BackendError::ParseError(msg, _)) => assert!(msg.contains("60"), "{msg}"),
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+        // no stated total
+        assert_eq!(chapter_count(&fiction_page("", 40)).unwrap(), 40);
+    }
+
+    #[test]
+    fn test_genres() {
+        let info_panel = indoc! {r#"
+            <div class="item"><span class="glyphicon glyphicon-user" title="Author"></span>
+              <div class="right"><a class="a1" href="/authors/ye-fei-ran">Ye Fei Ran</a></div></div>
+            <div class="item"><span class="glyphicon glyphicon-th-list" title="Genre"></span>
+              <div class="right"><a href="/genre/Fantasy" class="a1" title="Fantasy">Fantasy</a>,
+              <a href="/genre/Romance" class="a1" title="Romance">Romance</a></div></div>
+        "#};
+        assert_eq!(
+            genres(&fiction_page(info_panel, 1)),
+            vec!["Fantasy", "Romance"]
+        );
+        assert!(genres(&fiction_page("", 1)).is_empty());
+    }
+
+    #[test]
+    fn test_status() {
+        let info_panel = indoc! {r#"
+            <div class="item"><span class="glyphicon glyphicon-user" title="Author"></span>
+              <div class="right"><a class="a1" href="/authors/ye-fei-ran">Ye Fei Ran</a></div></div>
+            <div class="item"><span class="glyphicon glyphicon-time" title="Status"></span>
+              <div class="right"><span class="s1 s2"><a href="/sort/completed-novel">Completed</a></span></div></div>
+        "#};
+        assert_eq!(
+            status(&fiction_page(info_panel, 1)),
+            FictionStatus::Completed
+        );
+        assert_eq!(
+            status(&fiction_page(
+                &info_panel.replace("Completed", "OnGoing"),
+                1
+            )),
+            FictionStatus::Ongoing
+        );
+        assert_eq!(status(&fiction_page("", 1)), FictionStatus::Unknown);
+    }
+
+    #[test]
+    fn test_description() {
+        let page = Html::parse_document(indoc! {r#"
+            <head><meta name="description" content="A short & sweet summary"></head>
+            <div class="m-desc"><div class="txt"><div class="inner">
+              <p>Earth is <em>conquered</em>.</p>
+            </div></div></div>
+        "#});
+        assert_eq!(
+            description(&page).unwrap(),
+            "<p>Earth is <em>conquered</em>.</p>"
+        );
+        let page = Html::parse_document(
+            r#"<head><meta name="description" content="A short & sweet summary"></head>"#,
+        );
+        assert_eq!(description(&page).unwrap(), "A short &amp; sweet summary");
+        assert!(matches!(
+            description(&fiction_page("", 1)),
+            Err(BackendError::ParseError(..))
+        ));
+    }
 }
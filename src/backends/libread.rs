@@ -4,15 +4,19 @@ use regex::Regex;
 use scraper::Html;
 
 use crate::backends::{
-    freewebnovel, Backend, BackendError, ChapterListElem, ChapterOrderingFn, FreeWebNovel,
+    freewebnovel, Backend, BackendCapabilities, BackendError, BackendOptions, ChapterListElem,
+    ChapterOrderingFn, FictionStatus, FreeWebNovel, BACKEND_NAME_METADATA_KEY,
 };
-use crate::utils::get;
-use crate::Chapter;
+use crate::utils::{declared_language, slugify, ClientConfig, HttpClient};
+use crate::{Chapter, ChapterInfo};
 
 /// A backend using [libread](https://libread.com). Honestly i don't know why i bothered with it, since i'm under the impression that most chapters redirect to [FreeWebNovel](https://freewebnovel.com).
+#[derive(Clone)]
 pub struct LibRead {
     url: String,
     page: Html,
+    options: BackendOptions,
+    client: HttpClient,
 }
 
 #[allow(unused_variables, dead_code)]
@@ -22,7 +26,12 @@ impl Debug for LibRead {
         struct LibRead<'a> {
             url: &'a String,
         }
-        let Self { url, page: _ } = self;
+        let Self {
+            url,
+            page: _,
+            options: _,
+            client: _,
+        } = self;
         Debug::fmt(&LibRead { url }, f)
     }
 }
@@ -32,6 +41,8 @@ impl Default for LibRead {
         Self {
             url: "".to_string(),
             page: Html::new_document(),
+            options: BackendOptions::default(),
+            client: HttpClient::default(),
         }
     }
 }
@@ -55,6 +66,16 @@ impl Backend for LibRead {
         "libread"
     }
 
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities {
+            has_cover: true,
+            has_dates: false,
+            supports_description: true,
+            supports_tags: true,
+            ..Default::default()
+        }
+    }
+
     /// returns a function capable of comparing two chapters
     /// ```rust
     /// use libwebnovel::backends::LibRead;
@@ -91,7 +112,12 @@ impl Backend for LibRead {
     /// );
     /// ```
     fn new(url: &str) -> Result<Self, BackendError> {
-        let req = get(url)?;
+        Self::new_with_config(url, &ClientConfig::default())
+    }
+
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        let client = config.build()?;
+        let req = client.get(url)?;
         if !req.status().is_success() {
             return Err(BackendError::RequestFailed {
                 message: format!("Could not fetch url {url}"),
@@ -102,16 +128,30 @@ impl Backend for LibRead {
         Ok(Self {
             url: url.to_string(),
             page: Html::parse_document(&req.text()?),
+            options: BackendOptions::default(),
+            client,
         })
     }
 
+    fn http_client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        &self.options
+    }
+
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        self.options = options;
+    }
+
     /// Title of the fiction. See [`LibRead::new`] for docs.
     fn title(&self) -> Result<String, BackendError> {
         freewebnovel::title(&self.page)
     }
 
     fn immutable_identifier(&self) -> Result<String, BackendError> {
-        Ok(self.url.split('/').last().unwrap().to_string())
+        Ok(slugify(self.url.split('/').next_back().unwrap()))
     }
 
     /// Returns the URL of the fiction
@@ -130,6 +170,14 @@ impl Backend for LibRead {
         self.url.clone()
     }
 
+    fn site_icon_url(&self) -> String {
+        "https://libread.com/favicon.ico".to_string()
+    }
+
+    fn declared_language(&self) -> Option<String> {
+        declared_language(&self.page)
+    }
+
     /// Returns the cover URL of the fiction
     ///
     /// ```rust
@@ -164,6 +212,19 @@ impl Backend for LibRead {
         freewebnovel::authors(&self.page)
     }
 
+    /// Returns the genres of the fiction, listed like on FreeWebNovel.
+    fn get_tags(&self) -> Result<Vec<String>, BackendError> {
+        Ok(freewebnovel::genres(&self.page))
+    }
+
+    fn description(&self) -> Result<String, BackendError> {
+        freewebnovel::description(&self.page)
+    }
+
+    fn status(&self) -> Result<FictionStatus, BackendError> {
+        Ok(freewebnovel::status(&self.page))
+    }
+
     /// Returns the chapter list as available on the main fiction page
     /// ```rust
     /// use libwebnovel::backends::LibRead;
@@ -186,6 +247,10 @@ impl Backend for LibRead {
         freewebnovel::get_chapter_list(&self.page)
     }
 
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        freewebnovel::chapter_infos(&self.page, "https://libread.com")
+    }
+
     /// returns a chapter
     /// ```rust
     /// use libwebnovel::backends::LibRead;
@@ -202,17 +267,20 @@ impl Backend for LibRead {
         if chapter_number == 0 {
             return Err(BackendError::UnknownChapter(chapter_number));
         }
-        let chapter_url = self
-            .page
-            .select(&freewebnovel::CHAPTER_LIST_SELECTOR)
-            .map(|select| select.attr("href").unwrap())
+        let chapter_url = freewebnovel::chapter_urls(&self.page)?
+            .into_iter()
             .nth(chapter_number - 1)
             .ok_or(BackendError::UnknownChapter(chapter_number))?;
         let chapter_url = format!("https://libread.com{}", chapter_url);
         println!("{:?}", chapter_url);
-        let mut chapter = freewebnovel::get_chapter(chapter_url)?;
+        let mut chapter =
+            freewebnovel::get_chapter(&self.client, chapter_url, &self.url, &self.options)?;
         chapter.index = chapter_number;
         chapter.fiction_url = self.url.clone();
+        chapter.add_metadata(BACKEND_NAME_METADATA_KEY, Self::get_backend_name());
+        self.options.clean_chapter(&mut chapter);
+        self.options
+            .check_chapter(&chapter, Self::capabilities().has_dates)?;
         Ok(chapter)
     }
 
@@ -239,6 +307,7 @@ mod tests {
     use crate::{Backend, Chapter};
 
     #[test]
+    #[ignore = "needs access to libread.com"]
     fn test_chapter_to_string_and_back() {
         let b =
             LibRead::new("https://libread.com/libread/the-guide-to-conquering-earthlings-33024")
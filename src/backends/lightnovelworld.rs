@@ -1,22 +1,40 @@
+use std::cell::OnceCell;
 use std::fmt::{Debug, Formatter};
 use std::sync::LazyLock;
 
-use chrono::NaiveDateTime;
+use getset::{Getters, Setters};
 use html_escape::decode_html_entities;
 use log::{trace, warn};
 use regex::Regex;
-use scraper::{Html, Selector};
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
 
 use crate::backends::BackendError::ParseError;
-use crate::backends::{BackendError, ChapterListElem};
-use crate::utils::get;
-use crate::{Backend, Chapter};
+use crate::backends::{
+    check_not_redirected_home, ensure_unique_chapter_urls, BackendCapabilities, BackendError,
+    BackendOptions, ChapterListElem, ChapterUrlParts, ParagraphClassFilter,
+    BACKEND_NAME_METADATA_KEY,
+};
+use crate::utils::{declared_language, parse_date, slugify, ClientConfig, HttpClient};
+use crate::{Backend, Chapter, ChapterInfo, Volume};
 
-/// Backend for lightnovelworld.com
+/// Backend for lightnovelworld.com, as well as lightnovelpub.com &
+/// webnovelpub.com which run the same platform (requires the `lightnovelpub`
+/// feature).
+#[derive(Getters, Setters, Clone)]
 pub struct LightNovelWorld {
     url: String,
     main_page: Html,
     chapter_list_page: Html,
+    /// Parsed from `chapter_list_page` (and the following pages of the
+    /// chapter list) on first access
+    listed_chapters: OnceCell<Vec<ListedChapter>>,
+    options: BackendOptions,
+    /// Decides which classed paragraphs of the chapters are ads, to be
+    /// removed.
+    #[getset(get = "pub", set = "pub")]
+    paragraph_classes: ParagraphClassFilter,
+    client: HttpClient,
 }
 
 impl Default for LightNovelWorld {
@@ -25,6 +43,10 @@ impl Default for LightNovelWorld {
             url: "".to_string(),
             main_page: Html::new_document(),
             chapter_list_page: Html::new_document(),
+            listed_chapters: OnceCell::new(),
+            options: BackendOptions::default(),
+            paragraph_classes: ParagraphClassFilter::default(),
+            client: HttpClient::default(),
         }
     }
 }
@@ -40,26 +62,195 @@ impl Debug for LightNovelWorld {
             url,
             main_page: _,
             chapter_list_page: _,
+            listed_chapters: _,
+            options: _,
+            paragraph_classes: _,
+            client: _,
         } = self;
         Debug::fmt(&LightNovelWorld { url }, f)
     }
 }
 
+/// A chapter, as listed on the chapter list pages.
+#[derive(Debug, Clone)]
+struct ListedChapter {
+    number: usize,
+    title: String,
+    /// Absolute URL of the chapter
+    url: String,
+    /// Number of the volume the chapter is part of, if listed
+    volume: Option<usize>,
+}
+
+impl ListedChapter {
+    fn info(&self) -> ChapterInfo {
+        let mut info = ChapterInfo::default();
+        info.set_index(self.number);
+        info.set_title(Some(self.title.clone()));
+        info.set_chapter_url(Some(self.url.clone()));
+        info
+    }
+}
+
+/// Returns the URL of a page of the fiction, such as its chapter list. The
+/// trailing slash, query string & fragment of the fiction URL are left out.
+fn fiction_page_url(fiction_url: &str, page: &str) -> String {
+    let fiction_url = fiction_url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('/');
+    format!("{fiction_url}/{page}")
+}
+
+impl LightNovelWorld {
+    /// Returns the chapters listed on the chapter list pages, which are only
+    /// fetched & parsed the first time. Makes sure no chapter URL appears
+    /// twice.
+    fn listed_chapters(&self) -> Result<&[ListedChapter], BackendError> {
+        static CHAPTER_LIST_PAGE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
+            Selector::parse("article#chapter-list-page section#chpagedlist ul.pagination li")
+                .unwrap()
+        });
+        static CHAPTER_LIST_SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
+            Selector::parse(
+                "article#chapter-list-page section#chpagedlist.container ul.chapter-list li",
+            )
+            .unwrap()
+        });
+        static CHAPTER_LIST_SELECTOR_CHAPTER_NO: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("a span.chapter-no").unwrap());
+        static CHAPTER_LIST_SELECTOR_CHAPTER_TITLE: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("a").unwrap());
+
+        if let Some(listed_chapters) = self.listed_chapters.get() {
+            return Ok(listed_chapters);
+        }
+        let base = Url::parse(&self.url).map_err(|e| {
+            BackendError::parse_error_with_source(format!("Invalid URL {}", self.url), e)
+        })?;
+        let chapter_pages_count = self
+            .chapter_list_page
+            .select(&CHAPTER_LIST_PAGE_SELECTOR)
+            .count()
+            .saturating_sub(1); // "next" button
+        let mut current_page = self.chapter_list_page.clone();
+        let mut chapters = Vec::new();
+        let mut i = 1usize;
+        loop {
+            for sel in current_page.select(&CHAPTER_LIST_SELECTOR) {
+                trace!("sel: {:?}", sel);
+                let Some(link) = sel.select(&CHAPTER_LIST_SELECTOR_CHAPTER_TITLE).next() else {
+                    continue;
+                };
+                let chapter_no_inner_html = sel
+                    .select(&CHAPTER_LIST_SELECTOR_CHAPTER_NO)
+                    .next()
+                    .map(|chapter_no| chapter_no.inner_html())
+                    .unwrap_or_default();
+                // Need to filter, some chapter are numbered "ex1" for instance, for "extra 1".
+                let number = match chapter_no_inner_html.trim().parse() {
+                    Ok(number) => number,
+                    Err(e) => {
+                        warn!("Could not parse chapter number {chapter_no_inner_html:?}: {e}");
+                        continue;
+                    }
+                };
+                let Some(url) = link.attr("href").and_then(|href| base.join(href).ok()) else {
+                    warn!("Ignoring chapter {number}, listed without a valid link");
+                    continue;
+                };
+                chapters.push(ListedChapter {
+                    number,
+                    title: decode_html_entities(link.attr("title").unwrap_or_default()).to_string(),
+                    url: url.to_string(),
+                    volume: sel
+                        .attr("data-volumeno")
+                        .and_then(|volume| volume.trim().parse().ok()),
+                });
+            }
+            if i < chapter_pages_count {
+                i += 1;
+                current_page = Html::parse_document(
+                    &self
+                        .client
+                        .get(fiction_page_url(&self.url, &format!("chapters?page={i}")))?
+                        .text()?,
+                );
+            } else {
+                break;
+            }
+        }
+        let chapter_urls: Vec<String> =
+            chapters.iter().map(|chapter| chapter.url.clone()).collect();
+        ensure_unique_chapter_urls(&chapter_urls)?;
+        Ok(self.listed_chapters.get_or_init(|| chapters))
+    }
+
+    /// Returns a chapter of the chapter list, by number.
+    fn listed_chapter(&self, chapter_number: usize) -> Result<&ListedChapter, BackendError> {
+        self.listed_chapters()?
+            .iter()
+            .find(|chapter| chapter.number == chapter_number)
+            .ok_or(BackendError::UnknownChapter(chapter_number))
+    }
+}
+
 /// Implementation of [`Backend`] for [Light Novel World](https://www.lightnovelworld.com)
 impl Backend for LightNovelWorld {
     fn get_backend_regexps() -> Vec<Regex> {
-        vec![
-            Regex::new(r#"https://www.lightnovelworld.com/novel/(?<novel_slug>[a-z0-9\-]+)/?"#)
-                .unwrap(),
-        ]
+        #[allow(unused_mut)]
+        let mut regexps = vec![Regex::new(
+            r#"https://www.lightnovelworld.com/novel/(?<novel_slug>[a-z0-9\-]+)/?"#,
+        )
+        .unwrap()];
+        #[cfg(feature = "lightnovelpub")]
+        regexps.push(
+            Regex::new(
+                r#"https://www\.(?:lightnovelpub|webnovelpub)\.com/novel/(?<novel_slug>[a-z0-9\-]+)/?"#,
+            )
+            .unwrap(),
+        );
+        regexps
     }
 
     fn get_backend_name() -> &'static str {
         "lightnovelworld"
     }
 
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities {
+            has_cover: true,
+            has_dates: true,
+            supports_description: true,
+            ..Default::default()
+        }
+    }
+
+    /// Chapter URLs are made of the fiction URL & the chapter number, which
+    /// is used as the chapter identifier.
+    fn parse_chapter_url(url: &str) -> Option<ChapterUrlParts> {
+        Self::get_backend_regexps().iter().find_map(|regex| {
+            let captures = regex.captures(url)?;
+            let chapter_number = url[captures.get(0)?.end()..]
+                .trim_end_matches('/')
+                .strip_prefix("chapter-")
+                .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))?;
+            Some(ChapterUrlParts {
+                fiction_id: captures["novel_slug"].to_string(),
+                chapter_id: chapter_number.to_string(),
+                slugs: Vec::new(),
+            })
+        })
+    }
+
     fn new(url: &str) -> Result<Self, BackendError> {
-        let resp = get(url)?;
+        Self::new_with_config(url, &ClientConfig::default())
+    }
+
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        let client = config.build()?;
+        let resp = client.get(url)?;
         if !resp.status().is_success() {
             return Err(BackendError::RequestFailed {
                 message: format!("could not get fiction URL {url}"),
@@ -68,7 +259,7 @@ impl Backend for LightNovelWorld {
             });
         }
         let main_page = Html::parse_document(&resp.text()?);
-        let chapter_list_page = get(format!("{}/chapters", url))?;
+        let chapter_list_page = client.get(fiction_page_url(url, "chapters"))?;
         if !chapter_list_page.status().is_success() {
             return Err(BackendError::RequestFailed {
                 message: format!("could not get chapter page, although we could get the main fiction page. Generated chapters url:  {url}"),
@@ -81,9 +272,23 @@ impl Backend for LightNovelWorld {
             url: url.to_string(),
             main_page,
             chapter_list_page,
+            client,
+            ..Default::default()
         })
     }
 
+    fn http_client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        &self.options
+    }
+
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        self.options = options;
+    }
+
     fn title(&self) -> Result<String, BackendError> {
         static TITLE_SELECTOR: LazyLock<Selector> =
             LazyLock::new(|| Selector::parse("h1.novel-title").unwrap());
@@ -92,34 +297,46 @@ impl Backend for LightNovelWorld {
             .select(&TITLE_SELECTOR)
             .map(|sel| sel.inner_html())
             .next()
-            .ok_or(BackendError::ParseError(format!(
-                "Could not parse page to find title: {}",
-                self.url
-            )))?
+            .ok_or(BackendError::ParseError(
+                format!("Could not parse page to find title: {}", self.url),
+                None,
+            ))?
             .trim_matches('\n')
             .to_string())
     }
 
+    /// Returns the novel's slug, as found in its URL. Trailing slashes, query
+    /// strings & sub-paths (such as `/chapters`) are ignored.
     fn immutable_identifier(&self) -> Result<String, BackendError> {
-        let mut split = self.url.rsplitn(3, '/');
-        if let Some(s) = split.next() {
-            if !s.is_empty() {
-                // if we don't have a trailing slash
-                return Ok(s.to_string());
-            }
-            if let Some(s) = split.next() {
-                return Ok(s.to_string());
-            }
-        }
-        Err(ParseError(
-            "Could not find a proper identifier.".to_string(),
-        ))
+        Self::get_backend_regexps()
+            .iter()
+            .find_map(|regex| regex.captures(&self.url))
+            .and_then(|captures| captures.name("novel_slug"))
+            .map(|slug| slugify(slug.as_str()))
+            .ok_or(ParseError(
+                format!("Could not find a proper identifier in URL {}", self.url),
+                None,
+            ))
     }
 
     fn url(&self) -> String {
         self.url.clone()
     }
 
+    /// Returns the favicon of the site the fiction is on, since this backend
+    /// handles several sites.
+    fn site_icon_url(&self) -> String {
+        let host = Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_string()))
+            .unwrap_or("www.lightnovelworld.com".to_string());
+        format!("https://{host}/favicon.ico")
+    }
+
+    fn declared_language(&self) -> Option<String> {
+        declared_language(&self.main_page)
+    }
+
     fn cover_url(&self) -> Result<String, BackendError> {
         static COVER_IMAGE_SELECTOR: LazyLock<Selector> =
             LazyLock::new(|| Selector::parse("html head meta[property=\"og:image\"]").unwrap());
@@ -133,6 +350,32 @@ impl Backend for LightNovelWorld {
             .to_string())
     }
 
+    /// Covers are served at any size, given in their URL (such as
+    /// `/bookcover/300x400/…`).
+    fn cover_url_sized(&self, width: u32, height: u32) -> Result<String, BackendError> {
+        static COVER_SIZE_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"/\d+x\d+/").unwrap());
+        Ok(COVER_SIZE_REGEX
+            .replace(&self.cover_url()?, format!("/{width}x{height}/"))
+            .to_string())
+    }
+
+    fn description(&self) -> Result<String, BackendError> {
+        static DESCRIPTION_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("div.summary div.content").unwrap());
+        Ok(self
+            .main_page
+            .select(&DESCRIPTION_SELECTOR)
+            .next()
+            .ok_or(BackendError::ParseError(
+                format!("Could not find the description of {}", self.url),
+                None,
+            ))?
+            .inner_html()
+            .trim()
+            .to_string())
+    }
+
     fn get_authors(&self) -> Result<Vec<String>, BackendError> {
         static AUTHOR_SELECTOR: LazyLock<Selector> =
             LazyLock::new(|| Selector::parse("div.author a span").unwrap());
@@ -143,80 +386,58 @@ impl Backend for LightNovelWorld {
             .next()
             .ok_or(BackendError::ParseError(
                 "Failed to find authors in fiction page".to_string(),
+                None,
             ))?
             .inner_html()
             .to_string()])
     }
 
     fn get_chapter_list(&self) -> Result<Vec<ChapterListElem>, BackendError> {
-        const _CHAPTER_LIST_PAGE_COUNT: usize = 100;
-        static CHAPTER_LIST_PAGE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
-            Selector::parse("article#chapter-list-page section#chpagedlist ul.pagination li")
-                .unwrap()
-        });
-        static CHAPTER_LIST_SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
-            Selector::parse(
-                "article#chapter-list-page section#chpagedlist.container ul.chapter-list li",
-            )
-            .unwrap()
-        });
-        static CHAPTER_LIST_SELECTOR_CHAPTER_NO: LazyLock<Selector> =
-            LazyLock::new(|| Selector::parse("a span.chapter-no").unwrap());
-        static CHAPTER_LIST_SELECTOR_CHAPTER_TITLE: LazyLock<Selector> =
-            LazyLock::new(|| Selector::parse("a").unwrap());
+        Ok(self
+            .listed_chapters()?
+            .iter()
+            .map(|chapter| (chapter.number, chapter.title.clone()))
+            .collect())
+    }
 
-        let chapter_pages_count = self
-            .chapter_list_page
-            .select(&CHAPTER_LIST_PAGE_SELECTOR)
-            .count()
-            - 1; // "next" button
-        let mut current_page = self.chapter_list_page.clone();
-        let mut chapters = Vec::new();
-        let mut i = 1usize;
-        loop {
-            let page_chapters: Vec<ChapterListElem> = current_page
-                .select(&CHAPTER_LIST_SELECTOR)
-                .filter_map(|sel| {
-                    trace!("sel: {:?}", sel);
-                    let chapter_no_inner_html = sel
-                        .select(&CHAPTER_LIST_SELECTOR_CHAPTER_NO)
-                        .next()
-                        .unwrap()
-                        .inner_html();
-                    // Need to filter, some chapter are numbered "ex1" for instance, for "extra 1".
-                    let chapter_no: usize = match chapter_no_inner_html.parse().map_err(|e| {
-                        BackendError::ParseError(format!(
-                            "Could not parse \"{}\" as an integer: {e}",
-                            chapter_no_inner_html
-                        ))
-                    }) {
-                        Ok(i) => i,
-                        Err(e) => {
-                            warn!("Could not parse chapter number: {e}");
-                            return None;
-                        }
-                    };
-                    let chapter_title = decode_html_entities(
-                        sel.select(&CHAPTER_LIST_SELECTOR_CHAPTER_TITLE)
-                            .next()
-                            .unwrap()
-                            .attr("title")
-                            .unwrap(),
-                    );
-                    Some((chapter_no, chapter_title.to_string()))
-                })
-                .collect();
-            chapters.extend(page_chapters);
-            if i < chapter_pages_count {
-                i += 1;
-                current_page = Html::parse_document(
-                    &get(format!("{}/chapters?page={}", self.url, i))?.text()?,
-                );
-            } else {
-                break;
+    /// Returns the title & URL of a chapter, as listed on the chapter list.
+    fn get_chapter_info(&self, chapter_number: usize) -> Result<ChapterInfo, BackendError> {
+        Ok(self.listed_chapter(chapter_number)?.info())
+    }
+
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        Ok(self
+            .listed_chapters()?
+            .iter()
+            .map(ListedChapter::info)
+            .collect())
+    }
+
+    /// Groups the chapters by the volume number the chapter list gives them.
+    /// The site doesn't name its volumes, so they are titled "Volume N";
+    /// chapters listed without a volume (or in volume 0) go to unnamed
+    /// volumes.
+    fn get_volumes(&self) -> Result<Vec<Volume>, BackendError> {
+        let mut volumes: Vec<(Option<usize>, Vec<ChapterInfo>)> = Vec::new();
+        for chapter in self.listed_chapters()? {
+            let number = chapter.volume.filter(|number| *number > 0);
+            match volumes.last_mut() {
+                Some((volume, chapters)) if *volume == number => chapters.push(chapter.info()),
+                _ => volumes.push((number, vec![chapter.info()])),
             }
         }
-        Ok(chapters)
+        if volumes.is_empty() {
+            return Ok(vec![Volume::default()]);
+        }
+        Ok(volumes
+            .into_iter()
+            .map(|(number, chapters)| {
+                let mut volume = Volume::default();
+                volume.set_title(number.map(|number| format!("Volume {number}")));
+                volume.set_chapters(chapters);
+                volume
+            })
+            .collect())
     }
 
     fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
@@ -228,46 +449,60 @@ impl Backend for LightNovelWorld {
         static CHAPTER_PUBLISHED_AT_SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
             Selector::parse("article#chapter-article section.page-in.content-wrap div.titles meta[itemprop='datePublished']").unwrap()
         });
-        let url = format!("{}/chapter-{}", self.url, chapter_number);
-        let chapter_page = get(&url)?;
+        let url = self.listed_chapter(chapter_number)?.url.clone();
+        let chapter_page = self.client.get(&url)?;
+        check_not_redirected_home(&chapter_page, &url, &self.url)?;
         let chapter_content = Html::parse_document(&chapter_page.text()?);
-        let chapter_title = decode_html_entities(
-            &chapter_content
-                .select(&CHAPTER_TITLE_SELECTOR)
-                .next()
-                .unwrap()
-                .inner_html(),
-        )
-        .to_string();
-        // FIXME: remove ads (<p class="…"> instead of <p>)
-        let chapter_paragraphs = chapter_content
-            .select(&CHAPTER_CONTENT_SELECTOR)
+        let chapter_title = chapter_content
+            .select(&CHAPTER_TITLE_SELECTOR)
             .next()
-            .unwrap()
-            .inner_html()
-            .lines()
-            .filter(|line| line.starts_with("<p>"))
-            .collect::<Vec<&str>>()
-            .join("\n");
-        let published_at_str = chapter_content
+            .map(|title| decode_html_entities(&title.inner_html()).to_string());
+        let content_element = chapter_content.select(&CHAPTER_CONTENT_SELECTOR).next();
+        let raw_content =
+            content_element.and_then(|content| self.options.raw_content(&content.inner_html()));
+        let chapter_paragraphs = content_element
+            .map(|content| chapter_paragraphs(content, &self.paragraph_classes))
+            .unwrap_or_default();
+        let published_at = chapter_content
             .select(&CHAPTER_PUBLISHED_AT_SELECTOR)
             .next()
-            .unwrap()
-            .attr("content")
-            .unwrap();
-        let published_at =
-            NaiveDateTime::parse_from_str(published_at_str, "%Y-%m-%dT%H:%M:%S")?.and_utc();
+            .and_then(|published_at| published_at.attr("content"))
+            .map(parse_date)
+            .transpose()?;
         let mut chapter = Chapter::default();
         chapter.set_index(chapter_number);
-        chapter.set_title(Some(chapter_title));
+        chapter.set_title(chapter_title);
         chapter.set_chapter_url(url);
         chapter.set_fiction_url(self.url().clone());
-        chapter.set_published_at(Some(published_at.to_utc()));
+        chapter.set_published_at(published_at);
         chapter.set_content(chapter_paragraphs);
+        chapter.set_raw_content(raw_content);
+        chapter.add_metadata(BACKEND_NAME_METADATA_KEY, Self::get_backend_name());
+        self.options.clean_chapter(&mut chapter);
+        self.options
+            .check_chapter(&chapter, Self::capabilities().has_dates)?;
         Ok(chapter)
     }
 }
 
+/// Returns the paragraphs & quotes of the chapter container, whole: line
+/// breaks within them (such as in verses) are kept. Everything else, such as
+/// ads (paragraphs rejected by `paragraph_classes`, `<div>`…) or scripts, is
+/// left out.
+fn chapter_paragraphs(container: ElementRef, paragraph_classes: &ParagraphClassFilter) -> String {
+    container
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|element| match element.value().name() {
+            "p" => paragraph_classes.keeps(element.value().classes()),
+            "blockquote" => true,
+            _ => false,
+        })
+        .map(|element| element.html())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -275,12 +510,119 @@ mod tests {
     use chrono::NaiveDateTime;
 
     use super::*;
-    use crate::Backend;
+    use crate::test_support::MockServer;
+    use crate::{Backend, Backends, TypographyOptions};
 
     const TEST_URL: &str = "https://www.lightnovelworld.com/novel/the-perfect-run-24071713";
     type TestBackend = LightNovelWorld;
 
+    /// A chapter list page linking to the first `count` chapters of the
+    /// fiction.
+    fn chapter_list_page(count: usize) -> Html {
+        let items: String = (1..=count)
+            .map(|n| {
+                format!(
+                    r#"<li><a href="/novel/the-perfect-run-24071713/chapter-{n}" title="Chapter {n}">
+                    <span class="chapter-no">{n}</span></a></li>"#
+                )
+            })
+            .collect();
+        Html::parse_document(&format!(
+            r#"<article id="chapter-list-page"><section id="chpagedlist" class="container">
+            <ul class="chapter-list">{items}</ul></section></article>"#
+        ))
+    }
+
+    #[test]
+    fn test_get_volumes() {
+        let item = |n: usize, volume: usize| {
+            format!(
+                r#"<li data-chapterno="{n}" data-volumeno="{volume}" data-orderno="{n}">
+                <a href="/novel/the-perfect-run-24071713/chapter-{n}" title="Chapter {n}">
+                <span class="chapter-no">{n}</span></a></li>"#
+            )
+        };
+        let backend = LightNovelWorld {
+            url: TEST_URL.to_string(),
+            chapter_list_page: Html::parse_document(&format!(
+                r#"<article id="chapter-list-page"><section id="chpagedlist" class="container">
+                <ul class="chapter-list">{}{}{}</ul></section></article>"#,
+                item(1, 1),
+                item(2, 1),
+                item(3, 2)
+            )),
+            ..Default::default()
+        };
+        let volumes = backend.get_volumes().unwrap();
+        let summary: Vec<(Option<String>, Vec<usize>)> = volumes
+            .iter()
+            .map(|volume| {
+                (
+                    volume.title().clone(),
+                    volume.chapters().iter().map(|info| *info.index()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some("Volume 1".to_string()), vec![1, 2]),
+                (Some("Volume 2".to_string()), vec![3]),
+            ]
+        );
+        assert_eq!(
+            volumes[1].chapters()[0].chapter_url(),
+            &Some(format!("{TEST_URL}/chapter-3"))
+        );
+
+        // without volume numbers, there is a single unnamed volume
+        let backend = LightNovelWorld {
+            url: TEST_URL.to_string(),
+            chapter_list_page: chapter_list_page(2),
+            ..Default::default()
+        };
+        let volumes = backend.get_volumes().unwrap();
+        assert_eq!(volumes.len(), 1);
+        assert_eq!(volumes[0].title(), &None);
+        assert_eq!(volumes[0].chapters().len(), 2);
+    }
+
     #[test]
+    fn test_listed_chapter_urls() {
+        let server = MockServer::start(&[(
+            "/novel/the-perfect-run-24071713/chapter-2",
+            200,
+            r#"<html><body><article id="chapter-article"><section class="page-in content-wrap">
+            <div class="titles"><h1><span class="chapter-title">Chapter 2</span></h1></div>
+            <div id="chapter-container"><p>Second</p></div>
+            </section></article></body></html>"#,
+        )]);
+        // chapter URLs are the listed ones, whatever the shape of the fiction URL
+        let backend = LightNovelWorld {
+            url: server.url("/novel/the-perfect-run-24071713/?utm_source=rss"),
+            chapter_list_page: chapter_list_page(2),
+            ..Default::default()
+        };
+        let chapter_url = server.url("/novel/the-perfect-run-24071713/chapter-2");
+        assert_eq!(
+            backend.get_chapter_info(2).unwrap().chapter_url(),
+            &Some(chapter_url.clone())
+        );
+        let chapter = backend.get_chapter(2).unwrap();
+        assert_eq!(chapter.chapter_url(), &chapter_url);
+        assert!(chapter.content().contains("Second"));
+        assert!(matches!(
+            backend.get_chapter(3),
+            Err(BackendError::UnknownChapter(3))
+        ));
+        assert_eq!(
+            fiction_page_url(&backend.url, "chapters?page=2"),
+            server.url("/novel/the-perfect-run-24071713/chapters?page=2")
+        );
+    }
+
+    #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_chapter_to_string_and_back() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let chapter = b.get_chapter(1).unwrap();
@@ -299,6 +641,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_chapter_equality() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let chapters: Vec<Chapter> = (1..3)
@@ -314,9 +657,10 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_ordering_function() {
         let b = TestBackend::new(TEST_URL).unwrap();
-        let mut chapters = vec![
+        let mut chapters = [
             b.get_chapter(2).unwrap(),
             b.get_chapter(1).unwrap(),
             b.get_chapter(4).unwrap(),
@@ -330,19 +674,89 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
+    fn test_backends_new() {
+        let backend = Backends::new(TEST_URL).unwrap();
+        assert!(matches!(backend, Backends::LightNovelWorld(_)));
+        assert_eq!(backend.get_backend_name(), "lightnovelworld");
+        assert_eq!(backend.title().unwrap(), "The Perfect Run");
+    }
+
+    #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_title() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let title = b.title().unwrap();
         assert_eq!(title, "The Perfect Run");
     }
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_immutable_identifier() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let immutable_identifier = b.immutable_identifier().unwrap();
         assert_eq!(immutable_identifier, "the-perfect-run-24071713");
     }
 
+    #[cfg(feature = "lightnovelpub")]
+    #[test]
+    fn test_sister_sites() {
+        for url in [
+            "https://www.lightnovelpub.com/novel/the-perfect-run-24071713",
+            "https://www.webnovelpub.com/novel/the-perfect-run-24071713/",
+        ] {
+            assert!(
+                TestBackend::get_backend_regexps()
+                    .iter()
+                    .any(|regex| regex.is_match(url)),
+                "{url}"
+            );
+            let b = TestBackend {
+                url: url.to_string(),
+                ..Default::default()
+            };
+            assert_eq!(
+                b.immutable_identifier().unwrap(),
+                "the-perfect-run-24071713",
+                "{url}"
+            );
+        }
+        assert!(
+            !TestBackend::get_backend_regexps()
+                .iter()
+                .any(|regex| regex
+                    .is_match("https://www.novelpub.com/novel/the-perfect-run-24071713"))
+        );
+    }
+
+    #[test]
+    fn test_immutable_identifier_url_shapes() {
+        for url in [
+            TEST_URL,
+            "https://www.lightnovelworld.com/novel/the-perfect-run-24071713/",
+            "https://www.lightnovelworld.com/novel/the-perfect-run-24071713?utm_source=rss",
+            "https://www.lightnovelworld.com/novel/the-perfect-run-24071713/?page=2",
+            "https://www.lightnovelworld.com/novel/the-perfect-run-24071713/chapters",
+            "https://www.lightnovelworld.com/novel/the-perfect-run-24071713/chapter-1",
+        ] {
+            let b = TestBackend {
+                url: url.to_string(),
+                ..Default::default()
+            };
+            assert_eq!(
+                b.immutable_identifier().unwrap(),
+                "the-perfect-run-24071713",
+                "{url}"
+            );
+        }
+        let b = TestBackend {
+            url: "https://www.lightnovelworld.com/".to_string(),
+            ..Default::default()
+        };
+        assert!(b.immutable_identifier().is_err());
+    }
+
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_cover_url() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let cover_url = b.cover_url().unwrap();
@@ -353,6 +767,22 @@ mod tests {
     }
 
     #[test]
+    fn test_cover_url_sized() {
+        let backend = LightNovelWorld {
+            url: TEST_URL.to_string(),
+            main_page: Html::parse_document(
+                r#"<html><head><meta property="og:image" content="https://static.lightnovelworld.com/bookcover/300x400/01261-the-perfect-run.jpg"></head></html>"#,
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            backend.cover_url_sized(150, 200).unwrap(),
+            "https://static.lightnovelworld.com/bookcover/150x200/01261-the-perfect-run.jpg"
+        );
+    }
+
+    #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_get_author() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let authors = b.get_authors().unwrap();
@@ -360,6 +790,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_get_chapter_list() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let chapter_list = b.get_chapter_list().unwrap();
@@ -367,6 +798,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_get_chapter() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let chapter = b.get_chapter(1).unwrap();
@@ -388,13 +820,157 @@ mod tests {
                     .and_utc()
             )
         );
-        assert_eq!(chapter.metadata().len(), 0);
+        assert_eq!(
+            chapter.metadata().get(BACKEND_NAME_METADATA_KEY),
+            Some(&"lightnovelworld".to_string())
+        );
     }
     #[test]
+    #[ignore = "needs access to lightnovelworld.com"]
     fn test_chapter_ads_removal() {
         let b = TestBackend::new(TEST_URL).unwrap();
         let chapter = b.get_chapter(1).unwrap();
         let regex = Regex::new(r#"<p class=".*">"#).unwrap();
         assert!(regex.captures(chapter.content()).is_none())
     }
+
+    #[test]
+    fn test_deleted_chapter_redirects_home() {
+        let home = r#"<html><body><h1 class="novel-title">The Perfect Run</h1></body></html>"#;
+        let server = MockServer::start(&[
+            (
+                "/novel/the-perfect-run-24071713/chapter-1",
+                200,
+                r#"<html><body><article id="chapter-article"><section class="page-in content-wrap">
+                <div class="titles"><h1><span class="chapter-title">Chapter 1</span></h1></div>
+                <div id="chapter-container"><p>Still there</p></div>
+                </section></article></body></html>"#,
+            ),
+            (
+                "/novel/the-perfect-run-24071713/chapter-2",
+                302,
+                "/novel/the-perfect-run-24071713/",
+            ),
+            ("/novel/the-perfect-run-24071713/chapter-3", 301, "/"),
+            ("/novel/the-perfect-run-24071713/", 200, home),
+            ("/", 200, home),
+        ]);
+        let backend = LightNovelWorld {
+            url: server.url("/novel/the-perfect-run-24071713"),
+            chapter_list_page: chapter_list_page(3),
+            ..Default::default()
+        };
+        assert!(backend
+            .get_chapter(1)
+            .unwrap()
+            .content()
+            .contains("Still there"));
+        for index in [2, 3] {
+            match backend.get_chapter(index) {
+                Err(BackendError::DeletedChapter(url)) => assert_eq!(
+                    url,
+                    server.url(&format!("/novel/the-perfect-run-24071713/chapter-{index}"))
+                ),
+                result => panic!("chapter {index} should be deleted, got {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_description() {
+        let backend = LightNovelWorld {
+            url: TEST_URL.to_string(),
+            main_page: Html::parse_document(
+                r#"<div class="summary"><h4 class="lined">Summary</h4><div class="content expand-wrapper">
+                <p>Ryan Romano can <em>save</em> and load.</p>
+                </div></div>"#,
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            backend.description().unwrap(),
+            "<p>Ryan Romano can <em>save</em> and load.</p>"
+        );
+        assert!(matches!(
+            LightNovelWorld::default().description(),
+            Err(BackendError::ParseError(..))
+        ));
+    }
+
+    #[test]
+    fn test_paragraph_classes() {
+        let server = MockServer::start(&[(
+            "/novel/the-perfect-run-24071713/chapter-1",
+            200,
+            r#"<html><body><article id="chapter-article"><section class="page-in content-wrap">
+            <div class="titles"><h1><span class="chapter-title">Chapter 1</span></h1></div>
+            <div id="chapter-container">
+            <p>Once upon a time…</p>
+            <p class="text-center">* * *</p>
+            <p class="adsbygoogle">Buy now!</p>
+            <p class="x7fq2b">Read this novel elsewhere</p>
+            <p class="kqzmtr">Support the translator</p>
+            <p class="dialogue">"Hello," she said.</p>
+            </div></section></article></body></html>"#,
+        )]);
+        let mut backend = LightNovelWorld {
+            url: server.url("/novel/the-perfect-run-24071713"),
+            chapter_list_page: chapter_list_page(3),
+            ..Default::default()
+        };
+        let content = backend.get_chapter(1).unwrap().content().to_string();
+        assert!(content.contains("Once upon a time"));
+        assert!(content.contains("* * *"));
+        assert!(content.contains("she said"));
+        assert!(!content.contains("Buy now"));
+        assert!(!content.contains("elsewhere"));
+        assert!(!content.contains("translator"));
+
+        backend.set_paragraph_classes(ParagraphClassFilter {
+            allowed: vec!["kqzmtr".to_string()],
+            denied: vec!["dialogue".to_string()],
+            remove_generated: false,
+        });
+        let content = backend.get_chapter(1).unwrap().content().to_string();
+        assert!(content.contains("* * *"));
+        assert!(content.contains("elsewhere"));
+        assert!(content.contains("translator"));
+        assert!(!content.contains("she said"));
+    }
+
+    #[test]
+    fn test_verse_line_breaks() {
+        let server = MockServer::start(&[(
+            "/novel/the-perfect-run-24071713/chapter-1",
+            200,
+            r#"<html><body><article id="chapter-article"><section class="page-in content-wrap">
+            <div class="titles"><h1><span class="chapter-title">The Song</span></h1></div>
+            <div id="chapter-container">
+            <p>She sang:</p>
+            <p>Roses are red,<br>
+            Violets are blue,<br/>
+            Sugar is sweet.</p>
+            <div class="ad"><p>Buy now!</p></div>
+            <p class="xyz">Read this novel elsewhere</p>
+            <blockquote><p>And so are you,<br>
+            said the bard.</p></blockquote>
+            </div></section></article></body></html>"#,
+        )]);
+        let backend = LightNovelWorld {
+            url: server.url("/novel/the-perfect-run-24071713"),
+            chapter_list_page: chapter_list_page(3),
+            ..Default::default()
+        };
+        let chapter = backend.get_chapter(1).unwrap();
+        assert!(!chapter.content().contains("Buy now"));
+        assert!(!chapter.content().contains("elsewhere"));
+        let mut chapter = Chapter::from_str(&chapter.to_string()).unwrap();
+        chapter.normalize_typography(TypographyOptions::default());
+        chapter.minify_content();
+        assert_eq!(
+            chapter.content(),
+            "<p>She sang:</p><p>Roses are red,<br> Violets are blue,<br> Sugar is sweet.</p>\
+             <blockquote><p>And so are you,<br> said the bard.</p></blockquote>"
+        );
+    }
 }
@@ -1,10 +1,28 @@
 use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+#[cfg(any(feature = "serde", feature = "epub"))]
+use std::path::Path;
+#[cfg(feature = "epub")]
+use std::path::PathBuf;
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::{LazyLock, Mutex};
+use std::thread;
 
+use chrono::{DateTime, Duration, Utc};
+use log::{debug, warn};
 use regex::Regex;
-use reqwest::StatusCode;
+use reqwest::blocking::Response;
+use reqwest::{StatusCode, Url};
+use scraper::Html;
 use strum::{EnumCount, EnumIter, IntoEnumIterator};
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
 
+#[cfg(feature = "async")]
+pub use crate::backends::async_backend::AsyncBackend;
+#[cfg(feature = "dreame")]
+pub use crate::backends::dreame::Dreame;
 #[cfg(feature = "freewebnovel")]
 pub use crate::backends::freewebnovel::FreeWebNovel;
 #[cfg(feature = "libread")]
@@ -12,9 +30,20 @@ pub use crate::backends::libread::LibRead;
 #[cfg(feature = "lightnovelworld")]
 pub use crate::backends::lightnovelworld::LightNovelWorld;
 #[cfg(feature = "royalroad")]
-pub use crate::backends::royalroad::RoyalRoad;
-use crate::utils::get;
-use crate::Chapter;
+pub use crate::backends::royalroad::{RoyalRoad, RoyalRoadOptions};
+#[cfg(feature = "scribblehub")]
+pub use crate::backends::scribblehub::ScribbleHub;
+#[cfg(feature = "template")]
+pub use crate::backends::template::{SiteTemplate, TemplateBackend};
+#[cfg(feature = "epub")]
+use crate::epub::{write_epubs, EpubSplit};
+#[cfg(feature = "serde")]
+use crate::manifest::{read_chapter_list, write_chapter_list, ExportOptions, FictionManifest};
+use crate::utils::{
+    describe_network_settings, guess_language, setting_line, slugify, ClientConfig, HttpClient,
+    HTTP_CLIENT,
+};
+use crate::{Chapter, ChapterInfo, CoverImage, FictionMetadata, FictionPackage, Volume};
 
 #[cfg(feature = "libread")]
 mod libread;
@@ -24,8 +53,18 @@ mod royalroad;
 #[cfg(feature = "freewebnovel")]
 mod freewebnovel;
 
+#[cfg(feature = "dreame")]
+mod dreame;
 #[cfg(feature = "lightnovelworld")]
 mod lightnovelworld;
+#[cfg(feature = "scribblehub")]
+mod scribblehub;
+
+#[cfg(feature = "template")]
+mod template;
+
+#[cfg(feature = "async")]
+mod async_backend;
 
 /// An error that may be returned when the backend encounters an error
 #[derive(thiserror::Error, Debug)]
@@ -41,7 +80,10 @@ pub enum BackendError {
     UrlNotFound,
     /// Used when [`reqwest::Response::status()`] returns something else than
     /// success
-    #[error("We could not access the fiction page: {message}: {status}: {content}")]
+    #[error(
+        "We could not access the fiction page: {message}: {status}: {}",
+        truncated_body(.content)
+    )]
     RequestFailed {
         /// A message describing the error
         message: String,
@@ -50,9 +92,13 @@ pub enum BackendError {
         /// Content of the response's body as text
         content: String,
     },
-    /// An error while parsing the fiction
+    /// An error while parsing the fiction. The underlying error, if any, is
+    /// available through [`std::error::Error::source`].
     #[error("An error occured while parsing the fiction page: {0}")]
-    ParseError(String),
+    ParseError(
+        String,
+        #[source] Option<Box<dyn std::error::Error + Send + Sync>>,
+    ),
     /// We could not parse a valid date
     #[error("An error occured while trying to make sense of a date: {0}")]
     DateParseError(#[from] chrono::format::ParseError),
@@ -68,28 +114,814 @@ pub enum BackendError {
         /// The [`Chapter`] the issue originated from
         chapter: Box<Chapter>,
     },
+    /// Error while reading or writing files
+    #[error("An I/O error occured: {0}")]
+    IoError(#[from] std::io::Error),
+    /// Requests to this host have been failing too much lately, so they are not
+    /// sent for a while. See
+    /// [`CircuitBreakerConfig`][crate::CircuitBreakerConfig].
+    #[error("{host} has been failing repeatedly, not sending requests to it for {retry_after:?}")]
+    CircuitOpen {
+        /// The failing host, as `host:port`
+        host: String,
+        /// How long until requests are sent to this host again
+        retry_after: std::time::Duration,
+    },
+    /// The backend does not support the requested operation
+    #[error("This backend does not support {0}")]
+    Unsupported(String),
+    /// The site served a placeholder ("this chapter has moved", "coming
+    /// soon"…) instead of the chapter at the given URL.
+    #[error("Got a placeholder instead of the chapter at {0}")]
+    PlaceholderChapter(String),
+    /// The fiction has more chapters than allowed by
+    /// [`BackendOptions::max_chapters`], so they have not been fetched.
+    #[error("The fiction has {count} chapters, more than the maximum of {max}")]
+    TooManyChapters {
+        /// Number of chapters reported by the backend, or walked through so
+        /// far
+        count: usize,
+        /// The maximum number of chapters allowed
+        max: usize,
+    },
+    /// The chapter with the given index is locked (behind a paywall, for
+    /// instance), so its content cannot be fetched. See
+    /// [`ChapterInfo::is_locked`].
+    #[error("Chapter {0} is locked")]
+    LockedChapter(usize),
+    /// The chapter at the given URL has been deleted: the site redirected it
+    /// to the fiction's page (or to its home page).
+    #[error("The chapter at {0} has been deleted, its URL leads back to the fiction")]
+    DeletedChapter(String),
+    /// The [`ClientConfig`] could not be turned into an
+    /// HTTP client (invalid header, for instance).
+    #[error("Invalid HTTP client configuration: {0}")]
+    InvalidClientConfig(String),
+}
+
+impl BackendError {
+    /// Builds a [`BackendError::ParseError`] caused by `source`.
+    #[cfg_attr(
+        not(any(
+            feature = "royalroad",
+            feature = "lightnovelworld",
+            feature = "template",
+            feature = "serde"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn parse_error_with_source(
+        message: impl Into<String>,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::ParseError(message.into(), Some(source.into()))
+    }
+
+    /// Tells whether the error is likely transient (network issues, timeouts,
+    /// HTTP 429 or 5xx…), in which case the failed operation may succeed if
+    /// retried later. Permanent errors (HTTP 404, parsing errors, unknown
+    /// chapters…) return `false`. This lets a downloader decide whether to
+    /// re-queue a failed chapter.
+    pub fn is_retryable(&self) -> bool {
+        fn is_retryable_status(status: StatusCode) -> bool {
+            status == StatusCode::TOO_MANY_REQUESTS
+                || status == StatusCode::REQUEST_TIMEOUT
+                || status.is_server_error()
+        }
+        match self {
+            BackendError::NetError(e) => match e.status() {
+                Some(status) => is_retryable_status(status),
+                None => e.is_timeout() || e.is_connect() || e.is_request() || e.is_body(),
+            },
+            BackendError::RequestFailed { status, .. } => is_retryable_status(*status),
+            BackendError::IoError(e) => matches!(
+                e.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::WouldBlock
+            ),
+            BackendError::CircuitOpen { .. } => true,
+            BackendError::NoMatchingBackendFound(_)
+            | BackendError::UrlNotFound
+            | BackendError::ParseError(..)
+            | BackendError::DateParseError(_)
+            | BackendError::UnknownChapter(_)
+            | BackendError::MissingChapterInformation { .. }
+            | BackendError::Unsupported(_)
+            | BackendError::PlaceholderChapter(_)
+            | BackendError::TooManyChapters { .. }
+            | BackendError::LockedChapter(_)
+            | BackendError::DeletedChapter(_)
+            | BackendError::InvalidClientConfig(_) => false,
+        }
+    }
+}
+
+/// Maximum number of characters of a response body shown in the message of a
+/// [`BackendError::RequestFailed`]. Error pages can be huge.
+const MAX_DISPLAYED_BODY_LENGTH: usize = 200;
+
+/// Shortens a response body to [`MAX_DISPLAYED_BODY_LENGTH`] characters, so
+/// that errors stay readable in logs.
+fn truncated_body(content: &str) -> String {
+    let content = content.trim();
+    match content.char_indices().nth(MAX_DISPLAYED_BODY_LENGTH) {
+        Some((end, _)) => format!("{}… ({} bytes in total)", &content[..end], content.len()),
+        None => content.to_string(),
+    }
+}
+
+/// Metadata key under which backends store their
+/// [name][Backend::get_backend_name] in the chapters they return. This allows
+/// knowing which backend a stored chapter comes from.
+pub const BACKEND_NAME_METADATA_KEY: &str = "backend_name";
+
+/// Makes sure no chapter URL appears twice in a chapter list. A duplicate
+/// usually means a chapter list selector matched something that is not a
+/// chapter link (navigation, "read first chapter" buttons…), which would
+/// otherwise silently corrupt the downloaded fiction.
+#[cfg_attr(
+    not(any(
+        feature = "royalroad",
+        feature = "freewebnovel",
+        feature = "lightnovelworld",
+        feature = "template",
+        feature = "scribblehub"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn ensure_unique_chapter_urls<S: AsRef<str>>(urls: &[S]) -> Result<(), BackendError> {
+    let mut seen = HashSet::new();
+    for url in urls {
+        if !seen.insert(url.as_ref()) {
+            return Err(BackendError::ParseError(
+                format!(
+                    "Found duplicate chapter URL in chapter list: {}",
+                    url.as_ref()
+                ),
+                None,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Number of most recent chapter publications considered by
+/// [`median_release_interval`].
+#[cfg_attr(not(feature = "royalroad"), allow(dead_code))]
+const RELEASE_CADENCE_SAMPLE_SIZE: usize = 10;
+
+/// Computes the median interval between the most recent publication dates
+/// given. Returns [`None`] if there are fewer than 3 dates, which is not enough
+/// to get a meaningful cadence.
+#[cfg_attr(not(feature = "royalroad"), allow(dead_code))]
+pub(crate) fn median_release_interval(dates: &[DateTime<Utc>]) -> Option<Duration> {
+    if dates.len() < 3 {
+        return None;
+    }
+    let mut dates = dates.to_vec();
+    dates.sort();
+    let recent = &dates[dates.len().saturating_sub(RELEASE_CADENCE_SAMPLE_SIZE + 1)..];
+    let mut intervals: Vec<Duration> = recent.windows(2).map(|w| w[1] - w[0]).collect();
+    intervals.sort();
+    let middle = intervals.len() / 2;
+    if intervals.len().is_multiple_of(2) {
+        Some((intervals[middle - 1] + intervals[middle]) / 2)
+    } else {
+        Some(intervals[middle])
+    }
+}
+
+/// Returns the indices of the chapters of the chapter list that are [not part
+/// of the story][Backend::is_story_chapter], to be skipped when
+/// [`BackendOptions::skip_non_story_chapters`] is set (none otherwise).
+pub(crate) fn non_story_chapters<B: Backend>(backend: &B) -> Result<HashSet<usize>, BackendError> {
+    if !backend.backend_options().skip_non_story_chapters {
+        return Ok(HashSet::new());
+    }
+    Ok(backend
+        .get_chapter_infos()?
+        .into_iter()
+        .filter(|info| !backend.is_story_chapter(info))
+        .map(|info| {
+            debug!(
+                "Skipping chapter {} ({:?}), not part of the story",
+                info.index(),
+                info.title()
+            );
+            *info.index()
+        })
+        .collect())
+}
+
+/// Puts all the chapters of the given chapter list in a single, unnamed,
+/// [`Volume`].
+pub(crate) fn single_volume(chapter_list: Vec<ChapterListElem>) -> Volume {
+    let mut volume = Volume::default();
+    volume.set_chapters(
+        chapter_list
+            .into_iter()
+            .map(|(index, title)| {
+                let mut info = ChapterInfo::default();
+                info.set_index(index);
+                info.set_title(Some(title));
+                info
+            })
+            .collect(),
+    );
+    volume
+}
+
+/// Default value of [`BackendOptions::max_chapters`].
+pub const DEFAULT_MAX_CHAPTERS: usize = 100_000;
+
+/// Average size of a word in a chapter's HTML, spaces & markup included, in
+/// bytes. Used to turn the word counts published by some sites into
+/// [`Backend::estimated_total_bytes`].
+pub const AVERAGE_BYTES_PER_WORD: u64 = 7;
+
+/// Language returned by [`Backend::language`] when nothing hints at another
+/// one.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Number of chapters whose content is used to guess the language of a
+/// fiction, when its site doesn't declare it.
+const LANGUAGE_SAMPLE_CHAPTERS: usize = 3;
+
+/// Decides which paragraphs of a chapter are kept according to their classes,
+/// for sites injecting ads in chapters as paragraphs with a class (often a
+/// random one, such as `<p class="x7fq2b">`). Paragraphs without a class are
+/// always kept.
+///
+/// ```rust
+/// use libwebnovel::backends::ParagraphClassFilter;
+/// let filter = ParagraphClassFilter::default();
+/// assert!(filter.keeps([]));
+/// assert!(filter.keeps(["text-center"]));
+/// assert!(!filter.keeps(["adsbygoogle"]));
+/// assert!(!filter.keeps(["x7fq2b"]));
+/// assert!(filter.keeps(["dialogue"]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParagraphClassFilter {
+    /// Paragraphs with one of these classes are kept, unless they also have a
+    /// denied class.
+    pub allowed: Vec<String>,
+    /// Paragraphs with one of these classes are removed.
+    pub denied: Vec<String>,
+    /// Remove the paragraphs with a class that is neither allowed nor denied,
+    /// but looks generated rather than written by someone: a class without
+    /// vowels, or mixing letters & digits (past 4 characters). Enabled by
+    /// default.
+    pub remove_generated: bool,
+}
+
+impl Default for ParagraphClassFilter {
+    fn default() -> Self {
+        Self {
+            allowed: [
+                "center",
+                "text-center",
+                "text-right",
+                "italic",
+                "bold",
+                "separator",
+            ]
+            .map(String::from)
+            .to_vec(),
+            denied: [
+                "ad",
+                "ads",
+                "adsbygoogle",
+                "advertisement",
+                "sponsored",
+                "hidden",
+            ]
+            .map(String::from)
+            .to_vec(),
+            remove_generated: true,
+        }
+    }
+}
+
+impl ParagraphClassFilter {
+    /// Tells whether a paragraph with the given classes should be kept.
+    pub fn keeps<'a>(&self, classes: impl IntoIterator<Item = &'a str>) -> bool {
+        let classes: Vec<&str> = classes.into_iter().collect();
+        let listed = |list: &[String]| classes.iter().any(|class| list.iter().any(|c| c == class));
+        if listed(&self.denied) {
+            return false;
+        }
+        if listed(&self.allowed) {
+            return true;
+        }
+        !(self.remove_generated && classes.iter().any(|class| looks_generated(class)))
+    }
+}
+
+/// Tells whether the given class name looks generated, such as the random
+/// classes given to ads. See [`ParagraphClassFilter::remove_generated`].
+fn looks_generated(class: &str) -> bool {
+    let has_vowel = class.chars().any(|c| "aeiouAEIOU".contains(c));
+    let has_digit = class.chars().any(|c| c.is_ascii_digit());
+    let has_letter = class.chars().any(|c| c.is_ascii_alphabetic());
+    !has_vowel || (class.len() > 4 && has_digit && has_letter)
+}
+
+/// Options altering the behaviour of every backend. See
+/// [`Backend::new_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendOptions {
+    /// Return a [`BackendError::ParseError`] when an expected element of a
+    /// chapter (title, content, publication date) is missing or empty, instead
+    /// of returning a partial [`Chapter`]. Disabled by default.
+    pub strict: bool,
+    /// Maximum number of chapters fetched by [`Backend::get_chapters`] or
+    /// walked through by [`Backend::walk_from_first_chapter`], past which a
+    /// [`BackendError::TooManyChapters`] is returned. This bounds runaway
+    /// fetches when a site reports a bogus chapter count or links chapters in
+    /// circles. Defaults to [`DEFAULT_MAX_CHAPTERS`].
+    pub max_chapters: usize,
+    /// Title to use in output (see [`Backend::output_title`]) instead of the
+    /// scraped one, which may contain site cruft ("Read X - Free Online") or
+    /// be in the wrong language. [`Backend::title`] still returns the scraped
+    /// title.
+    pub title_override: Option<String>,
+    /// Remove the "Previous Chapter | Next Chapter" navigation that may
+    /// surround the content of chapters (see [`Chapter::strip_navigation`]).
+    /// Disabled by default.
+    pub strip_navigation: bool,
+    /// Keep the content of chapters as served by the site, before any
+    /// cleaning, in [`Chapter::raw_content`], for archival or to re-run the
+    /// cleaning later. This roughly doubles the memory used by chapters, so
+    /// it is disabled by default.
+    pub keep_raw_content: bool,
+    /// Leave out of [`Backend::get_chapters`] the entries of the chapter list
+    /// that are not part of the story, such as announcements or hiatus notices
+    /// (see [`Backend::is_story_chapter`]). Disabled by default.
+    pub skip_non_story_chapters: bool,
+    /// Fetch the print-friendly (or AMP, or mobile) version of chapter pages
+    /// instead of the standard one, for sites known to have one (see
+    /// `SiteTemplate::print_url_template`). Such pages have far fewer ads
+    /// to strip. The standard page is used when the print version cannot be
+    /// fetched or parsed. Disabled by default.
+    pub prefer_print_pages: bool,
+}
+
+impl Default for BackendOptions {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            max_chapters: DEFAULT_MAX_CHAPTERS,
+            title_override: None,
+            strip_navigation: false,
+            keep_raw_content: false,
+            skip_non_story_chapters: false,
+            prefer_print_pages: false,
+        }
+    }
+}
+
+/// Options of the backends not supporting any, see
+/// [`Backend::backend_options`].
+static DEFAULT_BACKEND_OPTIONS: LazyLock<BackendOptions> = LazyLock::new(BackendOptions::default);
+
+/// Chapters with more text than this (in bytes) are never considered
+/// placeholders.
+const MAX_PLACEHOLDER_LENGTH: usize = 300;
+
+/// Matches the text of the placeholders some sites serve (with HTTP 200)
+/// instead of a chapter.
+static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(chapter|content) (has been |has |was )?(moved|removed|deleted)|(chapter|content) (is )?(coming soon|not available|unavailable)|^\W*coming soon\W*$",
+    )
+    .unwrap()
+});
+
+/// Makes sure that fetching the chapter at `chapter_url` did not end up,
+/// through redirections, on the page of the fiction at `fiction_url` or on the
+/// site's home page. This is how some sites answer for deleted chapters, whose
+/// page would otherwise be parsed as a chapter.
+#[cfg_attr(
+    not(any(
+        feature = "royalroad",
+        feature = "freewebnovel",
+        feature = "lightnovelworld",
+        feature = "template",
+        feature = "scribblehub"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn check_not_redirected_home(
+    response: &Response,
+    chapter_url: &str,
+    fiction_url: &str,
+) -> Result<(), BackendError> {
+    check_not_redirected_home_at(response.url(), chapter_url, fiction_url)
+}
+
+/// Like [`check_not_redirected_home`], given the URL the request for
+/// `chapter_url` landed on.
+#[cfg_attr(
+    not(any(
+        feature = "royalroad",
+        feature = "freewebnovel",
+        feature = "lightnovelworld",
+        feature = "template",
+        feature = "scribblehub"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn check_not_redirected_home_at(
+    landed_url: &Url,
+    chapter_url: &str,
+    fiction_url: &str,
+) -> Result<(), BackendError> {
+    let location = |url: &Url| {
+        (
+            url.host_str().map(str::to_string),
+            url.path().trim_end_matches('/').to_string(),
+        )
+    };
+    let landed = location(landed_url);
+    let redirected = Url::parse(chapter_url).map_or(true, |url| location(&url) != landed);
+    let home = Url::parse(fiction_url).is_ok_and(|url| location(&url) == landed);
+    if redirected && (home || landed.1.is_empty()) {
+        warn!("{chapter_url} redirects to {landed_url}, the chapter must have been deleted");
+        return Err(BackendError::DeletedChapter(chapter_url.to_string()));
+    }
+    Ok(())
+}
+
+/// Tells whether the given chapter content is a placeholder rather than an
+/// actual chapter: a short text matching [`PLACEHOLDER_REGEX`].
+fn is_placeholder(content: &str) -> bool {
+    let text: String = Html::parse_fragment(content)
+        .root_element()
+        .text()
+        .collect();
+    let text = text.trim();
+    text.len() <= MAX_PLACEHOLDER_LENGTH && PLACEHOLDER_REGEX.is_match(text)
+}
+
+impl BackendOptions {
+    /// Describes the effective settings, one `name: value` line each, followed
+    /// by the default value of the settings that have been changed. The
+    /// settings of the default client's requests (User-Agent, rate limit,
+    /// circuit breaker…) are included, which makes this suitable for bug
+    /// reports. See [`Backends::describe`] for the ones of a backend's client.
+    ///
+    /// ```rust
+    /// use libwebnovel::backends::BackendOptions;
+    /// let options = BackendOptions {
+    ///     strict: true,
+    ///     ..Default::default()
+    /// };
+    /// assert!(options.describe().contains("strict: true (default: false)"));
+    /// ```
+    pub fn describe(&self) -> String {
+        self.describe_with_client(&HTTP_CLIENT)
+    }
+
+    /// Like [`BackendOptions::describe`], with the network settings of
+    /// `client`.
+    pub(crate) fn describe_with_client(&self, client: &HttpClient) -> String {
+        let default = BackendOptions::default();
+        let Self {
+            strict,
+            max_chapters,
+            title_override,
+            strip_navigation,
+            keep_raw_content,
+            skip_non_story_chapters,
+            prefer_print_pages,
+        } = self;
+        [
+            setting_line("strict", strict, default.strict),
+            setting_line("max_chapters", max_chapters, default.max_chapters),
+            setting_line("title_override", title_override, default.title_override),
+            setting_line(
+                "strip_navigation",
+                strip_navigation,
+                default.strip_navigation,
+            ),
+            setting_line(
+                "keep_raw_content",
+                keep_raw_content,
+                default.keep_raw_content,
+            ),
+            setting_line(
+                "skip_non_story_chapters",
+                skip_non_story_chapters,
+                default.skip_non_story_chapters,
+            ),
+            setting_line(
+                "prefer_print_pages",
+                prefer_print_pages,
+                default.prefer_print_pages,
+            ),
+            describe_network_settings(client),
+        ]
+        .join("\n")
+    }
+
+    /// Makes sure fetching `count` chapters stays within
+    /// [`max_chapters`][BackendOptions::max_chapters].
+    pub(crate) fn check_chapter_count(&self, count: usize) -> Result<(), BackendError> {
+        if count > self.max_chapters {
+            return Err(BackendError::TooManyChapters {
+                count,
+                max: self.max_chapters,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the given content to be stored as the raw content of a chapter
+    /// if [`keep_raw_content`][BackendOptions::keep_raw_content] is set.
+    pub(crate) fn raw_content(&self, content: &str) -> Option<String> {
+        self.keep_raw_content.then(|| content.to_string())
+    }
+
+    /// Applies the clean-ups enabled in these options to a freshly scraped
+    /// chapter.
+    pub(crate) fn clean_chapter(&self, chapter: &mut Chapter) {
+        if self.strip_navigation {
+            chapter.strip_navigation();
+        }
+    }
+
+    /// Makes sure the given chapter is not a placeholder (see
+    /// [`BackendError::PlaceholderChapter`]). In strict mode, also makes sure
+    /// it has a title & some content, as well as a publication date if
+    /// `expects_date` is set.
+    pub(crate) fn check_chapter(
+        &self,
+        chapter: &Chapter,
+        expects_date: bool,
+    ) -> Result<(), BackendError> {
+        if is_placeholder(chapter.content()) {
+            return Err(BackendError::PlaceholderChapter(
+                chapter.chapter_url().to_string(),
+            ));
+        }
+        if !self.strict {
+            return Ok(());
+        }
+        let mut missing = Vec::new();
+        if chapter.title().as_ref().is_none_or(|t| t.trim().is_empty()) {
+            missing.push("title");
+        }
+        if chapter.content().trim().is_empty() {
+            missing.push("content");
+        }
+        if expects_date && chapter.published_at().is_none() {
+            missing.push("publication date");
+        }
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(BackendError::ParseError(
+            format!(
+                "Missing {} for chapter {} ({})",
+                missing.join(", "),
+                chapter.index(),
+                chapter.chapter_url()
+            ),
+            None,
+        ))
+    }
+}
+
+/// Tells whether a chapter list is sorted newest-first, from the publication
+/// dates of its chapters (in the order they are listed). Such lists must be
+/// reversed, so that index 1 is always the earliest chapter.
+#[cfg(feature = "royalroad")]
+pub(crate) fn is_newest_first(listed_dates: &[DateTime<Utc>]) -> bool {
+    match (listed_dates.first(), listed_dates.last()) {
+        (Some(first), Some(last)) => first > last,
+        _ => false,
+    }
+}
+
+/// The identifying parts of a chapter URL, see [`Backend::parse_chapter_url`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChapterUrlParts {
+    /// Identifier of the fiction on its site.
+    pub fiction_id: String,
+    /// Identifier of the chapter, within the fiction on its site.
+    pub chapter_id: String,
+    /// The descriptive parts of the URL (usually made from the fiction &
+    /// chapter titles) that are not needed to identify the chapter, in the
+    /// order they appear in.
+    pub slugs: Vec<String>,
+}
+
+/// Describes which optional features a backend supports, so that callers can
+/// skip unsupported calls instead of handling errors. See
+/// [`Backend::capabilities`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BackendCapabilities {
+    /// The backend can find a cover image for the fiction.
+    pub has_cover: bool,
+    /// The backend can find the publication date of chapters.
+    pub has_dates: bool,
+    /// The backend can search fictions on its site.
+    pub supports_search: bool,
+    /// The backend can find the description (synopsis) of the fiction.
+    pub supports_description: bool,
+    /// The backend can find the tags/genres of the fiction.
+    pub supports_tags: bool,
+}
+
+/// Publication status of a fiction, see [`Backend::status`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FictionStatus {
+    /// New chapters are still being published
+    Ongoing,
+    /// The story is finished
+    Completed,
+    /// Publication has been paused, but may resume
+    Hiatus,
+    /// The author gave up on the story, or removed most of it (RoyalRoad's
+    /// "stubs")
+    Dropped,
+    /// The site does not tell, or uses a status this crate does not know
+    #[default]
+    Unknown,
+}
+
+impl FictionStatus {
+    /// Maps the status displayed by a site (such as "ONGOING" or "Completed")
+    /// to a [`FictionStatus`], [`FictionStatus::Unknown`] if it is not a known
+    /// one.
+    #[cfg_attr(
+        not(any(feature = "royalroad", feature = "freewebnovel")),
+        allow(dead_code)
+    )]
+    pub(crate) fn from_label(label: &str) -> Self {
+        match label.trim().to_lowercase().as_str() {
+            "ongoing" | "active" | "publishing" => FictionStatus::Ongoing,
+            "completed" | "complete" | "finished" => FictionStatus::Completed,
+            "hiatus" | "on hiatus" => FictionStatus::Hiatus,
+            "dropped" | "stub" | "discontinued" | "cancelled" => FictionStatus::Dropped,
+            _ => FictionStatus::Unknown,
+        }
+    }
+}
+
+/// An [`Iterator`] over the chapters of a fiction, obtained by starting at its
+/// first chapter and following the links to the next chapters. See
+/// [`Backend::walk_from_first_chapter`].
+///
+/// The walk stops when a chapter has no next chapter, when the next chapter
+/// has already been visited (self-referential links), after
+/// [`limit`][ChapterWalk::limit] chapters if set, or after the first error.
+/// Both bounds apply: going past [`BackendOptions::max_chapters`] yields a
+/// [`BackendError::TooManyChapters`] and ends the walk, so a limit above it
+/// only makes a difference if the fiction has fewer chapters.
+pub struct ChapterWalk<'a, B: Backend> {
+    backend: &'a B,
+    next_url: Option<Result<String, BackendError>>,
+    visited: HashSet<String>,
+    limit: Option<usize>,
+}
+
+impl<'a, B: Backend> ChapterWalk<'a, B> {
+    fn new(backend: &'a B) -> Self {
+        Self {
+            backend,
+            next_url: Some(backend.first_chapter_url()),
+            visited: HashSet::new(),
+            limit: None,
+        }
+    }
+
+    /// Sets the number of chapters to walk through, after which the walk ends
+    /// without error, such as the few chapters of a preview. Unlike
+    /// [`BackendOptions::max_chapters`], a safeguard failing the walk with
+    /// [`BackendError::TooManyChapters`], reaching it is expected. Without it,
+    /// the walk is only bounded by [`BackendOptions::max_chapters`].
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl<B: Backend> Iterator for ChapterWalk<'_, B> {
+    type Item = Result<Chapter, BackendError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.limit.is_some_and(|limit| self.visited.len() >= limit) {
+            return None;
+        }
+        let url = match self.next_url.take()? {
+            Ok(url) => url,
+            Err(e) => return Some(Err(e)),
+        };
+        if !self.visited.insert(url.clone()) {
+            return None;
+        }
+        if let Err(e) = self
+            .backend
+            .backend_options()
+            .check_chapter_count(self.visited.len())
+        {
+            return Some(Err(e));
+        }
+        match self.backend.get_linked_chapter(self.visited.len(), &url) {
+            Ok((chapter, next_url)) => {
+                self.next_url = next_url.map(Ok);
+                Some(Ok(chapter))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 
 type ChapterOrderingFn = Box<dyn Fn(&Chapter, &Chapter) -> Ordering>;
+
+/// Returns the integer at the end of the path of the given chapter URL, if any
+/// (`12` for `https://example.com/fiction/chapter-12/`).
+fn chapter_url_suffix(chapter_url: &str) -> Option<u64> {
+    let path = chapter_url.split(['?', '#']).next()?.trim_end_matches('/');
+    let digits_start = path
+        .rfind(|c: char| !c.is_ascii_digit())
+        .map_or(0, |i| i + 1);
+    path[digits_start..].parse().ok()
+}
+
+/// An ordering function for sites whose chapter titles & dates are unreliable
+/// but whose chapter URLs end with a sequential number (`/chapter-12`).
+/// Chapters are ordered by that number, and chapters without one come last.
+pub fn url_suffix_ordering() -> ChapterOrderingFn {
+    Box::new(|c1: &Chapter, c2: &Chapter| {
+        let suffix_1 = chapter_url_suffix(c1.chapter_url());
+        let suffix_2 = chapter_url_suffix(c2.chapter_url());
+        match (suffix_1, suffix_2) {
+            (Some(n1), Some(n2)) => n1.cmp(&n2),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    })
+}
 pub(crate) type ChapterListElem = (usize, String);
 impl TryFrom<&Chapter> for ChapterListElem {
     type Error = BackendError;
 
+    /// Chapters without a title (some sites, such as Syosetu, only number
+    /// them) are titled "Chapter {index}".
     fn try_from(value: &Chapter) -> Result<Self, Self::Error> {
         Ok((
             value.index,
-            value
-                .title()
-                .as_ref()
-                .ok_or(BackendError::MissingChapterInformation {
-                    msg: "Could not find a valid title".to_string(),
-                    chapter: Box::new(value.clone()),
-                })?
-                .to_string(),
+            chapter_title_or_untitled(value.title().as_deref().unwrap_or_default(), value.index),
         ))
     }
 }
 
+/// Guesses the language of a fiction from the scripts used in its first
+/// chapters. See [`Backend::language`].
+fn sampled_language(backend: &impl Backend) -> Result<String, BackendError> {
+    let mut sample = String::new();
+    for (index, _) in backend
+        .get_chapter_list()?
+        .into_iter()
+        .take(LANGUAGE_SAMPLE_CHAPTERS)
+    {
+        sample.push_str(&backend.get_chapter(index)?.content_as_text());
+    }
+    Ok(guess_language(&sample)
+        .unwrap_or(DEFAULT_LANGUAGE)
+        .to_string())
+}
+
+/// Matches the titles of chapter list entries that are not part of the story
+/// (see [`Backend::is_story_chapter`]): a title made only of a word such as
+/// "Announcement" or "Hiatus", possibly qualified ("Important announcement"),
+/// between brackets or followed by punctuation. Numbered chapters ("Chapter
+/// 12: Hiatus") are part of the story.
+static NON_STORY_TITLE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^\W*((important|small|quick|short|brief|hiatus|schedule|update)\s+)?(announcements?|hiatus|a/n|author'?s notes?|authors? notes?|not a chapter|patreon( update)?|shout-?outs?|taking a break|on break|break week|schedule (update|change)|update schedule|delay(ed)? notice)\W*$",
+    )
+    .unwrap()
+});
+
+/// Returns the given chapter title, or "Chapter {index}" if it is blank, so
+/// that untitled chapters can still be listed & told apart.
+pub(crate) fn chapter_title_or_untitled(title: &str, index: usize) -> String {
+    let title = title.trim();
+    if title.is_empty() {
+        format!("Chapter {index}")
+    } else {
+        title.to_string()
+    }
+}
+
 /// Must be implemented by each backend.
 ///
 /// ## How to implement a new backend ?
@@ -117,16 +949,96 @@ where
     /// An identifier for the backend. It is static and can be used for
     /// long-term storage.
     fn get_backend_name() -> &'static str;
+    /// Describes what this backend is capable of. The default implementation
+    /// claims no optional capability at all.
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
     /// Returns a function enabling chapter ordering. This is important to
     /// ensure that chapters may still be correctly sorted when the source
     /// chapters have been removed.
     fn get_ordering_function() -> ChapterOrderingFn {
         Box::new(|c1: &Chapter, c2: &Chapter| c1.published_at().cmp(c2.published_at()))
     }
+    /// Splits a chapter URL of this backend's site into the fiction & chapter
+    /// identifiers it contains, so that tools storing chapter URLs can make
+    /// sense of them. Returns [`None`] if the URL is not a chapter URL of this
+    /// site, which is what the default implementation always does.
+    #[allow(unused_variables)]
+    fn parse_chapter_url(url: &str) -> Option<ChapterUrlParts> {
+        None
+    }
+    /// Returns the URLs of the fictions listed on the given user profile page
+    /// of this backend's site (the fictions written by that user), so that a
+    /// whole catalog can be downloaded by feeding them to [`Backend::new`].
+    /// Returns [`BackendError::NoMatchingBackendFound`] if the URL is not a
+    /// profile URL of this site. The default implementation returns
+    /// [`BackendError::Unsupported`].
+    #[allow(unused_variables)]
+    fn fictions_from_profile(profile_url: &str) -> Result<Vec<String>, BackendError> {
+        Err(BackendError::Unsupported("profile pages".to_string()))
+    }
     /// Creates a new instance of itself
     fn new(url: &str) -> Result<Self, BackendError>;
+    /// Creates a new instance of itself, sending every request with an HTTP
+    /// client configured by `config` (custom User-Agent, headers…). The
+    /// default implementation calls [`Backend::new`] for the default
+    /// configuration, and returns [`BackendError::Unsupported`] for any other
+    /// one.
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        if *config != ClientConfig::default() {
+            return Err(BackendError::Unsupported(
+                "custom HTTP client configurations".to_string(),
+            ));
+        }
+        Self::new(url)
+    }
+    /// Returns the HTTP client this backend sends its requests with. The
+    /// default implementation returns the one used without a [`ClientConfig`].
+    fn http_client(&self) -> &HttpClient {
+        &HTTP_CLIENT
+    }
+    /// Creates a new instance of itself, using the given options. The default
+    /// implementation calls [`Backend::new`], then
+    /// [`Backend::set_backend_options`].
+    fn new_with_options(url: &str, options: BackendOptions) -> Result<Self, BackendError> {
+        let mut backend = Self::new(url)?;
+        backend.set_backend_options(options);
+        Ok(backend)
+    }
+    /// Returns the options in use by this backend. The default implementation
+    /// returns the [default options][BackendOptions::default], for backends
+    /// that don't support options.
+    fn backend_options(&self) -> &BackendOptions {
+        &DEFAULT_BACKEND_OPTIONS
+    }
+    /// Changes the options used by this backend. The default implementation
+    /// ignores them, with a warning.
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        if options != *self.backend_options() {
+            warn!(
+                "The {} backend does not support options, ignoring them: {options:?}",
+                Self::get_backend_name()
+            );
+        }
+    }
     /// Returns the title of the fiction
     fn title(&self) -> Result<String, BackendError>;
+    /// Returns the title to use in output (file names, epub metadata…): the
+    /// [`title_override`][BackendOptions::title_override] if any, the scraped
+    /// [`title`][Backend::title] otherwise.
+    fn output_title(&self) -> Result<String, BackendError> {
+        match &self.backend_options().title_override {
+            Some(title) if !title.trim().is_empty() => Ok(title.trim().to_string()),
+            _ => self.title(),
+        }
+    }
+    /// Returns a name for the files generated for this fiction (such as an
+    /// epub), without extension: the [slugified][crate::slugify]
+    /// [`output_title`][Backend::output_title].
+    fn output_file_stem(&self) -> Result<String, BackendError> {
+        Ok(slugify(&self.output_title()?))
+    }
     /// Returns _something_ that can be used to identify this novel, and won't
     /// change if (for instance) the title changes.
     fn immutable_identifier(&self) -> Result<String, BackendError>;
@@ -134,6 +1046,24 @@ where
     fn url(&self) -> String;
     /// Returns the fictions' cover URL, if any
     fn cover_url(&self) -> Result<String, BackendError>;
+    /// Returns the URL of the cover at the given size, for sites serving
+    /// covers at several sizes, so that UIs can fetch small thumbnails or
+    /// high-resolution covers. The default implementation ignores the size and
+    /// returns [`Backend::cover_url`].
+    #[allow(unused_variables)]
+    fn cover_url_sized(&self, width: u32, height: u32) -> Result<String, BackendError> {
+        self.cover_url()
+    }
+    /// Returns the URL of the icon of the site, so that UIs can show where a
+    /// fiction comes from. Nothing is fetched. The default implementation
+    /// returns `/favicon.ico` on the host of [`Backend::url`], or an empty
+    /// string if the latter is not a valid URL.
+    fn site_icon_url(&self) -> String {
+        Url::parse(&self.url())
+            .and_then(|url| url.join("/favicon.ico"))
+            .map(|url| url.to_string())
+            .unwrap_or_default()
+    }
 
     /// Returns a list of authors, if any
     fn get_authors(&self) -> Result<Vec<String>, BackendError>;
@@ -150,41 +1080,487 @@ where
     /// some webnovel platforms allow truncating the chapter list.
     fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError>;
 
+    /// Returns what can be known about a chapter from the chapter list, without
+    /// fetching the chapter itself. This is much cheaper than
+    /// [`Backend::get_chapter`] to build a table of contents or check
+    /// publication dates. The default implementation only finds the title in
+    /// [`Backend::get_chapter_list`].
+    fn get_chapter_info(&self, chapter_number: usize) -> Result<ChapterInfo, BackendError> {
+        let (index, title) = self
+            .get_chapter_list()?
+            .into_iter()
+            .find(|(index, _)| *index == chapter_number)
+            .ok_or(BackendError::UnknownChapter(chapter_number))?;
+        let mut info = ChapterInfo::default();
+        info.set_index(index);
+        info.set_title(Some(title));
+        Ok(info)
+    }
+
+    /// Returns the [`ChapterInfo`] of every chapter of the chapter list, in
+    /// order. Backends overriding [`Backend::get_chapter_info`] should
+    /// override this too, so that the infos are built in a single pass over
+    /// the chapter list rather than reading it once per chapter. The default
+    /// implementation only finds the titles in [`Backend::get_chapter_list`].
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        Ok(single_volume(self.get_chapter_list()?).chapters)
+    }
+
+    /// Returns the chapters grouped by volume (or arc), for sites listing
+    /// chapters under volume headers. This allows building a nested table of
+    /// contents. The default implementation returns a single unnamed volume
+    /// containing all the chapters of [`Backend::get_chapter_list`].
+    fn get_volumes(&self) -> Result<Vec<Volume>, BackendError> {
+        Ok(vec![single_volume(self.get_chapter_list()?)])
+    }
+
     /// Must return the total chapter count. Default implementation calls
     /// [`self.get_chapter_list().len()`][Backend::get_chapter_list()].
     fn get_chapter_count(&self) -> Result<usize, BackendError> {
         Ok(self.get_chapter_list()?.len())
     }
 
+    /// Tells whether an entry of the chapter list is part of the story, as
+    /// opposed to announcements, hiatus notices or author's notes posted as
+    /// chapters. The default implementation only looks at the title, and
+    /// conservatively: a title merely mentioning an announcement ("The
+    /// Announcement", "Announcement: book 2 is out") or a numbered chapter
+    /// ("Chapter 12: Hiatus") is a story chapter, while "Announcement" or
+    /// "\[Hiatus\]" are not.
+    fn is_story_chapter(&self, info: &ChapterInfo) -> bool {
+        info.title()
+            .as_deref()
+            .is_none_or(|title| !NON_STORY_TITLE_REGEX.is_match(title))
+    }
+
     /// Returns all chapters for this fiction. The default implementation simply
-    /// calls [`Self::get_chapter`] repeatedly
+    /// calls [`Self::get_chapter`] repeatedly, after making sure the chapter
+    /// count does not exceed [`BackendOptions::max_chapters`]. With
+    /// [`BackendOptions::skip_non_story_chapters`], chapters that are [not part
+    /// of the story][Backend::is_story_chapter] are not fetched.
     fn get_chapters(&self) -> Result<Vec<Chapter>, BackendError> {
+        let count = self.get_chapter_count()?;
+        self.backend_options().check_chapter_count(count)?;
+        let skipped = non_story_chapters(self)?;
         let mut chapters = Vec::new();
-        for i in 1..self.get_chapter_count()? {
+        for i in (1..=count).filter(|i| !skipped.contains(i)) {
             let chapter = self.get_chapter(i)?;
             chapters.push(chapter);
         }
         Ok(chapters)
     }
 
-    /// Returns the fictions' cover as a byte array, if any.
-    fn cover(&self) -> Result<Vec<u8>, BackendError> {
-        let resp = get(self.cover_url()?)?;
-        if !resp.status().is_success() {
-            return Err(BackendError::RequestFailed {
-                message: "Could not download cover image".to_string(),
-                status: resp.status(),
-                content: resp.text()?,
-            });
+    /// Returns at most the first `n` chapters of this fiction, which is enough
+    /// to preview it. Chapters are fetched one at a time from the first one,
+    /// stopping at the `n`th one or at the first [unknown
+    /// chapter][BackendError::UnknownChapter], without counting chapters first.
+    /// Since the chapter count is never known, [`BackendOptions::max_chapters`]
+    /// does not apply: `n` is the only bound, like the
+    /// [`limit`][ChapterWalk::limit] of a walk.
+    fn get_first_chapters(&self, n: usize) -> Result<Vec<Chapter>, BackendError> {
+        let mut chapters = Vec::with_capacity(n);
+        for i in 1..=n {
+            match self.get_chapter(i) {
+                Ok(chapter) => chapters.push(chapter),
+                Err(BackendError::UnknownChapter(_)) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(chapters)
+    }
+
+    /// Fetches the chapters with the given (possibly non-contiguous) indices,
+    /// such as the ones that failed during a previous download. Results are
+    /// returned in the order of `indices`, so that callers know which chapters
+    /// have been fetched. See [`Backend::get_chapters_concurrent`] to fetch
+    /// them several at a time.
+    fn get_chapters_by_indices(&self, indices: &[usize]) -> Vec<Result<Chapter, BackendError>> {
+        indices
+            .iter()
+            .map(|&chapter_number| self.get_chapter(chapter_number))
+            .collect()
+    }
+
+    /// Returns all chapters for this fiction like [`Backend::get_chapters`],
+    /// but sorted with [`Backend::get_ordering_function`] rather than in the
+    /// order of the chapter list. Chapters are then re-numbered from 1, so that
+    /// their index matches their position. This is useful for sites whose
+    /// chapter list is not in reading order.
+    fn get_chapters_ordered(&self) -> Result<Vec<Chapter>, BackendError> {
+        let mut chapters = self.get_chapters()?;
+        chapters.sort_by(Self::get_ordering_function());
+        for (i, chapter) in chapters.iter_mut().enumerate() {
+            chapter.set_index(i + 1);
+        }
+        Ok(chapters)
+    }
+
+    /// Fetches the chapter following the given one in reading order, which is
+    /// useful to a reader tracking its progress. Returns [`None`] if the given
+    /// chapter is the last one.
+    ///
+    /// The chapter is found in [`Backend::get_chapter_infos`] by its URL,
+    /// rather than by its index: indices shift on sites numbering chapters by
+    /// their position in the list, when a chapter is removed upstream. If the
+    /// given chapter is not listed anymore, its successor is the first listed
+    /// chapter that comes after it according to
+    /// [`Backend::get_ordering_function`], found by fetching a few chapters.
+    fn get_next_after(&self, chapter: &Chapter) -> Result<Option<Chapter>, BackendError> {
+        let infos = self.get_chapter_infos()?;
+        if let Some(position) = infos
+            .iter()
+            .position(|info| info.chapter_url().as_ref() == Some(chapter.chapter_url()))
+        {
+            return infos
+                .get(position + 1)
+                .map(|info| self.get_chapter(*info.index()))
+                .transpose();
+        }
+        let ordering = Self::get_ordering_function();
+        let (mut low, mut high) = (0, infos.len());
+        let mut next = None;
+        while low < high {
+            let middle = (low + high) / 2;
+            let candidate = self.get_chapter(*infos[middle].index())?;
+            if ordering(&candidate, chapter) == Ordering::Greater {
+                high = middle;
+                next = Some(candidate);
+            } else {
+                low = middle + 1;
+            }
+        }
+        Ok(next)
+    }
+
+    /// Fetches the chapters with the given indices, several at a time. Results
+    /// are returned in the order of `indices`; failed chapters come with their
+    /// index, so that only those can be fetched again later.
+    ///
+    /// Since backends cannot be shared between threads, each worker uses its
+    /// own clone of this backend: nothing is fetched again, and requests are
+    /// sent with the same client, sharing its configuration & rate limit.
+    fn get_chapters_concurrent(
+        &self,
+        indices: &[usize],
+    ) -> Vec<Result<Chapter, (usize, BackendError)>>
+    where
+        Self: Clone + Send,
+    {
+        get_chapters_concurrently(indices, || self.clone())
+    }
+
+    /// Returns the URL of the first chapter of the fiction, for backends able
+    /// to [walk through the chapters][Backend::walk_from_first_chapter]. The
+    /// default implementation returns [`BackendError::Unsupported`].
+    fn first_chapter_url(&self) -> Result<String, BackendError> {
+        Err(BackendError::Unsupported(
+            "walking through chapters".to_string(),
+        ))
+    }
+
+    /// Fetches the chapter at the given URL, giving it the given index, and
+    /// returns it along with the URL of the next chapter, if any. The default
+    /// implementation returns [`BackendError::Unsupported`].
+    #[allow(unused_variables)]
+    fn get_linked_chapter(
+        &self,
+        chapter_number: usize,
+        chapter_url: &str,
+    ) -> Result<(Chapter, Option<String>), BackendError> {
+        Err(BackendError::Unsupported(
+            "walking through chapters".to_string(),
+        ))
+    }
+
+    /// Returns an iterator over the chapters, starting at the first one and
+    /// following the links to the next chapters until there are none left.
+    /// This supports sites with no usable chapter list, and fetches each
+    /// chapter only once. See [`ChapterWalk`].
+    fn walk_from_first_chapter(&self) -> ChapterWalk<'_, Self> {
+        ChapterWalk::new(self)
+    }
+
+    /// Returns the typical interval between two chapter releases, computed as
+    /// the median interval between the publication dates of the most recent
+    /// chapters. This can be used to predict when the next chapter will be
+    /// out. Returns [`None`] if there aren't enough dated chapters, which is
+    /// what the default implementation always does.
+    fn release_cadence(&self) -> Result<Option<Duration>, BackendError> {
+        Ok(None)
+    }
+
+    /// Tells whether a chapter has been published after `since`, according to
+    /// the publication date of the most recent chapter in the
+    /// [chapter list][Backend::get_chapter_info]. No chapter is fetched, so a
+    /// watcher can cheaply call this on a schedule. Returns
+    /// [`BackendError::Unsupported`] if the chapter list has no dates.
+    fn has_updates_since(&self, since: DateTime<Utc>) -> Result<bool, BackendError> {
+        let unsupported =
+            || BackendError::Unsupported("publication dates in the chapter list".to_string());
+        if !Self::capabilities().has_dates {
+            return Err(unsupported());
+        }
+        let Some((latest, _)) = self.get_chapter_list()?.pop() else {
+            return Ok(false);
+        };
+        match self.get_chapter_info(latest)?.published_at() {
+            Some(published_at) => Ok(*published_at > since),
+            None => Err(unsupported()),
+        }
+    }
+
+    /// Returns an estimation of the total size of the chapters' content, in
+    /// bytes, for progress reporting: chapters vary wildly in length, so the
+    /// chapter count alone makes for a poor progress bar. Only sites publishing
+    /// word counts allow it; the default implementation returns `None`.
+    fn estimated_total_bytes(&self) -> Result<Option<u64>, BackendError> {
+        Ok(None)
+    }
+
+    /// Returns identifiers of the fiction that are external to this crate, such
+    /// as the site's own fiction id, an ISBN, or its canonical URL. Those can
+    /// for instance be used as unique identifiers in an epub. Keys are
+    /// backend-specific. The default implementation returns an empty map.
+    fn external_identifiers(&self) -> Result<HashMap<String, String>, BackendError> {
+        Ok(HashMap::new())
+    }
+
+    /// Returns the content warnings the site attached to the fiction (such as
+    /// "Gore" or "Profanity"), allowing to filter fictions. The default
+    /// implementation returns an empty list, as does a fiction without
+    /// warnings.
+    fn content_warnings(&self) -> Result<Vec<String>, BackendError> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the genres & tags the site attached to the fiction (such as
+    /// "Fantasy" or "Time Loop"), allowing to categorize fictions. The default
+    /// implementation returns an empty list.
+    fn get_tags(&self) -> Result<Vec<String>, BackendError> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the description (synopsis) of the fiction, as HTML, such as
+    /// the blurb to put on the title page of an epub. The default
+    /// implementation returns [`BackendError::Unsupported`].
+    fn description(&self) -> Result<String, BackendError> {
+        Err(BackendError::Unsupported(
+            "fiction descriptions".to_string(),
+        ))
+    }
+
+    /// Returns the publication status of the fiction, telling whether it is
+    /// worth checking it for new chapters. The default implementation returns
+    /// [`FictionStatus::Unknown`], as do backends when the site does not
+    /// display a status they know.
+    fn status(&self) -> Result<FictionStatus, BackendError> {
+        Ok(FictionStatus::Unknown)
+    }
+
+    /// Returns the metadata of the fiction. The default implementation gathers
+    /// it from the other methods of this trait; a missing cover or language is
+    /// not considered an error. Note that finding the
+    /// [language][Backend::language] may require fetching a few chapters.
+    fn metadata(&self) -> Result<FictionMetadata, BackendError> {
+        let mut metadata = FictionMetadata::default();
+        metadata.set_url(self.url());
+        metadata.set_title(self.title()?);
+        metadata.set_authors(self.get_authors()?);
+        metadata.set_cover_url(self.cover_url().ok());
+        metadata.set_chapter_count(self.get_chapter_count()?);
+        metadata.set_language(self.declared_language());
+        Ok(metadata)
+    }
+
+    /// Returns the language declared by the site for the fiction (such as the
+    /// `lang` attribute of its page), as a BCP-47 tag. Unlike
+    /// [`Backend::language`], this never fetches any chapter. The default
+    /// implementation returns [`None`].
+    fn declared_language(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the primary language of the fiction as a BCP-47 tag (such as
+    /// `en` or `zh-CN`), suitable as the `dc:language` of an epub: the
+    /// [declared language][Backend::declared_language] when there is one.
+    /// Otherwise, it is guessed from the scripts used in the first chapters,
+    /// which are downloaded for that. This only recognizes languages with a
+    /// script of their own: the default is then [`DEFAULT_LANGUAGE`].
+    fn language(&self) -> Result<String, BackendError> {
+        self.declared_language()
+            .map_or_else(|| sampled_language(self), Ok)
+    }
+
+    /// Returns a UUID identifying the fiction, suitable as the `dc:identifier`
+    /// of an epub. It is a v5 UUID derived from the backend name & the
+    /// [immutable identifier][Backend::immutable_identifier] of the fiction,
+    /// so the same fiction always gets the same UUID, and re-generated epubs
+    /// keep their identity in e-reader libraries.
+    #[cfg(feature = "uuid")]
+    fn fiction_uuid(&self) -> Result<Uuid, BackendError> {
+        Ok(fiction_uuid(
+            Self::get_backend_name(),
+            &self.immutable_identifier()?,
+        ))
+    }
+
+    /// Returns the fictions' cover as a byte array, if any. Returns
+    /// [`None`] both when the fiction has no cover URL and when there is
+    /// nothing at that URL anymore (HTTP 404), since a missing cover should not
+    /// prevent using the rest of the fiction.
+    fn cover(&self) -> Result<Option<Vec<u8>>, BackendError> {
+        match self.cover_url() {
+            Ok(cover_url) => download_cover(self.http_client(), &cover_url),
+            Err(BackendError::ParseError(..)) | Err(BackendError::UrlNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Fetches again the chapters previously downloaded, given as `(index,
+    /// content hash)` pairs (see [`Chapter::content_hash`]), and returns only
+    /// those whose content has changed upstream since then.
+    fn refresh_changed(&self, stored: &[(usize, String)]) -> Result<Vec<Chapter>, BackendError> {
+        let mut changed = Vec::new();
+        for (index, content_hash) in stored {
+            let chapter = self.get_chapter(*index)?;
+            if &chapter.content_hash() != content_hash {
+                debug!("Chapter {index} of {} has changed", self.url());
+                changed.push(chapter);
+            }
+        }
+        Ok(changed)
+    }
+}
+
+/// Namespace of the UUIDs returned by [`Backend::fiction_uuid`].
+#[cfg(feature = "uuid")]
+static FICTION_UUID_NAMESPACE: LazyLock<Uuid> = LazyLock::new(|| {
+    Uuid::new_v5(
+        &Uuid::NAMESPACE_URL,
+        b"https://codeberg.org/paulollivier/libwebnovel",
+    )
+});
+
+/// Implementation of [`Backend::fiction_uuid`].
+#[cfg(feature = "uuid")]
+fn fiction_uuid(backend_name: &str, immutable_identifier: &str) -> Uuid {
+    Uuid::new_v5(
+        &FICTION_UUID_NAMESPACE,
+        format!("{backend_name}/{immutable_identifier}").as_bytes(),
+    )
+}
+
+/// Downloads the cover at the given URL. Returns [`None`] if there is nothing
+/// at this URL anymore (HTTP 404).
+fn download_cover(client: &HttpClient, cover_url: &str) -> Result<Option<Vec<u8>>, BackendError> {
+    let resp = match client.get(cover_url) {
+        Ok(resp) => resp,
+        Err(BackendError::UrlNotFound) => {
+            warn!("The cover URL {cover_url} is dead, ignoring the cover");
+            return Ok(None);
         }
-        let image_bytes = resp.bytes()?;
-        Ok(image_bytes.to_vec())
+        Err(e) => return Err(e),
+    };
+    if !resp.status().is_success() {
+        return Err(BackendError::RequestFailed {
+            message: "Could not download cover image".to_string(),
+            status: resp.status(),
+            content: resp.text()?,
+        });
     }
+    let image_bytes = resp.bytes()?;
+    Ok(Some(image_bytes.to_vec()))
+}
+
+/// Maximum number of covers downloaded at the same time by [`fetch_covers`].
+const MAX_CONCURRENT_COVER_DOWNLOADS: usize = 4;
+
+/// Fetches the covers of the fictions at the given URLs, with a bounded
+/// concurrency. This is faster than building each backend & calling
+/// [`Backend::cover`] in turn, which is useful to build thumbnails for many
+/// fictions.
+///
+/// Results are returned in the order of the given URLs, as `(cover URL, cover
+/// bytes)` tuples. Fictions without a (live) cover get a
+/// [`BackendError::UrlNotFound`].
+///
+/// ```rust
+/// use libwebnovel::backends::fetch_covers;
+/// let covers = fetch_covers(&["https://www.royalroad.com/fiction/21220/mother-of-learning"]);
+/// let (cover_url, cover) = covers[0].as_ref().unwrap();
+/// assert!(cover_url.starts_with("https://www.royalroadcdn.com/"));
+/// assert!(!cover.is_empty());
+/// ```
+pub fn fetch_covers(urls: &[&str]) -> Vec<Result<(String, Vec<u8>), BackendError>> {
+    fetch_covers_with(urls, |url| Backends::new(url)?.cover_url())
+}
+
+/// Implementation of [`fetch_covers`], using `resolve_cover_url` to get the
+/// cover URL of a fiction.
+fn fetch_covers_with(
+    urls: &[&str],
+    resolve_cover_url: impl Fn(&str) -> Result<String, BackendError> + Sync,
+) -> Vec<Result<(String, Vec<u8>), BackendError>> {
+    let fetch_cover = |url: &str| {
+        let cover_url = resolve_cover_url(url)?;
+        let cover = download_cover(&HTTP_CLIENT, &cover_url)?.ok_or(BackendError::UrlNotFound)?;
+        Ok((cover_url, cover))
+    };
+    let next_url = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(urls.len()));
+    thread::scope(|scope| {
+        for _ in 0..MAX_CONCURRENT_COVER_DOWNLOADS.min(urls.len()) {
+            scope.spawn(|| loop {
+                let i = next_url.fetch_add(1, atomic::Ordering::Relaxed);
+                let Some(url) = urls.get(i) else {
+                    break;
+                };
+                let result = fetch_cover(url);
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Maximum number of chapters downloaded at the same time by
+/// [`Backend::get_chapters_concurrent`].
+const MAX_CONCURRENT_CHAPTER_DOWNLOADS: usize = 4;
+
+/// Implementation of [`Backend::get_chapters_concurrent`], each worker using a
+/// backend made by `new_worker`.
+fn get_chapters_concurrently<B: Backend + Send>(
+    indices: &[usize],
+    mut new_worker: impl FnMut() -> B,
+) -> Vec<Result<Chapter, (usize, BackendError)>> {
+    let next_index = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(indices.len()));
+    thread::scope(|scope| {
+        for _ in 0..MAX_CONCURRENT_CHAPTER_DOWNLOADS.min(indices.len()) {
+            let backend = new_worker();
+            let (next_index, results) = (&next_index, &results);
+            scope.spawn(move || loop {
+                let i = next_index.fetch_add(1, atomic::Ordering::Relaxed);
+                let Some(&chapter_number) = indices.get(i) else {
+                    break;
+                };
+                let result = backend
+                    .get_chapter(chapter_number)
+                    .map_err(|e| (chapter_number, e));
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
 }
 
 /// Enum listing all available backends. A new backend may be constructed using
 /// [`Backends::new`].
-#[derive(EnumCount, EnumIter, Debug, Default)]
+#[derive(EnumCount, EnumIter, Debug, Default, Clone)]
 pub enum Backends {
     /// A dumb backend that should never be constructed, but is necessary for
     /// iteration (with [`strum::EnumIter`] & other features.
@@ -192,18 +1568,31 @@ pub enum Backends {
     Dumb,
     #[cfg(feature = "royalroad")]
     /// A RoyalRoad backend
-    RoyalRoad(RoyalRoad),
+    RoyalRoad(Box<RoyalRoad>),
     #[cfg(feature = "libread")]
     /// A LibRead backend
-    LibRead(LibRead),
+    LibRead(Box<LibRead>),
     #[cfg(feature = "freewebnovel")]
     /// A FreeWebNovel backend
-    FreeWebNovel(FreeWebNovel),
+    FreeWebNovel(Box<FreeWebNovel>),
     /// A LightNovelWorld backend
     #[cfg(feature = "lightnovelworld")]
-    LightNovelWorld(LightNovelWorld),
+    LightNovelWorld(Box<LightNovelWorld>),
+    #[cfg(feature = "dreame")]
+    /// A Dreame backend
+    Dreame(Box<Dreame>),
+    #[cfg(feature = "scribblehub")]
+    /// A ScribbleHub backend
+    ScribbleHub(Box<ScribbleHub>),
 }
 
+// With the `all` feature, every backend must be a variant of [`Backends`].
+#[cfg(feature = "all")]
+const _: () = assert!(
+    Backends::COUNT == 7,
+    "Backends::COUNT does not match the number of backends: did you forget to add a backend to the `all` feature?"
+);
+
 impl Backends {
     /// Returns the ordering function specific to the underlying backend.
     ///
@@ -223,21 +1612,47 @@ impl Backends {
             Backends::FreeWebNovel(_) => FreeWebNovel::get_ordering_function(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(_) => LightNovelWorld::get_ordering_function(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(_) => Dreame::get_ordering_function(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(_) => ScribbleHub::get_ordering_function(),
         }
     }
 
-    /// Creates a new [`Backends`] variant from the given URL.
-    pub(crate) fn new_from_url(&self, url: &str) -> Result<Backends, BackendError> {
+    /// Creates a new [`Backends`] variant from the given URL, sending requests
+    /// with a client configured by `config`.
+    #[allow(unused_variables)]
+    pub(crate) fn new_from_url(
+        &self,
+        url: &str,
+        config: &ClientConfig,
+    ) -> Result<Backends, BackendError> {
         match self {
             Backends::Dumb => Ok(Self::Dumb),
             #[cfg(feature = "royalroad")]
-            Backends::RoyalRoad(_) => Ok(Self::RoyalRoad(RoyalRoad::new(url)?)),
+            Backends::RoyalRoad(_) => Ok(Self::RoyalRoad(Box::new(RoyalRoad::new_with_config(
+                url, config,
+            )?))),
             #[cfg(feature = "libread")]
-            Backends::LibRead(_) => Ok(Self::LibRead(LibRead::new(url)?)),
+            Backends::LibRead(_) => Ok(Self::LibRead(Box::new(LibRead::new_with_config(
+                url, config,
+            )?))),
             #[cfg(feature = "freewebnovel")]
-            Backends::FreeWebNovel(_) => Ok(Self::FreeWebNovel(FreeWebNovel::new(url)?)),
+            Backends::FreeWebNovel(_) => Ok(Self::FreeWebNovel(Box::new(
+                FreeWebNovel::new_with_config(url, config)?,
+            ))),
             #[cfg(feature = "lightnovelworld")]
-            Backends::LightNovelWorld(_) => Ok(Self::LightNovelWorld(LightNovelWorld::new(url)?)),
+            Backends::LightNovelWorld(_) => Ok(Self::LightNovelWorld(Box::new(
+                LightNovelWorld::new_with_config(url, config)?,
+            ))),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(_) => Ok(Self::Dreame(Box::new(Dreame::new_with_config(
+                url, config,
+            )?))),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(_) => Ok(Self::ScribbleHub(Box::new(
+                ScribbleHub::new_with_config(url, config)?,
+            ))),
         }
     }
 
@@ -254,21 +1669,333 @@ impl Backends {
             Backends::FreeWebNovel(_) => FreeWebNovel::get_backend_regexps(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(_) => LightNovelWorld::get_backend_regexps(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(_) => Dreame::get_backend_regexps(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(_) => ScribbleHub::get_backend_regexps(),
         }
     }
 
-    /// Returns the underlying backend name.
-    pub fn get_backend_name(&self) -> &'static str {
-        match self {
-            Backends::Dumb => "dummy",
-            #[cfg(feature = "royalroad")]
-            Backends::RoyalRoad(_) => RoyalRoad::get_backend_name(),
-            #[cfg(feature = "libread")]
-            Backends::LibRead(_) => LibRead::get_backend_name(),
-            #[cfg(feature = "freewebnovel")]
+    /// Describes the backend, the fiction it was built for, the
+    /// [options][BackendOptions::describe] it uses & the settings of its client
+    /// (such as the User-Agent of its [`ClientConfig`]), which is useful to
+    /// report bugs.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `self` is [`Backends::Dumb`].
+    pub fn describe(&self) -> String {
+        format!(
+            "backend: {}\nurl: {}\n{}",
+            self.get_backend_name(),
+            self.url(),
+            self.backend_options()
+                .describe_with_client(self.http_client())
+        )
+    }
+
+    /// Builds the backend matching the given URL (see [`Backends::new`]) and
+    /// extracts the fiction's metadata from the page it fetched, which is
+    /// handy to show a preview of a fiction.
+    ///
+    /// ```rust
+    /// use libwebnovel::Backends;
+    /// let (backend, metadata) =
+    ///     Backends::probe("https://www.royalroad.com/fiction/21220/mother-of-learning").unwrap();
+    /// assert_eq!(backend.get_backend_name(), "royalroad");
+    /// assert_eq!(metadata.title(), "Mother of Learning");
+    /// assert_eq!(metadata.authors(), &vec!["nobody103".to_string()]);
+    /// assert_eq!(*metadata.chapter_count(), 109);
+    /// ```
+    pub fn probe(url: &str) -> Result<(Backends, FictionMetadata), BackendError> {
+        let backend = Backends::new(url)?;
+        let metadata = backend.metadata()?;
+        Ok((backend, metadata))
+    }
+
+    /// Like [`Backends::new`], but if no backend claims the URL (for instance,
+    /// on a mirror of a supported site), tries to build each enabled backend
+    /// in turn, and returns the first one able to find the fiction's title.
+    ///
+    /// This sends requests to the URL for every backend tried, hence it is not
+    /// what [`Backends::new`] does.
+    pub fn try_all_backends(url: &str) -> Result<Backends, BackendError> {
+        match Backends::new(url) {
+            Err(BackendError::NoMatchingBackendFound(_)) => {}
+            result => return result,
+        }
+        for backend_variant in Backends::iter().filter(|b| !matches!(b, Backends::Dumb)) {
+            let name = backend_variant.get_backend_name();
+            match backend_variant.new_from_url(url, &ClientConfig::default()) {
+                Ok(backend) if backend.title().is_ok() => {
+                    debug!("{url} can be handled by the {name} backend");
+                    return Ok(backend);
+                }
+                Ok(_) => debug!("The {name} backend could not find a title at {url}"),
+                Err(e) => debug!("The {name} backend could not handle {url}: {e}"),
+            }
+        }
+        Err(BackendError::NoMatchingBackendFound(url.to_string()))
+    }
+
+    /// Fetches everything about the fiction at the given URL in one call: see
+    /// [`Backends::download_package`].
+    pub fn download_full(url: &str) -> Result<FictionPackage, BackendError> {
+        Backends::new(url)?.download_package()
+    }
+
+    /// Fetches the fiction's metadata (titled with
+    /// [`output_title`][Backend::output_title]), its cover if any, and all of
+    /// its chapters. Chapters are fetched several at a time (see
+    /// [`Backend::get_chapters_concurrent`]), then sorted in reading order
+    /// with [`Backends::get_ordering_function`]. A cover that could not be
+    /// downloaded is left out, but the first chapter that could not be fetched
+    /// fails the whole download. To be gentler with the site,
+    /// see [`set_rate_limit`][crate::set_rate_limit].
+    pub fn download_package(&self) -> Result<FictionPackage, BackendError> {
+        let mut metadata = self.metadata()?;
+        metadata.set_title(self.output_title()?);
+        let cover = match metadata.cover_url() {
+            Some(cover_url) => match download_cover(self.http_client(), cover_url) {
+                Ok(bytes) => bytes.map(|bytes| {
+                    let mut cover = CoverImage::default();
+                    cover.set_url(cover_url.clone());
+                    cover.set_bytes(bytes);
+                    cover
+                }),
+                Err(e) => {
+                    warn!("Could not download the cover at {cover_url}, leaving it out: {e}");
+                    None
+                }
+            },
+            None => None,
+        };
+        let indices: Vec<usize> = self
+            .get_chapter_list()?
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        self.backend_options().check_chapter_count(indices.len())?;
+        let mut chapters = self
+            .get_chapters_concurrent(&indices)
+            .into_iter()
+            .map(|result| result.map_err(|(_, e)| e))
+            .collect::<Result<Vec<_>, _>>()?;
+        chapters.sort_by(self.get_ordering_function());
+        let mut package = FictionPackage::default();
+        package.set_metadata(metadata);
+        package.set_cover(cover);
+        package.set_chapters(chapters);
+        Ok(package)
+    }
+
+    /// Downloads the whole fiction to `dir`: its chapters (one file per
+    /// chapter, in the format of [`Chapter`]'s [`Display`][std::fmt::Display]
+    /// implementation), its cover image if any, and a
+    /// [`manifest.json`][crate::manifest::MANIFEST_FILE_NAME] listing them in
+    /// order along with their URLs, publication dates & content hashes. See
+    /// [`FictionManifest`].
+    ///
+    /// ```rust
+    /// use libwebnovel::{Backend, Backends};
+    /// let backend =
+    ///     Backends::new("https://www.royalroad.com/fiction/21220/mother-of-learning").unwrap();
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let manifest = backend.export_manifest(dir.path()).unwrap();
+    /// assert_eq!(manifest.title, "Mother of Learning");
+    /// assert!(dir.path().join(&manifest.chapters[0].file).exists());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn export_manifest(&self, dir: &Path) -> Result<FictionManifest, BackendError> {
+        self.export_manifest_with_options(dir, ExportOptions::default())
+    }
+
+    /// Like [`Backends::export_manifest`], with the given options. For
+    /// instance, the cover can be skipped when only the text is wanted.
+    #[cfg(feature = "serde")]
+    pub fn export_manifest_with_options(
+        &self,
+        dir: &Path,
+        options: ExportOptions,
+    ) -> Result<FictionManifest, BackendError> {
+        let mut metadata = self.metadata()?;
+        metadata.set_title(self.output_title()?);
+        let cover = if options.include_cover {
+            self.cover()?
+        } else {
+            None
+        };
+        let mut chapters = self.get_chapters()?;
+        chapters.sort_by(self.get_ordering_function());
+        FictionManifest::write(
+            dir,
+            &metadata,
+            self.get_backend_name(),
+            cover.as_deref(),
+            &chapters,
+        )
+    }
+
+    /// Downloads the fiction with [`Backends::download_package`], and writes it
+    /// to `dir` as epub files named after its
+    /// [`output_file_stem`][Backend::output_file_stem]: a single one, or one
+    /// per [volume][Backend::get_volumes] depending on `split` (see
+    /// [`write_epubs`]). Returns the written files, in reading order.
+    #[cfg(feature = "epub")]
+    pub fn export_epub(&self, dir: &Path, split: EpubSplit) -> Result<Vec<PathBuf>, BackendError> {
+        let package = self.download_package()?;
+        let chapters: HashMap<usize, &Chapter> = package
+            .chapters()
+            .iter()
+            .map(|chapter| (*chapter.index(), chapter))
+            .collect();
+        let volumes: Vec<(Volume, Vec<Chapter>)> = self
+            .get_volumes()?
+            .into_iter()
+            .filter(|volume| !volume.chapters().is_empty())
+            .map(|volume| {
+                let volume_chapters = volume
+                    .chapters()
+                    .iter()
+                    .filter_map(|info| chapters.get(info.index()).map(|chapter| (*chapter).clone()))
+                    .collect();
+                (volume, volume_chapters)
+            })
+            .collect();
+        write_epubs(
+            dir,
+            &self.output_file_stem()?,
+            package.metadata(),
+            package
+                .cover()
+                .as_ref()
+                .map(|cover| cover.bytes().as_slice()),
+            &volumes,
+            split,
+        )
+    }
+
+    /// Like [`Backends::export_epub`], writing one epub per
+    /// [volume][Backend::get_volumes] so that very long series can be read one
+    /// volume at a time.
+    #[cfg(feature = "epub")]
+    pub fn export_volumes(&self, dir: &Path) -> Result<Vec<PathBuf>, BackendError> {
+        self.export_epub(dir, EpubSplit::PerVolume)
+    }
+
+    /// Writes the [chapter list][Backend::get_chapter_info] of the fiction
+    /// (indices, titles, URLs, publication dates…) to `path` as JSON, and
+    /// returns it. Reloading it with [`Backends::load_chapter_list`] later
+    /// allows detecting new chapters by diffing, without depending on the
+    /// structure of the site's pages at the time.
+    #[cfg(feature = "serde")]
+    pub fn cache_chapter_list(&self, path: &Path) -> Result<Vec<ChapterInfo>, BackendError> {
+        let chapters = self.get_chapter_infos()?;
+        write_chapter_list(path, &chapters)?;
+        Ok(chapters)
+    }
+
+    /// Loads a fiction exported by [`Backends::export_manifest`] from `dir`,
+    /// without any network access: its metadata comes from the manifest, and
+    /// its chapters from the chapter files, sorted with the ordering function
+    /// of the backend the fiction was downloaded with (or by index, when that
+    /// backend is not available). Offline tools can re-generate their output
+    /// this way.
+    #[cfg(feature = "serde")]
+    pub fn load_exported(dir: &Path) -> Result<(FictionMetadata, Vec<Chapter>), BackendError> {
+        let manifest = FictionManifest::read(dir)?;
+        let mut chapters = manifest.read_chapters(dir)?;
+        match Backends::iter()
+            .find(|b| !matches!(b, Backends::Dumb) && b.get_backend_name() == manifest.backend_name)
+        {
+            Some(backend) => chapters.sort_by(backend.get_ordering_function()),
+            None => chapters.sort_by_key(|chapter| *chapter.index()),
+        }
+        Ok((FictionMetadata::from(&manifest), chapters))
+    }
+
+    /// Reads a chapter list written by [`Backends::cache_chapter_list`].
+    #[cfg(feature = "serde")]
+    pub fn load_chapter_list(path: &Path) -> Result<Vec<ChapterInfo>, BackendError> {
+        read_chapter_list(path)
+    }
+
+    /// Merges two sets of chapters of this fiction, such as a previous
+    /// download & a fresh one, into a single set without duplicates, sorted
+    /// with [`Backends::get_ordering_function`].
+    ///
+    /// Two chapters collide if they have the same URL, or the same index while
+    /// being equal according to the ordering function. On collision, the
+    /// chapter from `new` wins, unless it has the same content as the one from
+    /// `old` or an earlier publication date.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `self` is [`Backends::Dumb`].
+    pub fn merge_chapter_sets(&self, old: Vec<Chapter>, new: Vec<Chapter>) -> Vec<Chapter> {
+        fn replaces(candidate: &Chapter, existing: &Chapter) -> bool {
+            if candidate.content_hash() == existing.content_hash() {
+                return false;
+            }
+            match (candidate.published_at(), existing.published_at()) {
+                (Some(candidate_date), Some(existing_date)) => candidate_date >= existing_date,
+                _ => true,
+            }
+        }
+        let ordering = self.get_ordering_function();
+        let mut merged: Vec<Chapter> = Vec::with_capacity(old.len().max(new.len()));
+        for chapter in old.into_iter().chain(new) {
+            let collision = merged.iter().position(|other| {
+                (!chapter.chapter_url().is_empty() && other.chapter_url() == chapter.chapter_url())
+                    || (other.index() == chapter.index()
+                        && ordering(other, &chapter) == Ordering::Equal)
+            });
+            match collision {
+                Some(i) if replaces(&chapter, &merged[i]) => merged[i] = chapter,
+                Some(_) => {}
+                None => merged.push(chapter),
+            }
+        }
+        merged.sort_by(ordering);
+        merged
+    }
+
+    /// Returns the capabilities of the underlying backend. [`Backends::Dumb`]
+    /// has none.
+    pub fn capabilities(&self) -> BackendCapabilities {
+        match self {
+            Backends::Dumb => BackendCapabilities::default(),
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(_) => RoyalRoad::capabilities(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(_) => LibRead::capabilities(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(_) => FreeWebNovel::capabilities(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(_) => LightNovelWorld::capabilities(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(_) => Dreame::capabilities(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(_) => ScribbleHub::capabilities(),
+        }
+    }
+
+    /// Returns the underlying backend name.
+    pub fn get_backend_name(&self) -> &'static str {
+        match self {
+            Backends::Dumb => "dummy",
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(_) => RoyalRoad::get_backend_name(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(_) => LibRead::get_backend_name(),
+            #[cfg(feature = "freewebnovel")]
             Backends::FreeWebNovel(_) => FreeWebNovel::get_backend_name(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(_) => LightNovelWorld::get_backend_name(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(_) => Dreame::get_backend_name(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(_) => ScribbleHub::get_backend_name(),
         }
     }
 }
@@ -292,74 +2019,738 @@ impl Backend for Backends {
         unimplemented!()
     }
 
-    /// Not implemented for [`Backends`]. Use
-    /// [`Backends::get_backend_name(&self)`][a] instead.
-    ///
-    /// [a]: Backends#method.get_backend_name
-    fn get_backend_name() -> &'static str {
-        unimplemented!()
+    /// Not implemented for [`Backends`]. Use
+    /// [`Backends::get_backend_name(&self)`][a] instead.
+    ///
+    /// [a]: Backends#method.get_backend_name
+    fn get_backend_name() -> &'static str {
+        unimplemented!()
+    }
+
+    /// Not implemented for [`Backends`]. Use
+    /// [`Backends::capabilities(&self)`][a] instead.
+    ///
+    /// [a]: Backends#method.capabilities
+    fn capabilities() -> BackendCapabilities {
+        unimplemented!()
+    }
+
+    /// Can't implement this function for backends without reference to `self`.
+    /// use [`Backends::get_ordering_function(&self)`][a] instead.
+    ///
+    /// [a]: Backends#method.get_ordering_function
+    fn get_ordering_function() -> ChapterOrderingFn {
+        unimplemented!()
+    }
+
+    /// Parses the given chapter URL with the first backend that recognizes it.
+    ///
+    /// ```rust
+    /// use libwebnovel::{Backend, Backends};
+    /// let parts = Backends::parse_chapter_url(
+    ///     "https://www.royalroad.com/fiction/21220/mother-of-learning/chapter/301778/1-good-morning-brother",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(parts.fiction_id, "21220");
+    /// assert_eq!(parts.chapter_id, "301778");
+    /// ```
+    fn parse_chapter_url(url: &str) -> Option<ChapterUrlParts> {
+        let parsers: Vec<fn(&str) -> Option<ChapterUrlParts>> = vec![
+            #[cfg(feature = "royalroad")]
+            RoyalRoad::parse_chapter_url,
+            #[cfg(feature = "libread")]
+            LibRead::parse_chapter_url,
+            #[cfg(feature = "freewebnovel")]
+            FreeWebNovel::parse_chapter_url,
+            #[cfg(feature = "lightnovelworld")]
+            LightNovelWorld::parse_chapter_url,
+            #[cfg(feature = "dreame")]
+            Dreame::parse_chapter_url,
+            #[cfg(feature = "scribblehub")]
+            ScribbleHub::parse_chapter_url,
+        ];
+        parsers.into_iter().find_map(|parse| parse(url))
+    }
+
+    /// Lists the fictions of the given profile with the first backend that
+    /// recognizes it, returning [`BackendError::NoMatchingBackendFound`] if
+    /// none does.
+    ///
+    /// ```rust,no_run
+    /// use libwebnovel::{Backend, Backends};
+    /// for url in Backends::fictions_from_profile("https://www.royalroad.com/profile/12345").unwrap() {
+    ///     let backend = Backends::new(&url).unwrap();
+    ///     println!("{}", backend.title().unwrap());
+    /// }
+    /// ```
+    fn fictions_from_profile(profile_url: &str) -> Result<Vec<String>, BackendError> {
+        type ProfileLister = fn(&str) -> Result<Vec<String>, BackendError>;
+        let listers: Vec<ProfileLister> = vec![
+            #[cfg(feature = "royalroad")]
+            RoyalRoad::fictions_from_profile,
+            #[cfg(feature = "libread")]
+            LibRead::fictions_from_profile,
+            #[cfg(feature = "freewebnovel")]
+            FreeWebNovel::fictions_from_profile,
+            #[cfg(feature = "lightnovelworld")]
+            LightNovelWorld::fictions_from_profile,
+            #[cfg(feature = "dreame")]
+            Dreame::fictions_from_profile,
+            #[cfg(feature = "scribblehub")]
+            ScribbleHub::fictions_from_profile,
+        ];
+        for list in listers {
+            match list(profile_url) {
+                Err(BackendError::NoMatchingBackendFound(_) | BackendError::Unsupported(_)) => {}
+                result => return result,
+            }
+        }
+        Err(BackendError::NoMatchingBackendFound(
+            profile_url.to_string(),
+        ))
+    }
+
+    /// Builds a new backend for a given URL. Auto-detects the backend to use
+    /// from the given URL, returning [`BackendError::NoMatchingBackendFound`]
+    /// if none could be found.
+    ///
+    /// ```rust
+    /// use libwebnovel::{Backend, Backends};
+    /// let backend =
+    ///     Backends::new("https://www.royalroad.com/fiction/21220/mother-of-learning").unwrap();
+    /// assert_eq!(backend.title().unwrap(), "Mother of Learning");
+    /// ```
+    fn new(url: &str) -> Result<Self, BackendError> {
+        Self::new_with_config(url, &ClientConfig::default())
+    }
+
+    /// Builds a new backend for a given URL, like [`Backends::new`], sending
+    /// every request with a client configured by `config`.
+    ///
+    /// ```rust
+    /// use libwebnovel::{Backend, Backends, ClientConfig};
+    /// let config = ClientConfig {
+    ///     user_agent: "Mozilla/5.0 (X11; Linux x86_64; rv:130.0) Gecko/20100101 Firefox/130.0"
+    ///         .to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let backend = Backends::new_with_config(
+    ///     "https://www.royalroad.com/fiction/21220/mother-of-learning",
+    ///     &config,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(backend.title().unwrap(), "Mother of Learning");
+    /// ```
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        for backend_variant in Backends::iter() {
+            for regex in backend_variant.get_backend_regexps() {
+                if regex.is_match(url) {
+                    return backend_variant.new_from_url(url, config);
+                }
+            }
+        }
+        Err(BackendError::NoMatchingBackendFound(url.to_string()))
+    }
+
+    fn http_client(&self) -> &HttpClient {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.http_client(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.http_client(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.http_client(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.http_client(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.http_client(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.http_client(),
+        }
+    }
+
+    /// Returns the title of the webnovel. See [`Backends::new`] for an example.
+    fn title(&self) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.title(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.title(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.title(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.title(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.title(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.title(),
+        }
+    }
+
+    fn output_title(&self) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.output_title(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.output_title(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.output_title(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.output_title(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.output_title(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.output_title(),
+        }
+    }
+
+    fn output_file_stem(&self) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.output_file_stem(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.output_file_stem(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.output_file_stem(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.output_file_stem(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.output_file_stem(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.output_file_stem(),
+        }
+    }
+
+    fn immutable_identifier(&self) -> Result<String, BackendError> {
+        match self {
+            // implement this on the model of self.title() please
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.immutable_identifier(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.immutable_identifier(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.immutable_identifier(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.immutable_identifier(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.immutable_identifier(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.immutable_identifier(),
+        }
+    }
+
+    fn first_chapter_url(&self) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.first_chapter_url(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.first_chapter_url(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.first_chapter_url(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.first_chapter_url(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.first_chapter_url(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.first_chapter_url(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn get_linked_chapter(
+        &self,
+        chapter_number: usize,
+        chapter_url: &str,
+    ) -> Result<(Chapter, Option<String>), BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_linked_chapter(chapter_number, chapter_url),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_linked_chapter(chapter_number, chapter_url),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_linked_chapter(chapter_number, chapter_url),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_linked_chapter(chapter_number, chapter_url),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_linked_chapter(chapter_number, chapter_url),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_linked_chapter(chapter_number, chapter_url),
+        }
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.backend_options(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.backend_options(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.backend_options(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.backend_options(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.backend_options(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.backend_options(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.set_backend_options(options),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.set_backend_options(options),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.set_backend_options(options),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.set_backend_options(options),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.set_backend_options(options),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.set_backend_options(options),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn get_chapter_info(&self, chapter_number: usize) -> Result<ChapterInfo, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapter_info(chapter_number),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_chapter_info(chapter_number),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_chapter_info(chapter_number),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_chapter_info(chapter_number),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapter_info(chapter_number),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapter_info(chapter_number),
+        }
+    }
+
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapter_infos(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_chapter_infos(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_chapter_infos(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_chapter_infos(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapter_infos(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapter_infos(),
+        }
+    }
+
+    fn get_volumes(&self) -> Result<Vec<Volume>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_volumes(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_volumes(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_volumes(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_volumes(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_volumes(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_volumes(),
+        }
+    }
+
+    fn release_cadence(&self) -> Result<Option<Duration>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.release_cadence(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.release_cadence(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.release_cadence(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.release_cadence(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.release_cadence(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.release_cadence(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn has_updates_since(&self, since: DateTime<Utc>) -> Result<bool, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.has_updates_since(since),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.has_updates_since(since),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.has_updates_since(since),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.has_updates_since(since),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.has_updates_since(since),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.has_updates_since(since),
+        }
+    }
+
+    fn language(&self) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.language(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.language(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.language(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.language(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.language(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.language(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn get_chapters_by_indices(&self, indices: &[usize]) -> Vec<Result<Chapter, BackendError>> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapters_by_indices(indices),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_chapters_by_indices(indices),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_chapters_by_indices(indices),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_chapters_by_indices(indices),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapters_by_indices(indices),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapters_by_indices(indices),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn get_chapters_concurrent(
+        &self,
+        indices: &[usize],
+    ) -> Vec<Result<Chapter, (usize, BackendError)>>
+    where
+        Self: Clone + Send,
+    {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapters_concurrent(indices),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_chapters_concurrent(indices),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_chapters_concurrent(indices),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_chapters_concurrent(indices),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapters_concurrent(indices),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapters_concurrent(indices),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn is_story_chapter(&self, info: &ChapterInfo) -> bool {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.is_story_chapter(info),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.is_story_chapter(info),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.is_story_chapter(info),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.is_story_chapter(info),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.is_story_chapter(info),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.is_story_chapter(info),
+        }
+    }
+
+    fn estimated_total_bytes(&self) -> Result<Option<u64>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.estimated_total_bytes(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.estimated_total_bytes(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.estimated_total_bytes(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.estimated_total_bytes(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.estimated_total_bytes(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.estimated_total_bytes(),
+        }
+    }
+
+    fn external_identifiers(&self) -> Result<HashMap<String, String>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.external_identifiers(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.external_identifiers(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.external_identifiers(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.external_identifiers(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.external_identifiers(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.external_identifiers(),
+        }
+    }
+
+    fn content_warnings(&self) -> Result<Vec<String>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.content_warnings(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.content_warnings(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.content_warnings(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.content_warnings(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.content_warnings(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.content_warnings(),
+        }
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_tags(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_tags(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_tags(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_tags(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_tags(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_tags(),
+        }
+    }
+
+    fn description(&self) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.description(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.description(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.description(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.description(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.description(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.description(),
+        }
+    }
+
+    fn status(&self) -> Result<FictionStatus, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.status(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.status(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.status(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.status(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.status(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.status(),
+        }
+    }
+
+    fn declared_language(&self) -> Option<String> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.declared_language(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.declared_language(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.declared_language(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.declared_language(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.declared_language(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.declared_language(),
+        }
     }
 
-    /// Can't implement this function for backends without reference to `self`.
-    /// use [`Backends::get_ordering_function(&self)`][a] instead.
-    ///
-    /// [a]: Backends#method.get_ordering_function
-    fn get_ordering_function() -> ChapterOrderingFn {
-        unimplemented!()
+    #[allow(unused_variables)]
+    fn get_first_chapters(&self, n: usize) -> Result<Vec<Chapter>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_first_chapters(n),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_first_chapters(n),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_first_chapters(n),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_first_chapters(n),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_first_chapters(n),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_first_chapters(n),
+        }
     }
 
-    /// Builds a new backend for a given URL. Auto-detects the backend to use
-    /// from the given URL, returning [`BackendError::NoMatchingBackendFound`]
-    /// if none could be found.
-    ///
-    /// ```rust
-    /// use libwebnovel::{Backend, Backends};
-    /// let backend =
-    ///     Backends::new("https://www.royalroad.com/fiction/21220/mother-of-learning").unwrap();
-    /// assert_eq!(backend.title().unwrap(), "Mother of Learning");
-    /// ```
-    fn new(url: &str) -> Result<Self, BackendError> {
-        for backend_variant in Backends::iter() {
-            for regex in backend_variant.get_backend_regexps() {
-                if regex.is_match(url) {
-                    return backend_variant.new_from_url(url);
-                }
+    fn get_chapters_ordered(&self) -> Result<Vec<Chapter>, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
             }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.get_chapters_ordered(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.get_chapters_ordered(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.get_chapters_ordered(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.get_chapters_ordered(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapters_ordered(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapters_ordered(),
         }
-        Err(BackendError::NoMatchingBackendFound(url.to_string()))
     }
 
-    /// Returns the title of the webnovel. See [`Backends::new`] for an example.
-    fn title(&self) -> Result<String, BackendError> {
+    #[allow(unused_variables)]
+    fn get_next_after(&self, chapter: &Chapter) -> Result<Option<Chapter>, BackendError> {
         match self {
             Backends::Dumb => {
                 unimplemented!()
             }
             #[cfg(feature = "royalroad")]
-            Backends::RoyalRoad(b) => b.title(),
+            Backends::RoyalRoad(b) => b.get_next_after(chapter),
             #[cfg(feature = "libread")]
-            Backends::LibRead(b) => b.title(),
+            Backends::LibRead(b) => b.get_next_after(chapter),
             #[cfg(feature = "freewebnovel")]
-            Backends::FreeWebNovel(b) => b.title(),
+            Backends::FreeWebNovel(b) => b.get_next_after(chapter),
             #[cfg(feature = "lightnovelworld")]
-            Backends::LightNovelWorld(b) => b.title(),
+            Backends::LightNovelWorld(b) => b.get_next_after(chapter),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_next_after(chapter),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_next_after(chapter),
         }
     }
 
-    fn immutable_identifier(&self) -> Result<String, BackendError> {
+    #[cfg(feature = "uuid")]
+    fn fiction_uuid(&self) -> Result<Uuid, BackendError> {
         match self {
-            // implement this on the model of self.title() please
             Backends::Dumb => {
                 unimplemented!()
             }
             #[cfg(feature = "royalroad")]
-            Backends::RoyalRoad(b) => b.immutable_identifier(),
+            Backends::RoyalRoad(b) => b.fiction_uuid(),
             #[cfg(feature = "libread")]
-            Backends::LibRead(b) => b.immutable_identifier(),
+            Backends::LibRead(b) => b.fiction_uuid(),
             #[cfg(feature = "freewebnovel")]
-            Backends::FreeWebNovel(b) => b.immutable_identifier(),
+            Backends::FreeWebNovel(b) => b.fiction_uuid(),
             #[cfg(feature = "lightnovelworld")]
-            Backends::LightNovelWorld(b) => b.immutable_identifier(),
+            Backends::LightNovelWorld(b) => b.fiction_uuid(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.fiction_uuid(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.fiction_uuid(),
         }
     }
 
@@ -386,6 +2777,10 @@ impl Backend for Backends {
             Backends::FreeWebNovel(b) => b.url(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(b) => b.url(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.url(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.url(),
         }
     }
 
@@ -403,6 +2798,51 @@ impl Backend for Backends {
             Backends::FreeWebNovel(backend) => backend.cover_url(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(b) => b.cover_url(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.cover_url(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.cover_url(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn cover_url_sized(&self, width: u32, height: u32) -> Result<String, BackendError> {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.cover_url_sized(width, height),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.cover_url_sized(width, height),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.cover_url_sized(width, height),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.cover_url_sized(width, height),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.cover_url_sized(width, height),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.cover_url_sized(width, height),
+        }
+    }
+
+    fn site_icon_url(&self) -> String {
+        match self {
+            Backends::Dumb => {
+                unimplemented!()
+            }
+            #[cfg(feature = "royalroad")]
+            Backends::RoyalRoad(b) => b.site_icon_url(),
+            #[cfg(feature = "libread")]
+            Backends::LibRead(b) => b.site_icon_url(),
+            #[cfg(feature = "freewebnovel")]
+            Backends::FreeWebNovel(b) => b.site_icon_url(),
+            #[cfg(feature = "lightnovelworld")]
+            Backends::LightNovelWorld(b) => b.site_icon_url(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.site_icon_url(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.site_icon_url(),
         }
     }
 
@@ -429,6 +2869,10 @@ impl Backend for Backends {
             Backends::FreeWebNovel(b) => b.get_authors(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(b) => b.get_authors(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_authors(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_authors(),
         }
     }
 
@@ -445,6 +2889,10 @@ impl Backend for Backends {
             Backends::FreeWebNovel(b) => b.get_chapter_list(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(b) => b.get_chapter_list(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapter_list(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapter_list(),
         }
     }
 
@@ -460,6 +2908,7 @@ impl Backend for Backends {
     /// );
     /// assert_eq!(*chapter.index(), 1);
     /// ```
+    #[allow(unused_variables)]
     fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
         match self {
             Backends::Dumb => {
@@ -473,6 +2922,10 @@ impl Backend for Backends {
             Backends::FreeWebNovel(b) => b.get_chapter(chapter_number),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(b) => b.get_chapter(chapter_number),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapter(chapter_number),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapter(chapter_number),
         }
     }
 
@@ -502,6 +2955,1337 @@ impl Backend for Backends {
             Backends::FreeWebNovel(b) => b.get_chapter_count(),
             #[cfg(feature = "lightnovelworld")]
             Backends::LightNovelWorld(b) => b.get_chapter_count(),
+            #[cfg(feature = "dreame")]
+            Backends::Dreame(b) => b.get_chapter_count(),
+            #[cfg(feature = "scribblehub")]
+            Backends::ScribbleHub(b) => b.get_chapter_count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    use chrono::{DateTime, Duration, Utc};
+    use regex::Regex;
+    use reqwest::StatusCode;
+    use strum::IntoEnumIterator;
+
+    use crate::backends::{
+        fetch_covers_with, get_chapters_concurrently, median_release_interval, url_suffix_ordering,
+        BackendCapabilities, BackendError, BackendOptions, Backends, ChapterListElem,
+        FictionStatus, DEFAULT_MAX_CHAPTERS, MAX_DISPLAYED_BODY_LENGTH,
+    };
+    use crate::test_support::MockServer;
+    use crate::{Backend, Chapter, ChapterInfo, ClientConfig};
+
+    /// A backend serving its content from memory.
+    #[derive(Debug, Default)]
+    struct FakeBackend {
+        /// chapter URL -> next chapter URL
+        links: HashMap<String, Option<String>>,
+        cover_url: Option<String>,
+        /// chapter index -> chapter content
+        contents: HashMap<usize, String>,
+        /// chapter index -> publication date, defaults to `index` days after
+        /// the epoch
+        dates: HashMap<usize, DateTime<Utc>>,
+        /// reported chapter count, defaults to the number of contents
+        chapter_count: Option<usize>,
+        /// chapter index -> title in the chapter list, defaults to "Chapter
+        /// {index}"
+        titles: HashMap<usize, String>,
+        /// chapter index -> chapter URL, defaults to
+        /// "https://example.com/linked/chapter-{index}"
+        urls: HashMap<usize, String>,
+        options: BackendOptions,
+        /// number of calls to `get_chapter_list`
+        chapter_list_calls: Cell<usize>,
+    }
+
+    impl FakeBackend {
+        fn chapter_url(&self, index: usize) -> String {
+            self.urls
+                .get(&index)
+                .cloned()
+                .unwrap_or(format!("https://example.com/linked/chapter-{index}"))
+        }
+
+        fn new_linked(links: &[(&str, Option<&str>)]) -> Self {
+            Self {
+                links: links
+                    .iter()
+                    .map(|(url, next)| (url.to_string(), next.map(|n| n.to_string())))
+                    .collect(),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl Backend for FakeBackend {
+        fn get_backend_regexps() -> Vec<Regex> {
+            Vec::new()
+        }
+
+        fn get_backend_name() -> &'static str {
+            "linked"
+        }
+
+        fn new(url: &str) -> Result<Self, BackendError> {
+            Err(BackendError::NoMatchingBackendFound(url.to_string()))
+        }
+
+        fn backend_options(&self) -> &BackendOptions {
+            &self.options
+        }
+
+        fn set_backend_options(&mut self, options: BackendOptions) {
+            self.options = options;
+        }
+
+        fn title(&self) -> Result<String, BackendError> {
+            Ok("Linked".to_string())
+        }
+
+        fn immutable_identifier(&self) -> Result<String, BackendError> {
+            Ok("linked".to_string())
+        }
+
+        fn url(&self) -> String {
+            "https://example.com/linked".to_string()
+        }
+
+        fn cover_url(&self) -> Result<String, BackendError> {
+            self.cover_url.clone().ok_or(BackendError::ParseError(
+                "Could not find cover url".to_string(),
+                None,
+            ))
+        }
+
+        fn get_authors(&self) -> Result<Vec<String>, BackendError> {
+            Ok(Vec::new())
+        }
+
+        fn get_chapter_list(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+            self.chapter_list_calls
+                .set(self.chapter_list_calls.get() + 1);
+            if self.contents.is_empty() {
+                return Err(BackendError::Unsupported("chapter lists".to_string()));
+            }
+            let mut chapter_list: Vec<ChapterListElem> = self
+                .contents
+                .keys()
+                .map(|index| {
+                    let title = self.titles.get(index).cloned();
+                    (*index, title.unwrap_or(format!("Chapter {index}")))
+                })
+                .collect();
+            chapter_list.sort();
+            Ok(chapter_list)
+        }
+
+        fn get_chapter_count(&self) -> Result<usize, BackendError> {
+            match self.chapter_count {
+                Some(count) => Ok(count),
+                None => Ok(self.get_chapter_list()?.len()),
+            }
+        }
+
+        fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
+            let content = self
+                .contents
+                .get(&chapter_number)
+                .ok_or(BackendError::UnknownChapter(chapter_number))?;
+            let mut chapter = Chapter::default();
+            chapter.set_index(chapter_number);
+            chapter.set_published_at(Some(
+                self.dates
+                    .get(&chapter_number)
+                    .copied()
+                    .unwrap_or(DateTime::UNIX_EPOCH + Duration::days(chapter_number as i64)),
+            ));
+            chapter.set_chapter_url(self.chapter_url(chapter_number));
+            chapter.set_content_raw(content);
+            Ok(chapter)
+        }
+
+        fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+            Ok(self
+                .get_chapter_list()?
+                .into_iter()
+                .map(|(index, title)| {
+                    let mut info = ChapterInfo::default();
+                    info.set_index(index);
+                    info.set_title(Some(title));
+                    info.set_chapter_url(Some(self.chapter_url(index)));
+                    info
+                })
+                .collect())
+        }
+
+        fn first_chapter_url(&self) -> Result<String, BackendError> {
+            Ok("https://example.com/linked/1".to_string())
+        }
+
+        fn get_linked_chapter(
+            &self,
+            chapter_number: usize,
+            chapter_url: &str,
+        ) -> Result<(Chapter, Option<String>), BackendError> {
+            let next_url = self
+                .links
+                .get(chapter_url)
+                .ok_or(BackendError::UrlNotFound)?
+                .clone();
+            let mut chapter = Chapter::default();
+            chapter.set_index(chapter_number);
+            chapter.set_chapter_url(chapter_url.to_string());
+            Ok((chapter, next_url))
+        }
+    }
+
+    fn chapter_urls(backend: &FakeBackend, limit: usize) -> Vec<(usize, String)> {
+        backend
+            .walk_from_first_chapter()
+            .limit(limit)
+            .map(|chapter| {
+                let chapter = chapter.unwrap();
+                (*chapter.index(), chapter.chapter_url().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_check_chapter() {
+        let mut chapter = Chapter::default();
+        chapter.set_content("<p>content</p>");
+        let lenient = BackendOptions::default();
+        let strict = BackendOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(lenient.check_chapter(&chapter, true).is_ok());
+        match strict.check_chapter(&chapter, true) {
+            Err(BackendError::ParseError(msg, _)) => {
+                assert!(msg.contains("title, publication date"), "{msg}")
+            }
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+        chapter.set_title(Some("title".to_string()));
+        assert!(strict.check_chapter(&chapter, false).is_ok());
+    }
+
+    #[test]
+    fn test_placeholder_chapter() {
+        let mut chapter = Chapter::default();
+        chapter.set_chapter_url("https://example.com/fiction/chapter-12".to_string());
+        for placeholder in [
+            "<p>This chapter has moved to our new site!</p>",
+            r#"<div class="notice"><h3>Coming soon…</h3></div>"#,
+            "<p>Sorry, the content is not available in your region.</p>",
+        ] {
+            chapter.set_content(placeholder);
+            assert!(
+                matches!(
+                    BackendOptions::default().check_chapter(&chapter, false),
+                    Err(BackendError::PlaceholderChapter(url)) if url == *chapter.chapter_url()
+                ),
+                "{placeholder} is not considered a placeholder"
+            );
+        }
+        // an actual chapter mentioning the same words
+        chapter.set_content(format!(
+            "<p>{}</p><p>\"Coming soon,\" she said. The chapter has moved on.</p>",
+            "The rain kept falling. ".repeat(20)
+        ));
+        assert!(BackendOptions::default()
+            .check_chapter(&chapter, false)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_cover() {
+        let server = MockServer::start(&[("/cover.png", 200, "png")]);
+        let backend = |cover_url: Option<String>| FakeBackend {
+            cover_url,
+            ..Default::default()
+        };
+        assert_eq!(backend(None).cover().unwrap(), None);
+        assert_eq!(
+            backend(Some(server.url("/cover.png"))).cover().unwrap(),
+            Some(b"png".to_vec())
+        );
+        // dead cover URL
+        assert_eq!(
+            backend(Some(server.url("/dead.png"))).cover().unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fetch_covers() {
+        let server =
+            MockServer::start(&[("/cover1.png", 200, "png1"), ("/cover2.jpg", 200, "jpg2")]);
+        let fictions = ["fiction1", "fiction2", "no-cover", "dead-cover", "fiction1"];
+        let covers = fetch_covers_with(&fictions, |fiction| match fiction {
+            "fiction1" => Ok(server.url("/cover1.png")),
+            "fiction2" => Ok(server.url("/cover2.jpg")),
+            "dead-cover" => Ok(server.url("/dead.png")),
+            _ => Err(BackendError::ParseError("no cover".to_string(), None)),
+        });
+        assert_eq!(covers.len(), fictions.len());
+        assert_eq!(
+            covers[0].as_ref().unwrap(),
+            &(server.url("/cover1.png"), b"png1".to_vec())
+        );
+        assert_eq!(
+            covers[1].as_ref().unwrap(),
+            &(server.url("/cover2.jpg"), b"jpg2".to_vec())
+        );
+        assert!(matches!(covers[2], Err(BackendError::ParseError(..))));
+        assert!(matches!(covers[3], Err(BackendError::UrlNotFound)));
+        assert_eq!(covers[4].as_ref().unwrap().1, b"png1".to_vec());
+    }
+
+    /// Makes sure the `all` feature enables every feature of the crate, and
+    /// that every backend of [`Backends`] has its feature.
+    #[test]
+    #[cfg(feature = "all")]
+    fn test_all_feature() {
+        let cargo_toml = include_str!("../../Cargo.toml");
+        let features: HashMap<&str, &str> = cargo_toml
+            .split("[features]")
+            .nth(1)
+            .unwrap()
+            .split("\n[")
+            .next()
+            .unwrap()
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.trim().starts_with('#'))
+            .map(|line| line.split_once('=').unwrap())
+            .map(|(name, dependencies)| (name.trim(), dependencies.trim()))
+            .collect();
+        let all = features["all"];
+        for feature in features.keys() {
+            if ["all", "default", "helper_scripts", "test-support"].contains(feature) {
+                continue;
+            }
+            assert!(
+                all.contains(&format!("\"{feature}\"")),
+                "the `all` feature does not enable `{feature}`"
+            );
+        }
+        for backend in Backends::iter().skip(1) {
+            assert!(
+                features.contains_key(backend.get_backend_name()),
+                "no feature for backend {}",
+                backend.get_backend_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_median_release_interval() {
+        let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+        let dates: Vec<DateTime<Utc>> = [
+            "2024-01-01T00:00:00Z",
+            "2024-01-08T00:00:00Z",
+            "2024-01-10T00:00:00Z",
+            "2024-01-17T00:00:00Z",
+        ]
+        .into_iter()
+        .map(date)
+        .collect();
+        assert_eq!(median_release_interval(&dates[..2]), None);
+        assert_eq!(median_release_interval(&dates), Some(Duration::days(7)));
+        // order does not matter, and only the most recent chapters count
+        let mut dates: Vec<DateTime<Utc>> = (0..20)
+            .map(|i| date("2024-01-01T00:00:00Z") + Duration::days(2 * i))
+            .chain([date("2023-01-01T00:00:00Z")])
+            .rev()
+            .collect();
+        dates.push(dates[0] + Duration::days(3));
+        assert_eq!(median_release_interval(&dates), Some(Duration::days(2)));
+    }
+
+    #[test]
+    fn test_walk_from_first_chapter() {
+        let backend = FakeBackend::new_linked(&[
+            (
+                "https://example.com/linked/1",
+                Some("https://example.com/linked/2"),
+            ),
+            (
+                "https://example.com/linked/2",
+                Some("https://example.com/linked/3"),
+            ),
+            ("https://example.com/linked/3", None),
+        ]);
+        let expected = vec![
+            (1, "https://example.com/linked/1".to_string()),
+            (2, "https://example.com/linked/2".to_string()),
+            (3, "https://example.com/linked/3".to_string()),
+        ];
+        assert_eq!(chapter_urls(&backend, 100), expected);
+        assert_eq!(chapter_urls(&backend, 2), expected[..2]);
+    }
+
+    #[test]
+    fn test_walk_from_first_chapter_stops_on_loops() {
+        let backend = FakeBackend::new_linked(&[
+            (
+                "https://example.com/linked/1",
+                Some("https://example.com/linked/2"),
+            ),
+            (
+                "https://example.com/linked/2",
+                Some("https://example.com/linked/2"),
+            ),
+        ]);
+        assert_eq!(chapter_urls(&backend, 100).len(), 2);
+    }
+
+    #[test]
+    fn test_walk_from_first_chapter_errors() {
+        let backend = FakeBackend::new_linked(&[(
+            "https://example.com/linked/1",
+            Some("https://example.com/linked/404"),
+        )]);
+        let chapters: Vec<_> = backend.walk_from_first_chapter().collect();
+        assert_eq!(chapters.len(), 2);
+        assert!(matches!(chapters[1], Err(BackendError::UrlNotFound)));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_fiction_uuid() {
+        use crate::backends::fiction_uuid;
+
+        let backend = FakeBackend::default();
+        let uuid = backend.fiction_uuid().unwrap();
+        assert_eq!(uuid, FakeBackend::default().fiction_uuid().unwrap());
+        assert_eq!(uuid, fiction_uuid("linked", "linked"));
+        assert_eq!(uuid.get_version_num(), 5);
+        // must never change, or epubs would lose their identity
+        assert_eq!(uuid.to_string(), "8272f193-1fc5-514d-b9d1-bc66b0be65d6");
+        assert_ne!(uuid, fiction_uuid("other", "linked"));
+        assert_ne!(uuid, fiction_uuid("linked", "other"));
+    }
+
+    #[test]
+    fn test_title_override() {
+        let mut backend = FakeBackend::default();
+        assert_eq!(backend.output_file_stem().unwrap(), "linked");
+        backend.set_backend_options(BackendOptions {
+            title_override: Some(" Linked: The Novel ".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(backend.title().unwrap(), "Linked");
+        assert_eq!(backend.output_title().unwrap(), "Linked: The Novel");
+        assert_eq!(backend.output_file_stem().unwrap(), "linked-the-novel");
+    }
+
+    #[test]
+    fn test_is_story_chapter() {
+        let backend = FakeBackend::default();
+        let titles = [
+            ("Chapter 1: Good Morning Brother", true),
+            ("The Announcement", true),
+            ("Announcement of War", true),
+            ("Breaking Point", true),
+            ("Interlude: Patreon's Champion", true),
+            ("A/N and the Narrator", true),
+            ("Announcement", false),
+            ("Announcement: Book 2 is out!", true),
+            ("Chapter 12: Hiatus", true),
+            ("Chapter 12 - Announcement", true),
+            ("[A/N] Schedule change", true),
+            ("Not a chapter - sorry!", true),
+            ("Announcement!", false),
+            ("Important Announcement", false),
+            ("Hiatus", false),
+            ("Hiatus announcement", false),
+            ("[A/N]", false),
+            ("(Schedule change)", false),
+            ("Author's Note", false),
+            ("Not a chapter", false),
+            ("Taking a break.", false),
+            ("Patreon update", false),
+        ];
+        for (title, is_story) in titles {
+            let mut info = ChapterInfo::default();
+            info.set_title(Some(title.to_string()));
+            assert_eq!(backend.is_story_chapter(&info), is_story, "{title:?}");
+        }
+        assert!(backend.is_story_chapter(&ChapterInfo::default()));
+    }
+
+    #[test]
+    fn test_skip_non_story_chapters() {
+        let mut backend = FakeBackend {
+            contents: (1..=6)
+                .map(|index| (index, format!("<p>Chapter {index}</p>")))
+                .collect(),
+            titles: HashMap::from([
+                (2, "Important announcement!".to_string()),
+                (4, "Hiatus".to_string()),
+                (6, "Chapter 12: Hiatus".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let indices = |backend: &FakeBackend| -> Vec<usize> {
+            backend
+                .get_chapters()
+                .unwrap()
+                .iter()
+                .map(|chapter| *chapter.index())
+                .collect()
+        };
+        assert_eq!(indices(&backend), vec![1, 2, 3, 4, 5, 6]);
+        backend.set_backend_options(BackendOptions {
+            skip_non_story_chapters: true,
+            ..Default::default()
+        });
+        backend.chapter_list_calls.set(0);
+        assert_eq!(indices(&backend), vec![1, 3, 5, 6]);
+        // the chapter list is read to count the chapters & to classify them,
+        // not once per chapter
+        assert_eq!(backend.chapter_list_calls.get(), 2);
+    }
+
+    #[test]
+    fn test_get_chapters_includes_last_chapter() {
+        let backend = FakeBackend {
+            contents: (1..=109)
+                .map(|index| (index, format!("<p>Chapter {index}</p>")))
+                .collect(),
+            chapter_count: Some(109),
+            ..Default::default()
+        };
+        let chapters = backend.get_chapters().unwrap();
+        assert_eq!(chapters.len(), 109);
+        assert_eq!(*chapters.last().unwrap().index(), 109);
+    }
+
+    #[test]
+    fn test_get_chapters_by_indices() {
+        let backend = FakeBackend {
+            contents: (1..=50)
+                .map(|index| (index, format!("<p>Chapter {index}</p>")))
+                .collect(),
+            ..Default::default()
+        };
+        let results = backend.get_chapters_by_indices(&[42, 3, 51, 17]);
+        assert_eq!(results.len(), 4);
+        assert_eq!(*results[0].as_ref().unwrap().index(), 42);
+        assert_eq!(*results[1].as_ref().unwrap().index(), 3);
+        assert!(matches!(results[2], Err(BackendError::UnknownChapter(51))));
+        assert_eq!(results[3].as_ref().unwrap().content(), "<p>Chapter 17</p>");
+        assert!(backend.get_chapters_by_indices(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_max_chapters() {
+        let mut backend = FakeBackend {
+            contents: HashMap::from([(1, "<p>Only chapter</p>".to_string())]),
+            chapter_count: Some(usize::MAX),
+            ..Default::default()
+        };
+        assert!(matches!(
+            backend.get_chapters(),
+            Err(BackendError::TooManyChapters {
+                count: usize::MAX,
+                max: DEFAULT_MAX_CHAPTERS
+            })
+        ));
+
+        backend.links = HashMap::from([
+            (
+                "https://example.com/linked/1".to_string(),
+                Some("https://example.com/linked/2".to_string()),
+            ),
+            (
+                "https://example.com/linked/2".to_string(),
+                Some("https://example.com/linked/3".to_string()),
+            ),
+            ("https://example.com/linked/3".to_string(), None),
+        ]);
+        backend.set_backend_options(BackendOptions {
+            max_chapters: 2,
+            ..Default::default()
+        });
+        let chapters: Vec<_> = backend.walk_from_first_chapter().collect();
+        assert_eq!(chapters.len(), 3);
+        assert!(chapters[..2].iter().all(Result::is_ok));
+        assert!(matches!(
+            chapters[2],
+            Err(BackendError::TooManyChapters { count: 3, max: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_refresh_changed() {
+        let backend = FakeBackend {
+            contents: (1..=3)
+                .map(|i| (i, format!("<p>Content of chapter {i}</p>")))
+                .collect(),
+            ..Default::default()
+        };
+        let mut stored: Vec<(usize, String)> = (1..=3)
+            .map(|i| (i, backend.get_chapter(i).unwrap().content_hash()))
+            .collect();
+        assert!(backend.refresh_changed(&stored).unwrap().is_empty());
+
+        stored[1].1 = Chapter::default().content_hash();
+        let changed = backend.refresh_changed(&stored).unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(*changed[0].index(), 2);
+
+        stored.push((4, String::new()));
+        assert!(matches!(
+            backend.refresh_changed(&stored),
+            Err(BackendError::UnknownChapter(4))
+        ));
+    }
+
+    #[test]
+    fn test_get_chapters_concurrently() {
+        let backend = |indices: &[usize]| FakeBackend {
+            contents: indices
+                .iter()
+                .map(|i| (*i, format!("<p>Content of chapter {i}</p>")))
+                .collect(),
+            ..Default::default()
+        };
+        let indices: Vec<usize> = (1..=10).collect();
+        // chapters 4 & 7 cannot be fetched for now
+        let results = get_chapters_concurrently(&indices, || backend(&[1, 2, 3, 5, 6, 8, 9, 10]));
+        assert_eq!(results.len(), indices.len());
+        let failed: Vec<usize> = results
+            .iter()
+            .filter_map(|result| match result {
+                Err((i, BackendError::UnknownChapter(_))) => Some(*i),
+                Err((_, e)) => panic!("unexpected error {e}"),
+                Ok(_) => None,
+            })
+            .collect();
+        assert_eq!(failed, vec![4, 7]);
+        for (result, i) in results.iter().zip(&indices) {
+            if let Ok(chapter) = result {
+                assert_eq!(chapter.index(), i);
+            }
+        }
+
+        // resume with the failed indices only
+        let results = get_chapters_concurrently(&failed, || backend(&indices));
+        let fetched: Vec<usize> = results
+            .into_iter()
+            .map(|result| *result.unwrap().index())
+            .collect();
+        assert_eq!(fetched, failed);
+    }
+
+    #[test]
+    fn test_site_icon_url() {
+        for backend in Backends::iter().skip(1) {
+            assert!(
+                backend.site_icon_url().starts_with("https://"),
+                "{} has no site icon",
+                backend.get_backend_name()
+            );
+        }
+        let backend = FakeBackend::default();
+        assert_eq!(backend.site_icon_url(), "https://example.com/favicon.ico");
+    }
+
+    #[test]
+    fn test_get_first_chapters() {
+        let backend = FakeBackend {
+            contents: (1..=10)
+                .map(|index| (index, format!("<p>chapter {index}</p>")))
+                .collect(),
+            ..Default::default()
+        };
+        let chapters = backend.get_first_chapters(3).unwrap();
+        let indices: Vec<_> = chapters.iter().map(|chapter| *chapter.index()).collect();
+        assert_eq!(indices, [1, 2, 3]);
+        assert_eq!(backend.get_first_chapters(20).unwrap().len(), 10);
+        assert!(backend.get_first_chapters(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_chapters_ordered() {
+        // the third chapter was published first
+        let backend = FakeBackend {
+            contents: (1..=4)
+                .map(|index| (index, format!("<p>chapter {index}</p>")))
+                .collect(),
+            dates: HashMap::from([(3, DateTime::UNIX_EPOCH)]),
+            ..Default::default()
+        };
+        let chapters = backend.get_chapters_ordered().unwrap();
+        let mut expected = backend.get_chapters().unwrap();
+        expected.sort_by(FakeBackend::get_ordering_function());
+        let contents: Vec<_> = chapters.iter().map(|chapter| chapter.content()).collect();
+        assert_eq!(
+            contents,
+            expected
+                .iter()
+                .map(|chapter| chapter.content())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(contents[0], "<p>chapter 3</p>");
+        let indices: Vec<_> = chapters.iter().map(|chapter| *chapter.index()).collect();
+        assert_eq!(indices, (1..=chapters.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_get_next_after() {
+        let backend = FakeBackend {
+            contents: [1, 2, 4, 7]
+                .map(|index| (index, format!("<p>chapter {index}</p>")))
+                .into(),
+            ..Default::default()
+        };
+        let next_index = |index: usize| {
+            let mut chapter = Chapter::default();
+            chapter.set_index(index);
+            chapter.set_published_at(Some(DateTime::UNIX_EPOCH + Duration::days(index as i64)));
+            backend
+                .get_next_after(&chapter)
+                .unwrap()
+                .map(|next| *next.index())
+        };
+        assert_eq!(next_index(2), Some(4));
+        assert_eq!(next_index(4), Some(7));
+        assert_eq!(next_index(7), None);
+        // removed upstream
+        assert_eq!(next_index(5), Some(7));
+        assert_eq!(next_index(0), Some(1));
+        assert_eq!(next_index(9), None);
+    }
+
+    #[test]
+    fn test_metadata_language_is_declared_only() {
+        let backend = FakeBackend {
+            contents: HashMap::from([(1, "<p>很久很久以前，有一个国王。</p>".to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(backend.metadata().unwrap().language(), &None);
+        assert_eq!(backend.language().unwrap(), "zh");
+    }
+
+    #[test]
+    fn test_has_updates_since_without_dates() {
+        let backend = FakeBackend {
+            chapter_count: Some(3),
+            ..Default::default()
+        };
+        assert!(matches!(
+            backend.has_updates_since(DateTime::UNIX_EPOCH),
+            Err(BackendError::Unsupported(_))
+        ));
+        assert_eq!(backend.chapter_list_calls.get(), 0);
+    }
+
+    #[test]
+    fn test_get_next_after_removed_chapter() {
+        // chapters are numbered by their position in the list: once "c3" is
+        // removed upstream, "c4" is chapter 3 & "c5" chapter 4
+        let listed = ["c1", "c2", "c4", "c5"];
+        let backend = FakeBackend {
+            contents: (1..=listed.len())
+                .map(|index| (index, format!("<p>{}</p>", listed[index - 1])))
+                .collect(),
+            urls: (1..=listed.len())
+                .map(|index| (index, format!("https://example.com/{}", listed[index - 1])))
+                .collect(),
+            dates: (1..=listed.len())
+                .map(|index| {
+                    let number: i64 = listed[index - 1][1..].parse().unwrap();
+                    (index, DateTime::UNIX_EPOCH + Duration::days(number))
+                })
+                .collect(),
+            ..Default::default()
+        };
+        // a chapter downloaded before the removal, as "chapter {number}"
+        let next_content = |number: usize| {
+            let mut chapter = Chapter::default();
+            chapter.set_index(number);
+            chapter.set_chapter_url(format!("https://example.com/c{number}"));
+            chapter.set_published_at(Some(DateTime::UNIX_EPOCH + Duration::days(number as i64)));
+            backend
+                .get_next_after(&chapter)
+                .unwrap()
+                .map(|next| next.content().clone())
+        };
+        assert_eq!(next_content(2), Some("<p>c4</p>".to_string()));
+        assert_eq!(next_content(3), Some("<p>c4</p>".to_string()));
+        assert_eq!(next_content(4), Some("<p>c5</p>".to_string()));
+        assert_eq!(next_content(5), None);
+    }
+
+    #[test]
+    fn test_request_failed_display() {
+        let error = |content: String| BackendError::RequestFailed {
+            message: "Could not fetch https://example.com".to_string(),
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            content,
+        };
+        assert_eq!(
+            error("  oops\n".to_string()).to_string(),
+            "We could not access the fiction page: Could not fetch https://example.com: 500 Internal Server Error: oops"
+        );
+        let body = "é".repeat(5000);
+        let message = error(body.clone()).to_string();
+        assert!(message.contains(&"é".repeat(MAX_DISPLAYED_BODY_LENGTH)));
+        assert!(!message.contains(&"é".repeat(MAX_DISPLAYED_BODY_LENGTH + 1)));
+        assert!(message.ends_with("… (10000 bytes in total)"));
+        // the body itself is kept whole
+        assert!(
+            matches!(error(body.clone()), BackendError::RequestFailed { content, .. } if content == body)
+        );
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let request_failed = |status: u16| BackendError::RequestFailed {
+            message: "failed".to_string(),
+            status: StatusCode::from_u16(status).unwrap(),
+            content: String::new(),
+        };
+        let connection_error =
+            reqwest::blocking::get("http://127.0.0.1:1/closed-port").unwrap_err();
+        let builder_error = reqwest::blocking::get("not a url").unwrap_err();
+        let retryable = [
+            BackendError::NetError(connection_error),
+            request_failed(429),
+            request_failed(503),
+            BackendError::IoError(std::io::ErrorKind::TimedOut.into()),
+            BackendError::CircuitOpen {
+                host: "example.com:443".to_string(),
+                retry_after: std::time::Duration::from_secs(60),
+            },
+        ];
+        let permanent = [
+            BackendError::NoMatchingBackendFound("https://example.com".to_string()),
+            BackendError::NetError(builder_error),
+            BackendError::UrlNotFound,
+            request_failed(403),
+            BackendError::ParseError("no title".to_string(), None),
+            BackendError::parse_error_with_source("bad number", "x".parse::<u32>().unwrap_err()),
+            BackendError::DateParseError(DateTime::parse_from_rfc3339("yesterday").unwrap_err()),
+            BackendError::UnknownChapter(12),
+            BackendError::MissingChapterInformation {
+                msg: "no title".to_string(),
+                chapter: Box::default(),
+            },
+            BackendError::IoError(std::io::ErrorKind::NotFound.into()),
+            BackendError::Unsupported("searching".to_string()),
+            BackendError::PlaceholderChapter("https://example.com/chapter-1".to_string()),
+            BackendError::DeletedChapter("https://example.com/chapter-1".to_string()),
+            BackendError::InvalidClientConfig("invalid header".to_string()),
+        ];
+        for error in retryable {
+            assert!(error.is_retryable(), "{error:?} should be retryable");
+        }
+        for error in permanent {
+            assert!(!error.is_retryable(), "{error:?} should not be retryable");
+        }
+    }
+
+    #[test]
+    fn test_parse_chapter_url() {
+        #[cfg(any(feature = "freewebnovel", feature = "lightnovelworld"))]
+        use crate::backends::ChapterUrlParts;
+
+        assert_eq!(
+            Backends::parse_chapter_url("https://example.com/fiction/chapter-1"),
+            None
+        );
+        #[cfg(feature = "freewebnovel")]
+        assert_eq!(
+            Backends::parse_chapter_url(
+                "https://freewebnovel.com/the-guide-to-conquering-earthlings/chapter-12"
+            ),
+            Some(ChapterUrlParts {
+                fiction_id: "the-guide-to-conquering-earthlings".to_string(),
+                chapter_id: "12".to_string(),
+                slugs: Vec::new(),
+            })
+        );
+        #[cfg(feature = "lightnovelworld")]
+        assert_eq!(
+            Backends::parse_chapter_url(
+                "https://www.lightnovelworld.com/novel/the-perfect-run-24071713/chapter-3"
+            ),
+            Some(ChapterUrlParts {
+                fiction_id: "the-perfect-run-24071713".to_string(),
+                chapter_id: "3".to_string(),
+                slugs: Vec::new(),
+            })
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "royalroad"))]
+    #[test]
+    fn test_load_exported() {
+        use crate::manifest::FictionManifest;
+        use crate::FictionMetadata;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut metadata = FictionMetadata::default();
+        metadata.set_url("https://www.royalroad.com/fiction/21220/mother-of-learning".to_string());
+        metadata.set_title("Mother of Learning".to_string());
+        metadata.set_authors(vec!["nobody103".to_string()]);
+        // listed newest first, but sorted by publication date when loaded
+        let chapters: Vec<Chapter> = (1..=3)
+            .rev()
+            .map(|index| {
+                let mut chapter = Chapter::default();
+                chapter.set_index(index);
+                chapter.set_title(Some(format!("Chapter {index}")));
+                chapter.set_chapter_url(format!("{}/chapter/{index}", metadata.url()));
+                chapter.set_fiction_url(metadata.url().clone());
+                chapter.set_published_at(Some(DateTime::UNIX_EPOCH + Duration::days(index as i64)));
+                chapter.set_content(format!("<p>Content of chapter {index}</p>"));
+                chapter
+            })
+            .collect();
+        FictionManifest::write(dir.path(), &metadata, "royalroad", None, &chapters).unwrap();
+
+        let (loaded_metadata, loaded_chapters) = Backends::load_exported(dir.path()).unwrap();
+        assert_eq!(loaded_metadata.title(), "Mother of Learning");
+        assert_eq!(loaded_metadata.authors(), &vec!["nobody103".to_string()]);
+        assert_eq!(*loaded_metadata.chapter_count(), 3);
+        let indices: Vec<usize> = loaded_chapters.iter().map(|c| *c.index()).collect();
+        assert_eq!(indices, vec![1, 2, 3]);
+        assert_eq!(loaded_chapters[2], chapters[0]);
+        assert!(Backends::load_exported(&dir.path().join("missing")).is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "royalroad"))]
+    #[test]
+    fn test_export_manifest_without_cover() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        use crate::backends::RoyalRoad;
+        use crate::manifest::ExportOptions;
+
+        let cover_requests = Arc::new(AtomicUsize::new(0));
+        let server = MockServer::start_with_handler({
+            let cover_requests = cover_requests.clone();
+            move |path, headers| match path {
+                "/cover.png" => {
+                    cover_requests.fetch_add(1, Ordering::SeqCst);
+                    (200, "png".to_string())
+                }
+                _ => (
+                    200,
+                    format!(
+                        r#"<html><head>
+                        <meta property="og:image" content="http://{}/cover.png">
+                        <meta property="books:author" content="nobody103">
+                        </head><body><div class="row fic-header"><div class="fic-title"><div class="col">
+                        <h1 class="font-white">Mother of Learning</h1>
+                        </div></div></div></body></html>"#,
+                        headers["host"]
+                    ),
+                ),
+            }
+        });
+        let backend = Backends::RoyalRoad(Box::new(
+            RoyalRoad::new(&server.url("/fiction/21220/mother-of-learning")).unwrap(),
+        ));
+        let dir = tempfile::tempdir().unwrap();
+        let options = ExportOptions {
+            include_cover: false,
+        };
+        let manifest = backend
+            .export_manifest_with_options(dir.path(), options)
+            .unwrap();
+        assert_eq!(cover_requests.load(Ordering::SeqCst), 0);
+        assert_eq!(manifest.cover, None);
+        assert_eq!(manifest.cover_url, Some(server.url("/cover.png")));
+
+        let manifest = backend.export_manifest(dir.path()).unwrap();
+        assert_eq!(cover_requests.load(Ordering::SeqCst), 1);
+        assert_eq!(manifest.cover, Some("cover.png".to_string()));
+    }
+
+    #[cfg(feature = "lightnovelworld")]
+    #[test]
+    fn test_download_package() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        use crate::backends::LightNovelWorld;
+
+        let cover_available = Arc::new(AtomicBool::new(true));
+        let server_cover_available = cover_available.clone();
+        let server = MockServer::start_with_handler(move |path, headers| {
+            // only the configured client gets through
+            if headers.get("x-token").map(String::as_str) != Some("secret") {
+                return (403, "Forbidden".to_string());
+            }
+            let body = match path {
+                "/cover.jpg" if !server_cover_available.load(Ordering::SeqCst) => {
+                    return (403, "Forbidden".to_string());
+                }
+                "/cover.jpg" => "jpg".to_string(),
+                "/novel/fixture" => format!(
+                    r#"<html lang="en"><head>
+                    <meta property="og:image" content="http://{}/cover.jpg">
+                    </head><body>
+                    <h1 class="novel-title">Fixture</h1>
+                    <div class="author"><a><span>Jane Doe</span></a></div>
+                    </body></html>"#,
+                    headers["host"]
+                ),
+                // listed out of reading order
+                "/novel/fixture/chapters" => r#"<html><body>
+                    <article id="chapter-list-page"><section id="chpagedlist" class="container">
+                    <ul class="chapter-list">
+                    <li><a href="/novel/fixture/chapter-3" title="Chapter 3"><span class="chapter-no">3</span></a></li>
+                    <li><a href="/novel/fixture/chapter-1" title="Chapter 1"><span class="chapter-no">1</span></a></li>
+                    <li><a href="/novel/fixture/chapter-2" title="Chapter 2"><span class="chapter-no">2</span></a></li>
+                    </ul>
+                    <ul class="pagination"><li>next</li></ul>
+                    </section></article></body></html>"#
+                    .to_string(),
+                _ => {
+                    let Some(index) = path.strip_prefix("/novel/fixture/chapter-") else {
+                        return (404, String::new());
+                    };
+                    format!(
+                        r#"<html><body><article id="chapter-article"><section class="page-in content-wrap">
+                        <div class="titles"><h1><span class="chapter-title">Chapter {index}</span></h1>
+                        <meta itemprop="datePublished" content="2021-10-0{index}T08:00:00"></div>
+                        <div id="chapter-container"><p>Content of chapter {index}</p></div>
+                        </section></article></body></html>"#
+                    )
+                }
+            };
+            (200, body)
+        });
+        let config = ClientConfig {
+            headers: vec![("X-Token".to_string(), "secret".to_string())],
+            ..Default::default()
+        };
+        let mut backend = Backends::LightNovelWorld(Box::new(
+            LightNovelWorld::new_with_config(&server.url("/novel/fixture"), &config).unwrap(),
+        ));
+        let package = backend.download_package().unwrap();
+        assert_eq!(package.metadata().title(), "Fixture");
+        assert_eq!(package.metadata().authors(), &vec!["Jane Doe".to_string()]);
+        assert_eq!(*package.metadata().chapter_count(), 3);
+        assert_eq!(package.metadata().language(), &Some("en".to_string()));
+        let cover = package.cover().as_ref().unwrap();
+        assert_eq!(cover.url(), &server.url("/cover.jpg"));
+        assert_eq!(cover.bytes(), b"jpg");
+        let indices: Vec<usize> = package.chapters().iter().map(|c| *c.index()).collect();
+        assert_eq!(indices, vec![1, 2, 3]);
+        assert_eq!(
+            package.chapters()[1].title(),
+            &Some("Chapter 2".to_string())
+        );
+        assert!(package.chapters()[1]
+            .content()
+            .contains("Content of chapter 2"));
+
+        cover_available.store(false, Ordering::SeqCst);
+        backend.set_backend_options(BackendOptions {
+            title_override: Some("The Fixture".to_string()),
+            ..Default::default()
+        });
+        let package = backend.download_package().unwrap();
+        assert_eq!(package.metadata().title(), "The Fixture");
+        assert!(package.cover().is_none());
+        assert_eq!(package.chapters().len(), 3);
+
+        assert!(matches!(
+            Backends::download_full("https://example.com/novel/fixture"),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+    }
+
+    #[cfg(all(feature = "lightnovelworld", feature = "epub"))]
+    #[test]
+    fn test_export_volumes() {
+        use std::io::Read;
+
+        use crate::backends::LightNovelWorld;
+
+        let server = MockServer::start_with_handler(|path, headers| {
+            let body = match path {
+                "/cover.jpg" => "jpg".to_string(),
+                "/novel/fixture" => format!(
+                    r#"<html lang="en"><head>
+                    <meta property="og:image" content="http://{}/cover.jpg">
+                    </head><body>
+                    <h1 class="novel-title">Fixture</h1>
+                    <div class="author"><a><span>Jane Doe</span></a></div>
+                    </body></html>"#,
+                    headers["host"]
+                ),
+                "/novel/fixture/chapters" => r#"<html><body>
+                    <article id="chapter-list-page"><section id="chpagedlist" class="container">
+                    <ul class="chapter-list">
+                    <li data-volumeno="1"><a href="/novel/fixture/chapter-1" title="Chapter 1"><span class="chapter-no">1</span></a></li>
+                    <li data-volumeno="1"><a href="/novel/fixture/chapter-2" title="Chapter 2"><span class="chapter-no">2</span></a></li>
+                    <li data-volumeno="2"><a href="/novel/fixture/chapter-3" title="Chapter 3"><span class="chapter-no">3</span></a></li>
+                    </ul>
+                    <ul class="pagination"><li>next</li></ul>
+                    </section></article></body></html>"#
+                    .to_string(),
+                _ => {
+                    let Some(index) = path.strip_prefix("/novel/fixture/chapter-") else {
+                        return (404, String::new());
+                    };
+                    format!(
+                        r#"<html><body><article id="chapter-article"><section class="page-in content-wrap">
+                        <div class="titles"><h1><span class="chapter-title">Chapter {index}</span></h1></div>
+                        <div id="chapter-container"><p>Content of chapter {index}</p></div>
+                        </section></article></body></html>"#
+                    )
+                }
+            };
+            (200, body)
+        });
+        let backend = Backends::LightNovelWorld(Box::new(
+            LightNovelWorld::new(&server.url("/novel/fixture")).unwrap(),
+        ));
+        let dir = tempfile::tempdir().unwrap();
+        let paths = backend.export_volumes(dir.path()).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("fixture-01-volume-1.epub"),
+                dir.path().join("fixture-02-volume-2.epub"),
+            ]
+        );
+        let chapter_contents: Vec<Vec<String>> = paths
+            .iter()
+            .map(|path| {
+                let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+                // every volume has the cover of the fiction
+                assert!(archive.by_name("EPUB/cover.jpg").is_ok());
+                let names: Vec<String> = archive
+                    .file_names()
+                    .filter(|name| name.starts_with("EPUB/chapter-"))
+                    .map(String::from)
+                    .collect();
+                names
+                    .iter()
+                    .map(|name| {
+                        let mut content = String::new();
+                        archive
+                            .by_name(name)
+                            .unwrap()
+                            .read_to_string(&mut content)
+                            .unwrap();
+                        content
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(chapter_contents.len(), 2);
+        assert_eq!(chapter_contents[0].len(), 2);
+        assert_eq!(chapter_contents[1].len(), 1);
+        assert!(chapter_contents[0][1].contains("<p>Content of chapter 2</p>"));
+        assert!(chapter_contents[1][0].contains("<p>Content of chapter 3</p>"));
+    }
+
+    #[cfg(feature = "royalroad")]
+    #[test]
+    fn test_describe() {
+        let options = BackendOptions {
+            max_chapters: 100,
+            title_override: Some("Mother of Learning".to_string()),
+            ..Default::default()
+        };
+        let description = options.describe();
+        assert!(description.contains(&format!(
+            "max_chapters: 100 (default: {DEFAULT_MAX_CHAPTERS})"
+        )));
+        assert!(
+            description.contains("title_override: Some(\"Mother of Learning\") (default: None)")
+        );
+        assert!(description
+            .lines()
+            .any(|line| line == "strip_navigation: false"));
+        assert!(description.contains("rate_limit: "));
+        assert!(description.contains("circuit_breaker: "));
+
+        let mut backend = Backends::RoyalRoad(Box::default());
+        backend.set_backend_options(options.clone());
+        let description = backend.describe();
+        assert!(description.starts_with("backend: royalroad\n"));
+        // network settings are global, and may be changed by concurrent tests
+        let options_description = options.describe();
+        let options_section = options_description.split("\nuser_agent: ").next().unwrap();
+        assert!(description.contains(&format!("\n{options_section}\nuser_agent: ")));
+    }
+
+    #[cfg(feature = "royalroad")]
+    #[test]
+    fn test_merge_chapter_sets() {
+        let chapter = |index: usize, days: i64, content: &str| {
+            let mut chapter = Chapter::default();
+            chapter.set_index(index);
+            chapter.set_chapter_url(format!("https://example.com/fiction/chapter-{index}"));
+            chapter.set_published_at(Some(DateTime::UNIX_EPOCH + Duration::days(days)));
+            chapter.set_content(content);
+            chapter
+        };
+        let old = vec![
+            chapter(1, 1, "<p>one</p>"),
+            chapter(2, 2, "<p>two</p>"),
+            chapter(3, 3, "<p>three</p>"),
+        ];
+        let new = vec![
+            // edited since
+            chapter(2, 2, "<p>two, fixed</p>"),
+            // republished with older content
+            chapter(3, 1, "<p>three, draft</p>"),
+            chapter(4, 4, "<p>four</p>"),
+        ];
+        let backend = Backends::RoyalRoad(Default::default());
+        let merged = backend.merge_chapter_sets(old, new);
+        let contents: Vec<_> = merged.iter().map(|chapter| chapter.content()).collect();
+        assert_eq!(
+            contents,
+            [
+                "<p>one</p>",
+                "<p>two, fixed</p>",
+                "<p>three</p>",
+                "<p>four</p>"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_url_suffix_ordering() {
+        let mut chapters: Vec<Chapter> = [
+            ("Epilogue", "https://example.com/fiction/chapter-12"),
+            ("Chapter 1", "https://example.com/fiction/chapter-3/"),
+            ("Afterword", "https://example.com/fiction/afterword"),
+            (
+                "Chapter 12",
+                "https://example.com/fiction/chapter-1?lang=en",
+            ),
+        ]
+        .into_iter()
+        .map(|(title, url)| {
+            let mut chapter = Chapter::default();
+            chapter.set_title(Some(title.to_string()));
+            chapter.set_chapter_url(url.to_string());
+            chapter
+        })
+        .collect();
+        chapters.sort_by(url_suffix_ordering());
+        let titles: Vec<_> = chapters
+            .iter()
+            .map(|chapter| chapter.title().clone().unwrap())
+            .collect();
+        assert_eq!(titles, ["Chapter 12", "Chapter 1", "Epilogue", "Afterword"]);
+    }
+
+    #[cfg(feature = "royalroad")]
+    #[test]
+    fn test_try_all_backends() {
+        let server = MockServer::start(&[
+            (
+                "/fiction/21220/mother-of-learning",
+                200,
+                r#"<div class="row fic-header"><div class="fic-title"><div class="col">
+                <h1 class="font-white">Mother of Learning</h1>
+                </div></div></div>"#,
+            ),
+            ("/blank", 200, "<p>nothing to see here</p>"),
+        ]);
+        let mirror_url = server.url("/fiction/21220/mother-of-learning");
+        assert!(matches!(
+            Backends::new(&mirror_url),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+        let backend = Backends::try_all_backends(&mirror_url).unwrap();
+        assert_eq!(backend.get_backend_name(), "royalroad");
+        assert_eq!(backend.title().unwrap(), "Mother of Learning");
+        assert!(matches!(
+            Backends::try_all_backends(&server.url("/blank")),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_with_config() {
+        let config = ClientConfig {
+            user_agent: "Browser/2.0".to_string(),
+            ..Default::default()
+        };
+        // backends without support for custom clients refuse them
+        assert!(matches!(
+            FakeBackend::new_with_config("https://example.com/fiction/1", &config),
+            Err(BackendError::Unsupported(_))
+        ));
+        assert!(matches!(
+            FakeBackend::new_with_config("https://example.com/fiction/1", &ClientConfig::default()),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+        assert!(matches!(
+            Backends::new_with_config("https://example.com/fiction/1", &config),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+        #[cfg(feature = "royalroad")]
+        assert!(matches!(
+            Backends::new_with_config(
+                "https://www.royalroad.com/fiction/21220/mother-of-learning",
+                &ClientConfig {
+                    headers: vec![("X-Test".to_string(), "line\nbreak".to_string())],
+                    ..Default::default()
+                },
+            ),
+            Err(BackendError::InvalidClientConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_fiction_status_from_label() {
+        assert_eq!(FictionStatus::from_label("ONGOING"), FictionStatus::Ongoing);
+        assert_eq!(
+            FictionStatus::from_label(" Completed\n"),
+            FictionStatus::Completed
+        );
+        assert_eq!(FictionStatus::from_label("HIATUS"), FictionStatus::Hiatus);
+        assert_eq!(FictionStatus::from_label("STUB"), FictionStatus::Dropped);
+        assert_eq!(
+            FictionStatus::from_label("Original"),
+            FictionStatus::Unknown
+        );
+        assert_eq!(
+            FakeBackend::default().status().unwrap(),
+            FictionStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_capabilities() {
+        assert_eq!(
+            Backends::Dumb.capabilities(),
+            BackendCapabilities::default()
+        );
+        #[cfg(feature = "royalroad")]
+        {
+            let capabilities = Backends::RoyalRoad(Default::default()).capabilities();
+            assert!(capabilities.has_cover);
+            assert!(capabilities.has_dates);
+            assert!(!capabilities.supports_search);
+            assert!(capabilities.supports_description);
+            assert!(capabilities.supports_tags);
         }
     }
 }
@@ -0,0 +1,653 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::LazyLock;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use html_escape::decode_html_entities;
+use log::warn;
+use regex::Regex;
+use reqwest::blocking::Response;
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::backends::{
+    check_not_redirected_home, ensure_unique_chapter_urls, BackendCapabilities, BackendError,
+    BackendOptions, ChapterListElem, ChapterUrlParts, BACKEND_NAME_METADATA_KEY,
+};
+use crate::utils::{
+    cloudflare_challenge_error, declared_language, is_cloudflare_challenge, parse_date,
+    ClientConfig, CloudflareClearance, HttpClient,
+};
+use crate::{Backend, Chapter, ChapterInfo};
+
+/// Used to identify a fiction URL & extract the series ID from it
+static SCRIBBLEHUB_FICTION_URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://(?:www\.)?scribblehub\.com/series/(?<series_id>\d+)/(?<slug>[^/?#]+)")
+        .unwrap()
+});
+
+/// Used to identify a chapter URL, such as
+/// `https://www.scribblehub.com/read/123456-the-slug/chapter/789012/`
+static SCRIBBLEHUB_CHAPTER_URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"https?://(?:www\.)?scribblehub\.com/read/(?<series_id>\d+)-(?<slug>[^/?#]+)/chapter/(?<chapter_id>\d+)",
+    )
+    .unwrap()
+});
+
+/// Format of the publication dates in the `title` of the chapter list
+/// entries, such as `Mar 3, 2023 07:42 PM`.
+const PUBLICATION_DATE_FORMAT: &str = "%b %d, %Y %I:%M %p";
+
+/// A chapter, as listed in the table of contents.
+#[derive(Debug, Clone)]
+struct ListedChapter {
+    index: usize,
+    title: String,
+    url: String,
+    published_at: Option<DateTime<Utc>>,
+}
+
+impl ListedChapter {
+    fn info(&self) -> ChapterInfo {
+        let mut info = ChapterInfo::default();
+        info.set_index(self.index);
+        info.set_title(Some(self.title.clone()));
+        info.set_chapter_url(Some(self.url.clone()));
+        info.set_published_at(self.published_at);
+        info
+    }
+}
+
+/// A backend for [ScribbleHub](https://www.scribblehub.com).
+///
+/// ScribbleHub is behind Cloudflare, which sometimes answers with a challenge
+/// page instead of the requested one. Challenges are retried a few times,
+/// after which [`BackendError::RequestFailed`] is returned: pass the
+/// challenge in a browser, then build the backend with
+/// [`ScribbleHub::with_cloudflare_clearance`] to send the resulting
+/// `cf_clearance` cookie along with the browser's User-Agent.
+///
+/// The table of contents is paginated (`?toc=2`, `?toc=3`…), every page is
+/// read when the backend is built.
+#[derive(Clone)]
+pub struct ScribbleHub {
+    url: String,
+    main_page: Html,
+    chapters: Vec<ListedChapter>,
+    clearance: Option<CloudflareClearance>,
+    options: BackendOptions,
+    client: HttpClient,
+}
+
+impl Default for ScribbleHub {
+    fn default() -> Self {
+        Self {
+            url: "".to_string(),
+            main_page: Html::new_document(),
+            chapters: Vec::new(),
+            clearance: None,
+            options: BackendOptions::default(),
+            client: HttpClient::default(),
+        }
+    }
+}
+
+#[allow(unused_variables, dead_code)]
+impl Debug for ScribbleHub {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        #[derive(Debug)]
+        struct ScribbleHub<'a> {
+            url: &'a String,
+            chapters: usize,
+        }
+        let Self {
+            url,
+            main_page: _,
+            chapters,
+            clearance: _,
+            options: _,
+            client: _,
+        } = self;
+        Debug::fmt(
+            &ScribbleHub {
+                url,
+                chapters: chapters.len(),
+            },
+            f,
+        )
+    }
+}
+
+impl ScribbleHub {
+    /// Builds the backend for the fiction at `url`, getting past Cloudflare's
+    /// challenges with the given clearance.
+    ///
+    /// ```rust,no_run
+    /// use libwebnovel::backends::ScribbleHub;
+    /// use libwebnovel::{Backend, CloudflareClearance};
+    /// let clearance = CloudflareClearance {
+    ///     cf_clearance: "value of the cookie, as found in the browser".to_string(),
+    ///     user_agent: "Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0"
+    ///         .to_string(),
+    /// };
+    /// let backend = ScribbleHub::with_cloudflare_clearance(
+    ///     "https://www.scribblehub.com/series/862913/the-perfect-run/",
+    ///     clearance,
+    /// )
+    /// .unwrap();
+    /// println!("{}", backend.title().unwrap());
+    /// ```
+    pub fn with_cloudflare_clearance(
+        url: &str,
+        clearance: CloudflareClearance,
+    ) -> Result<Self, BackendError> {
+        Self::new_with_clearance(url, Some(clearance), HttpClient::default())
+    }
+
+    fn new_with_clearance(
+        url: &str,
+        clearance: Option<CloudflareClearance>,
+        client: HttpClient,
+    ) -> Result<Self, BackendError> {
+        let mut backend = Self {
+            url: url.to_string(),
+            clearance,
+            client,
+            ..Default::default()
+        };
+        backend.main_page = backend.get_page(url)?;
+        backend.chapters = backend.list_chapters()?;
+        Ok(backend)
+    }
+
+    /// Fetches the given URL, sending the Cloudflare clearance if there is
+    /// one.
+    fn get(&self, url: &str) -> Result<Response, BackendError> {
+        let headers: Vec<(&str, String)> = self
+            .clearance
+            .iter()
+            .flat_map(CloudflareClearance::headers)
+            .collect();
+        let headers: Vec<(&str, &str)> = headers
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        self.client.get_with_headers(url, &headers)
+    }
+
+    /// Fetches & parses the given page.
+    fn get_page(&self, url: &str) -> Result<Html, BackendError> {
+        page_html(url, self.get(url)?)
+    }
+
+    /// Walks the pages of the table of contents, which lists the chapters
+    /// from the most recent one.
+    fn list_chapters(&self) -> Result<Vec<ListedChapter>, BackendError> {
+        static PAGE_LINK_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("ul.simple-pagination a.page-link").unwrap());
+
+        let page_count = self
+            .main_page
+            .select(&PAGE_LINK_SELECTOR)
+            .filter_map(|link| link.text().collect::<String>().trim().parse::<usize>().ok())
+            .max()
+            .unwrap_or(1);
+        let mut chapters = toc_chapters(&self.main_page, &self.url)?;
+        for page in 2..=page_count {
+            let toc_page = self.get_page(&toc_page_url(&self.url, page)?)?;
+            let page_chapters = toc_chapters(&toc_page, &self.url)?;
+            if page_chapters.is_empty() {
+                warn!(
+                    "Page {page} of the table of contents of {} is empty",
+                    self.url
+                );
+                break;
+            }
+            chapters.extend(page_chapters);
+        }
+        chapters.sort_by_key(|chapter| chapter.index);
+        let urls: Vec<&str> = chapters
+            .iter()
+            .map(|chapter| chapter.url.as_str())
+            .collect();
+        ensure_unique_chapter_urls(&urls)?;
+        self.options.check_chapter_count(chapters.len())?;
+        Ok(chapters)
+    }
+
+    /// Returns the listed chapter with the given index.
+    fn listed_chapter(&self, chapter_number: usize) -> Result<&ListedChapter, BackendError> {
+        self.chapters
+            .iter()
+            .find(|chapter| chapter.index == chapter_number)
+            .ok_or(BackendError::UnknownChapter(chapter_number))
+    }
+
+    /// Returns the text of the first element matching `selector` in the
+    /// fiction page.
+    fn main_page_text(&self, selector: &Selector, what: &str) -> Result<String, BackendError> {
+        self.main_page
+            .select(selector)
+            .next()
+            .map(|element| element.text().collect::<String>().trim().to_string())
+            .filter(|text| !text.is_empty())
+            .ok_or(BackendError::ParseError(
+                format!("Could not find the {what} in fiction page {}", self.url),
+                None,
+            ))
+    }
+}
+
+/// Parses a page, making sure it is not a Cloudflare challenge.
+fn page_html(url: &str, response: Response) -> Result<Html, BackendError> {
+    let status = response.status();
+    if !status.is_success() {
+        return Err(BackendError::RequestFailed {
+            message: format!("Could not get page {url}"),
+            status,
+            content: response.text()?,
+        });
+    }
+    let content = response.text()?;
+    if is_cloudflare_challenge(&content) {
+        return Err(cloudflare_challenge_error(url, status, content));
+    }
+    Ok(Html::parse_document(&content))
+}
+
+/// Returns the URL of the given page of the table of contents of the fiction
+/// at `fiction_url`.
+fn toc_page_url(fiction_url: &str, page: usize) -> Result<String, BackendError> {
+    let mut url = Url::parse(fiction_url).map_err(|e| {
+        BackendError::ParseError(format!("Invalid fiction URL {fiction_url}: {e}"), None)
+    })?;
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| name != "toc")
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    url.set_fragment(None);
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(query)
+        .append_pair("toc", &page.to_string());
+    Ok(url.into())
+}
+
+/// Returns the chapters listed on a page of the table of contents of the
+/// fiction at `fiction_url`.
+fn toc_chapters(page: &Html, fiction_url: &str) -> Result<Vec<ListedChapter>, BackendError> {
+    static CHAPTER_SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("ol.toc_ol li.toc_w").unwrap());
+    static LINK_SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("a.toc_a").unwrap());
+    static DATE_SELECTOR: LazyLock<Selector> =
+        LazyLock::new(|| Selector::parse("span.fic_date_pub").unwrap());
+
+    page.select(&CHAPTER_SELECTOR)
+        .map(|item| {
+            let index = item
+                .attr("order")
+                .and_then(|order| order.parse().ok())
+                .ok_or(BackendError::ParseError(
+                    format!("Could not find the position of chapter {}", item.html()),
+                    None,
+                ))?;
+            let link = item
+                .select(&LINK_SELECTOR)
+                .next()
+                .ok_or(BackendError::ParseError(
+                    format!("Could not find the link to chapter {index}"),
+                    None,
+                ))?;
+            let href = link.attr("href").ok_or(BackendError::ParseError(
+                format!("Could not find the URL of chapter {index}"),
+                None,
+            ))?;
+            let url = Url::parse(fiction_url)
+                .and_then(|fiction_url| fiction_url.join(href))
+                .map_or_else(|_| href.to_string(), String::from);
+            let published_at = item.select(&DATE_SELECTOR).next().and_then(|date| {
+                date.attr("title")
+                    .and_then(|title| {
+                        NaiveDateTime::parse_from_str(title.trim(), PUBLICATION_DATE_FORMAT).ok()
+                    })
+                    .map(|date| date.and_utc())
+                    .or_else(|| parse_date(date.text().collect::<String>().trim()).ok())
+            });
+            Ok(ListedChapter {
+                index,
+                title: decode_html_entities(link.text().collect::<String>().trim()).to_string(),
+                url,
+                published_at,
+            })
+        })
+        .collect()
+}
+
+/// Returns the content of the chapter container, without its scripts & ads.
+fn chapter_content(container: ElementRef) -> String {
+    container
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|element| !["script", "style", "ins", "iframe"].contains(&element.value().name()))
+        .map(|element| element.html())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Implementation of [`Backend`] for [ScribbleHub](https://www.scribblehub.com)
+impl Backend for ScribbleHub {
+    fn get_backend_regexps() -> Vec<Regex> {
+        vec![SCRIBBLEHUB_FICTION_URL_REGEX.clone()]
+    }
+
+    fn get_backend_name() -> &'static str {
+        "scribblehub"
+    }
+
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities {
+            has_cover: true,
+            has_dates: true,
+            ..Default::default()
+        }
+    }
+
+    fn parse_chapter_url(url: &str) -> Option<ChapterUrlParts> {
+        let captures = SCRIBBLEHUB_CHAPTER_URL_REGEX.captures(url)?;
+        Some(ChapterUrlParts {
+            fiction_id: captures["series_id"].to_string(),
+            chapter_id: captures["chapter_id"].to_string(),
+            slugs: vec![captures["slug"].to_string()],
+        })
+    }
+
+    /// Builds the backend without a Cloudflare clearance, see
+    /// [`ScribbleHub::with_cloudflare_clearance`].
+    fn new(url: &str) -> Result<Self, BackendError> {
+        Self::new_with_config(url, &ClientConfig::default())
+    }
+
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        Self::new_with_clearance(url, None, config.build()?)
+    }
+
+    fn http_client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        &self.options
+    }
+
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        self.options = options;
+    }
+
+    fn title(&self) -> Result<String, BackendError> {
+        static TITLE_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("div.fic_title").unwrap());
+        self.main_page_text(&TITLE_SELECTOR, "title")
+    }
+
+    /// Returns the series ID, as found in the fiction URL.
+    fn immutable_identifier(&self) -> Result<String, BackendError> {
+        SCRIBBLEHUB_FICTION_URL_REGEX
+            .captures(&self.url)
+            .map(|captures| captures["series_id"].to_string())
+            .ok_or(BackendError::ParseError(
+                format!("Could not find a proper identifier in URL {}", self.url),
+                None,
+            ))
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn site_icon_url(&self) -> String {
+        "https://www.scribblehub.com/favicon.ico".to_string()
+    }
+
+    fn declared_language(&self) -> Option<String> {
+        declared_language(&self.main_page)
+    }
+
+    fn cover_url(&self) -> Result<String, BackendError> {
+        static COVER_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("div.fic_image img").unwrap());
+        self.main_page
+            .select(&COVER_SELECTOR)
+            .next()
+            .and_then(|img| img.attr("src"))
+            .map(str::to_string)
+            .ok_or(BackendError::ParseError(
+                format!("Could not find the cover in fiction page {}", self.url),
+                None,
+            ))
+    }
+
+    fn get_authors(&self) -> Result<Vec<String>, BackendError> {
+        static AUTHOR_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("span.auth_name_fic").unwrap());
+        Ok(vec![self.main_page_text(&AUTHOR_SELECTOR, "author")?])
+    }
+
+    fn get_chapter_list(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+        Ok(self
+            .chapters
+            .iter()
+            .map(|chapter| (chapter.index, chapter.title.clone()))
+            .collect())
+    }
+
+    fn get_chapter_info(&self, chapter_number: usize) -> Result<ChapterInfo, BackendError> {
+        Ok(self.listed_chapter(chapter_number)?.info())
+    }
+
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        Ok(self.chapters.iter().map(ListedChapter::info).collect())
+    }
+
+    fn get_chapter_count(&self) -> Result<usize, BackendError> {
+        Ok(self.chapters.len())
+    }
+
+    fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
+        static CHAPTER_TITLE_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("div.chapter-title").unwrap());
+        static CHAPTER_CONTENT_SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("div#chp_raw").unwrap());
+
+        let listed = self.listed_chapter(chapter_number)?;
+        let response = self.get(&listed.url)?;
+        check_not_redirected_home(&response, &listed.url, &self.url)?;
+        let page = page_html(&listed.url, response)?;
+        let content =
+            page.select(&CHAPTER_CONTENT_SELECTOR)
+                .next()
+                .ok_or(BackendError::ParseError(
+                    format!("Could not find the content of chapter {}", listed.url),
+                    None,
+                ))?;
+        let title = page
+            .select(&CHAPTER_TITLE_SELECTOR)
+            .next()
+            .map(|title| title.text().collect::<String>().trim().to_string())
+            .filter(|title| !title.is_empty())
+            .unwrap_or(listed.title.clone());
+        let mut chapter = Chapter::default();
+        chapter.set_index(chapter_number);
+        chapter.set_title(Some(title));
+        chapter.set_chapter_url(listed.url.clone());
+        chapter.set_fiction_url(self.url.clone());
+        chapter.set_published_at(listed.published_at);
+        chapter.set_content(chapter_content(content));
+        chapter.set_raw_content(self.options.raw_content(&content.inner_html()));
+        chapter.add_metadata(BACKEND_NAME_METADATA_KEY, Self::get_backend_name());
+        self.options.clean_chapter(&mut chapter);
+        self.options
+            .check_chapter(&chapter, Self::capabilities().has_dates)?;
+        Ok(chapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::backends::scribblehub::{toc_page_url, ScribbleHub};
+    use crate::backends::{BackendError, BACKEND_NAME_METADATA_KEY};
+    use crate::test_support::MockServer;
+    use crate::{Backend, CloudflareClearance};
+
+    const CHALLENGE: &str = r#"<!DOCTYPE html><html><head><title>Just a moment...</title></head>
+        <body><script src="/cdn-cgi/challenge-platform/h/g/orchestrate/chl_page/v1"></script></body></html>"#;
+
+    /// Serves a fiction with 3 chapters, listed on 2 pages (most recent
+    /// first), behind a Cloudflare challenge unless the clearance is sent.
+    fn fixture_server() -> MockServer {
+        MockServer::start_with_handler(|path, headers| {
+            let cleared = headers.get("cookie").map(String::as_str) == Some("cf_clearance=ok")
+                && headers.get("user-agent").map(String::as_str) == Some("Browser/1.0");
+            if !cleared {
+                // a challenge that made it through with a 200
+                return (200, CHALLENGE.to_string());
+            }
+            let entry = |index: usize| {
+                format!(
+                    r#"<li class="toc_w" order="{index}"><a class="toc_a" href="/read/123456-the-run/chapter/{}/">Chapter {index} &amp; more</a>
+                    <span class="fic_date_pub" title="Mar 3, 2023 07:42 PM">1 year ago</span></li>"#,
+                    1000 + index
+                )
+            };
+            let body = match path {
+                "/series/123456/the-run/" => format!(
+                    r#"<html lang="en"><body>
+                    <div class="fic_image"><img src="https://cdn.scribblehub.com/cover.jpg"></div>
+                    <div class="fic_title">The Run</div>
+                    <span class="auth_name_fic">Maxime J. Durand</span>
+                    <ol class="toc_ol">{}{}</ol>
+                    <ul class="simple-pagination"><li class="active"><span>1</span></li>
+                    <li><a class="page-link" href="?toc=2#content1">2</a></li>
+                    <li><a class="page-link next" href="?toc=2#content1">Next</a></li></ul>
+                    </body></html>"#,
+                    entry(3),
+                    entry(2)
+                ),
+                "/series/123456/the-run/?toc=2" => {
+                    format!(r#"<ol class="toc_ol">{}</ol>"#, entry(1))
+                }
+                "/read/123456-the-run/chapter/1001/" => r#"<html><body>
+                    <div class="chapter-title">Chapter 1: Start</div>
+                    <div id="chp_raw" class="chp_raw"><p>It begins.</p><script>ads()</script><p>Again.</p></div>
+                    </body></html>"#
+                    .to_string(),
+                _ => return (404, "not found".to_string()),
+            };
+            (200, body)
+        })
+    }
+
+    fn clearance() -> CloudflareClearance {
+        CloudflareClearance {
+            cf_clearance: "ok".to_string(),
+            user_agent: "Browser/1.0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_scribblehub() {
+        let server = fixture_server();
+        let url = server.url("/series/123456/the-run/");
+        let backend = ScribbleHub::with_cloudflare_clearance(&url, clearance()).unwrap();
+        assert_eq!(backend.title().unwrap(), "The Run");
+        assert_eq!(backend.get_authors().unwrap(), vec!["Maxime J. Durand"]);
+        assert_eq!(
+            backend.cover_url().unwrap(),
+            "https://cdn.scribblehub.com/cover.jpg"
+        );
+        assert_eq!(backend.language().unwrap(), "en");
+        assert_eq!(
+            backend.get_chapter_list().unwrap(),
+            vec![
+                (1, "Chapter 1 & more".to_string()),
+                (2, "Chapter 2 & more".to_string()),
+                (3, "Chapter 3 & more".to_string()),
+            ]
+        );
+        let published_at = Utc.with_ymd_and_hms(2023, 3, 3, 19, 42, 0).single();
+        assert_eq!(
+            backend.get_chapter_info(2).unwrap().published_at(),
+            &published_at
+        );
+
+        let chapter = backend.get_chapter(1).unwrap();
+        assert_eq!(chapter.title(), &Some("Chapter 1: Start".to_string()));
+        assert_eq!(chapter.content(), "<p>It begins.</p>\n<p>Again.</p>");
+        assert_eq!(chapter.published_at(), &published_at);
+        assert_eq!(chapter.fiction_url(), &url);
+        assert_eq!(
+            chapter.metadata().get(BACKEND_NAME_METADATA_KEY),
+            Some(&"scribblehub".to_string())
+        );
+        assert!(matches!(
+            backend.get_chapter(4),
+            Err(BackendError::UnknownChapter(4))
+        ));
+        // listed, but deleted since
+        assert!(matches!(
+            backend.get_chapter(2),
+            Err(BackendError::UrlNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_scribblehub_cloudflare_challenge() {
+        let server = fixture_server();
+        let url = server.url("/series/123456/the-run/");
+        match ScribbleHub::new(&url) {
+            Err(BackendError::RequestFailed { message, .. }) => {
+                assert!(message.contains("Cloudflare"), "{message}")
+            }
+            result => panic!("unexpected result {result:?}"),
+        }
+        // the clearance is only honored along with its User-Agent
+        let clearance = CloudflareClearance {
+            user_agent: "Other/2.0".to_string(),
+            ..clearance()
+        };
+        assert!(ScribbleHub::with_cloudflare_clearance(&url, clearance).is_err());
+    }
+
+    #[test]
+    fn test_scribblehub_urls() {
+        let backend = ScribbleHub {
+            url: "https://www.scribblehub.com/series/862913/the-perfect-run/".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(backend.immutable_identifier().unwrap(), "862913");
+        let parts = ScribbleHub::parse_chapter_url(
+            "https://www.scribblehub.com/read/862913-the-perfect-run/chapter/862914/",
+        )
+        .unwrap();
+        assert_eq!(parts.fiction_id, "862913");
+        assert_eq!(parts.chapter_id, "862914");
+        assert_eq!(parts.slugs, vec!["the-perfect-run"]);
+        assert!(ScribbleHub::get_backend_regexps()[0]
+            .is_match("https://www.scribblehub.com/series/862913/the-perfect-run/"));
+        assert_eq!(
+            toc_page_url(&backend.url, 2).unwrap(),
+            "https://www.scribblehub.com/series/862913/the-perfect-run/?toc=2"
+        );
+        assert_eq!(
+            toc_page_url(
+                "https://www.scribblehub.com/series/862913/the-perfect-run/?sort=asc&toc=1#chapters",
+                3
+            )
+            .unwrap(),
+            "https://www.scribblehub.com/series/862913/the-perfect-run/?sort=asc&toc=3"
+        );
+    }
+}
@@ -0,0 +1,1169 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+
+use html_escape::decode_html_entities;
+use log::debug;
+use regex::Regex;
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::backends::{
+    chapter_title_or_untitled, check_not_redirected_home, ensure_unique_chapter_urls,
+    BackendCapabilities, BackendError, BackendOptions, ChapterListElem, BACKEND_NAME_METADATA_KEY,
+};
+use crate::document_cache::DocumentCache;
+use crate::utils::{declared_language, readable_content, slugify, ClientConfig, HttpClient};
+use crate::{Backend, Chapter, ChapterInfo, Volume};
+
+/// Describes how to scrape a site, so that it can be supported by a
+/// [`TemplateBackend`] without writing a dedicated backend.
+///
+/// All selectors are CSS selectors, as understood by
+/// [`scraper::Selector::parse`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SiteTemplate {
+    /// Regexps matching the URLs of the fictions of the site.
+    pub url_patterns: Vec<String>,
+    /// Selects the element containing the fiction's title, on the fiction
+    /// page.
+    pub title_selector: String,
+    /// Selects the element(s) containing the fiction's authors, on the fiction
+    /// page.
+    pub authors_selector: String,
+    /// Selects the element giving the fiction's cover URL, on the fiction page.
+    /// The URL is read from the `content` attribute (for `<meta>` elements) or
+    /// from the `src` attribute (for `<img>` elements).
+    pub cover_selector: String,
+    /// Selects the links (`<a href="…">`) to the chapters, on the fiction page.
+    /// They must be returned in reading order, unless
+    /// [`chapters_newest_first`][Self::chapters_newest_first] is set.
+    pub chapter_link_selector: String,
+    /// Set this if the site lists chapters newest-first, so that the first
+    /// chapter still gets index 1.
+    pub chapters_newest_first: bool,
+    /// Selects the element containing the chapter's title, on a chapter page.
+    pub chapter_title_selector: String,
+    /// Selects the element containing the chapter's content, on a chapter
+    /// page.
+    pub chapter_content_selector: String,
+    /// Selects the link (`<a href="…">`) to the next chapter, on a chapter
+    /// page. Needed to [walk through the
+    /// chapters][Backend::walk_from_first_chapter], in which case the first
+    /// link matched by [`chapter_link_selector`][Self::chapter_link_selector]
+    /// is used as the first chapter.
+    pub next_chapter_link_selector: Option<String>,
+    /// Selects the headers of the volumes (or arcs) under which chapters are
+    /// listed, on the fiction page. Chapter links following a header belong
+    /// to its volume. See [`Backend::get_volumes`].
+    pub volume_title_selector: Option<String>,
+    /// Selects the links to locked chapters (such as paid chapters) among the
+    /// chapter links. See [`ChapterInfo::is_locked`].
+    pub locked_chapter_link_selector: Option<String>,
+    /// When [`chapter_content_selector`][Self::chapter_content_selector]
+    /// matches nothing, find the content with
+    /// [`extract_readable`][crate::extract_readable] instead of
+    /// returning an error. Disabled by default.
+    pub readable_fallback: bool,
+    /// URL of the print-friendly (or AMP, or mobile) version of chapter
+    /// pages, where `{url}` stands for the URL of the chapter, such as
+    /// `{url}?print=1`. Such pages have less clutter to strip, and are used
+    /// with [`BackendOptions::prefer_print_pages`].
+    pub print_url_template: Option<String>,
+    /// Selects the element containing the chapter's content, on the
+    /// print-friendly version of chapter pages. Defaults to
+    /// [`chapter_content_selector`][Self::chapter_content_selector].
+    pub print_content_selector: Option<String>,
+}
+
+/// A [`SiteTemplate`] whose selectors and URL patterns have been validated.
+#[derive(Debug, Clone)]
+struct CompiledTemplate {
+    url_patterns: Vec<Regex>,
+    title: Selector,
+    authors: Selector,
+    cover: Selector,
+    chapter_link: Selector,
+    chapter_title: Selector,
+    chapter_content: Selector,
+    next_chapter_link: Option<Selector>,
+    volume_title: Option<Selector>,
+    locked_chapter_link: Option<Selector>,
+    chapters_newest_first: bool,
+    readable_fallback: bool,
+    print_url_template: Option<String>,
+    print_content: Selector,
+}
+
+impl Default for CompiledTemplate {
+    fn default() -> Self {
+        SiteTemplate {
+            title_selector: "title".to_string(),
+            authors_selector: "meta[name='author']".to_string(),
+            cover_selector: "meta[property='og:image']".to_string(),
+            chapter_link_selector: "a".to_string(),
+            chapter_title_selector: "title".to_string(),
+            chapter_content_selector: "body".to_string(),
+            ..Default::default()
+        }
+        .compile()
+        .unwrap()
+    }
+}
+
+impl SiteTemplate {
+    /// Makes sure all the selectors & URL patterns of this template are valid.
+    fn compile(&self) -> Result<CompiledTemplate, BackendError> {
+        fn selector(name: &str, selector: &str) -> Result<Selector, BackendError> {
+            Selector::parse(selector).map_err(|e| {
+                BackendError::ParseError(
+                    format!("Invalid {name} selector \"{selector}\": {e}"),
+                    None,
+                )
+            })
+        }
+        let url_patterns = self
+            .url_patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| {
+                    BackendError::parse_error_with_source(
+                        format!("Invalid URL pattern \"{pattern}\""),
+                        e,
+                    )
+                })
+            })
+            .collect::<Result<Vec<Regex>, BackendError>>()?;
+        Ok(CompiledTemplate {
+            url_patterns,
+            title: selector("title", &self.title_selector)?,
+            authors: selector("authors", &self.authors_selector)?,
+            cover: selector("cover", &self.cover_selector)?,
+            chapter_link: selector("chapter link", &self.chapter_link_selector)?,
+            chapter_title: selector("chapter title", &self.chapter_title_selector)?,
+            chapter_content: selector("chapter content", &self.chapter_content_selector)?,
+            next_chapter_link: self
+                .next_chapter_link_selector
+                .as_ref()
+                .map(|s| selector("next chapter link", s))
+                .transpose()?,
+            volume_title: self
+                .volume_title_selector
+                .as_ref()
+                .map(|s| selector("volume title", s))
+                .transpose()?,
+            locked_chapter_link: self
+                .locked_chapter_link_selector
+                .as_ref()
+                .map(|s| selector("locked chapter link", s))
+                .transpose()?,
+            chapters_newest_first: self.chapters_newest_first,
+            readable_fallback: self.readable_fallback,
+            print_url_template: self.print_url_template.clone(),
+            print_content: selector(
+                "print content",
+                self.print_content_selector
+                    .as_ref()
+                    .unwrap_or(&self.chapter_content_selector),
+            )?,
+        })
+    }
+}
+
+/// A [`Backend`] configured at runtime by a [`SiteTemplate`], enabling support
+/// for a new site by providing a few CSS selectors instead of writing (and
+/// compiling) a new backend.
+///
+/// Since it needs a template, it can't be built by [`Backend::new`] (nor
+/// [`Backends::new`][crate::Backends::new]): use
+/// [`TemplateBackend::new_with_template`] instead.
+///
+/// ```rust
+/// use libwebnovel::backends::{SiteTemplate, TemplateBackend};
+/// use libwebnovel::Backend;
+/// let template = SiteTemplate {
+///     url_patterns: vec![r"https?://www\.royalroad\.com/fiction/\d+/[\w-]+".to_string()],
+///     title_selector: "div.fic-title h1".to_string(),
+///     authors_selector: "div.fic-title h4 a".to_string(),
+///     cover_selector: "meta[property='og:image']".to_string(),
+///     chapter_link_selector: "table#chapters tr.chapter-row td:first-child a".to_string(),
+///     chapter_title_selector: "div.fic-header h1".to_string(),
+///     chapter_content_selector: "div.chapter-content".to_string(),
+///     ..Default::default()
+/// };
+/// let backend = TemplateBackend::new_with_template(
+///     "https://www.royalroad.com/fiction/21220/mother-of-learning",
+///     template,
+/// )
+/// .unwrap();
+/// assert_eq!(backend.title().unwrap(), "Mother of Learning");
+/// ```
+#[derive(Clone)]
+pub struct TemplateBackend {
+    url: String,
+    page: Html,
+    template: CompiledTemplate,
+    options: BackendOptions,
+    /// Chapter pages parsed so far, since walking through chapters needs each
+    /// page twice: for its content & for the link to the next one.
+    chapter_pages: RefCell<DocumentCache>,
+    client: HttpClient,
+}
+
+impl Default for TemplateBackend {
+    fn default() -> Self {
+        Self {
+            url: "".to_string(),
+            page: Html::new_document(),
+            template: CompiledTemplate::default(),
+            options: BackendOptions::default(),
+            chapter_pages: Default::default(),
+            client: HttpClient::default(),
+        }
+    }
+}
+
+#[allow(unused_variables, dead_code)]
+impl Debug for TemplateBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        #[derive(Debug)]
+        struct TemplateBackend<'a> {
+            url: &'a String,
+        }
+        let Self {
+            url,
+            page: _,
+            template: _,
+            options: _,
+            chapter_pages: _,
+            client: _,
+        } = self;
+        Debug::fmt(&TemplateBackend { url }, f)
+    }
+}
+
+impl TemplateBackend {
+    /// Creates a new backend for the given URL, scraped according to the given
+    /// template. Returns a [`BackendError::ParseError`] if the template is
+    /// invalid, and [`BackendError::NoMatchingBackendFound`] if the URL doesn't
+    /// match any of its URL patterns.
+    pub fn new_with_template(url: &str, template: SiteTemplate) -> Result<Self, BackendError> {
+        Self::new_with_template_and_config(url, template, &ClientConfig::default())
+    }
+
+    /// Like [`TemplateBackend::new_with_template`], sending every request with
+    /// a client configured by `config`.
+    pub fn new_with_template_and_config(
+        url: &str,
+        template: SiteTemplate,
+        config: &ClientConfig,
+    ) -> Result<Self, BackendError> {
+        let template = template.compile()?;
+        let client = config.build()?;
+        if !template
+            .url_patterns
+            .iter()
+            .any(|regex| regex.is_match(url))
+        {
+            return Err(BackendError::NoMatchingBackendFound(url.to_string()));
+        }
+        let resp = client.get(url)?;
+        if !resp.status().is_success() {
+            return Err(BackendError::RequestFailed {
+                message: format!("Could not fetch url {url}"),
+                status: resp.status(),
+                content: resp.text()?,
+            });
+        }
+        Ok(Self {
+            url: url.to_string(),
+            page: Html::parse_document(&resp.text()?),
+            template,
+            options: BackendOptions::default(),
+            chapter_pages: Default::default(),
+            client,
+        })
+    }
+
+    /// Returns the fiction URL, parsed.
+    fn parsed_url(&self) -> Result<Url, BackendError> {
+        Url::parse(&self.url).map_err(|e| {
+            BackendError::parse_error_with_source(format!("Invalid URL {}", self.url), e)
+        })
+    }
+
+    /// Returns the absolute URLs (and titles) of the chapters listed on the
+    /// fiction page.
+    fn chapter_links(&self) -> Result<Vec<(String, String)>, BackendError> {
+        let base = self.parsed_url()?;
+        let mut links = self
+            .page
+            .select(&self.template.chapter_link)
+            .filter_map(|a| chapter_link(&base, &a))
+            .collect::<Vec<(String, String)>>();
+        if self.template.chapters_newest_first {
+            links.reverse();
+        }
+        let urls: Vec<&String> = links.iter().map(|(url, _)| url).collect();
+        ensure_unique_chapter_urls(&urls)?;
+        Ok(links)
+    }
+
+    /// Fetches & parses the page of the chapter at the given URL, unless it has
+    /// been recently, and passes it to `f`.
+    fn with_chapter_page<T>(
+        &self,
+        chapter_url: &str,
+        f: impl FnOnce(&Html) -> Result<T, BackendError>,
+    ) -> Result<T, BackendError> {
+        let mut chapter_pages = self.chapter_pages.borrow_mut();
+        let page = chapter_pages.get_or_parse(chapter_url, || {
+            let resp = self.client.get(chapter_url)?;
+            if !resp.status().is_success() {
+                return Err(BackendError::RequestFailed {
+                    message: format!("Could not get chapter at URL {chapter_url}"),
+                    status: resp.status(),
+                    content: resp.text()?,
+                });
+            }
+            check_not_redirected_home(&resp, chapter_url, &self.url)?;
+            Ok(resp.text()?)
+        })?;
+        f(page)
+    }
+
+    /// Returns the URL of the print-friendly version of the chapter at
+    /// `chapter_url`, if the site has one and
+    /// [`BackendOptions::prefer_print_pages`] is set.
+    fn print_url(&self, chapter_url: &str) -> Option<String> {
+        if !self.options.prefer_print_pages {
+            return None;
+        }
+        self.template
+            .print_url_template
+            .as_ref()
+            .map(|template| template.replace("{url}", chapter_url))
+    }
+
+    /// Returns the absolute URL of the chapter following the one at
+    /// `chapter_url`, if its page links to one.
+    fn next_chapter_url(
+        &self,
+        chapter_url: &str,
+        page: &Html,
+    ) -> Result<Option<String>, BackendError> {
+        let Some(selector) = &self.template.next_chapter_link else {
+            return Ok(None);
+        };
+        let base = Url::parse(chapter_url).map_err(|e| {
+            BackendError::parse_error_with_source(format!("Invalid URL {chapter_url}"), e)
+        })?;
+        Ok(page
+            .select(selector)
+            .next()
+            .and_then(|a| a.attr("href"))
+            .and_then(|href| base.join(href).ok())
+            .map(|url| url.to_string()))
+    }
+
+    /// Builds a [`Chapter`] from the HTML of its page.
+    fn parse_chapter(
+        &self,
+        chapter_number: usize,
+        chapter_url: String,
+        fallback_title: String,
+        page: &Html,
+    ) -> Result<Chapter, BackendError> {
+        self.parse_chapter_with(
+            &self.template.chapter_content,
+            chapter_number,
+            chapter_url,
+            fallback_title,
+            page,
+        )
+    }
+
+    /// Builds a [`Chapter`] from the HTML of a page, finding its content with
+    /// the given selector.
+    fn parse_chapter_with(
+        &self,
+        content_selector: &Selector,
+        chapter_number: usize,
+        chapter_url: String,
+        fallback_title: String,
+        page: &Html,
+    ) -> Result<Chapter, BackendError> {
+        let title = page
+            .select(&self.template.chapter_title)
+            .next()
+            .map(|title| text_of(&title))
+            .filter(|title| !title.is_empty())
+            .or((!self.options.strict).then_some(fallback_title));
+        let content = match page.select(content_selector).next() {
+            Some(content) => content.inner_html(),
+            None if self.template.readable_fallback => {
+                debug!("No content matched for chapter {chapter_url}, extracting it");
+                readable_content(page)
+            }
+            None => String::new(),
+        };
+        if content.is_empty() {
+            return Err(BackendError::ParseError(
+                format!("Could not find the content of chapter {chapter_url}"),
+                None,
+            ));
+        }
+        let mut chapter = Chapter::default();
+        chapter.set_index(chapter_number);
+        chapter.set_title(title);
+        chapter.set_chapter_url(chapter_url);
+        chapter.set_fiction_url(self.url.clone());
+        chapter.set_raw_content(self.options.raw_content(&content));
+        chapter.set_content(content);
+        chapter.add_metadata(BACKEND_NAME_METADATA_KEY, Self::get_backend_name());
+        self.options.clean_chapter(&mut chapter);
+        self.options
+            .check_chapter(&chapter, Self::capabilities().has_dates)?;
+        Ok(chapter)
+    }
+}
+
+/// Returns the decoded & trimmed text of an element.
+fn text_of(element: &ElementRef) -> String {
+    decode_html_entities(element.text().collect::<String>().trim()).to_string()
+}
+
+/// Returns the absolute URL & the title of the chapter the given link points
+/// to.
+fn chapter_link(base: &Url, a: &ElementRef) -> Option<(String, String)> {
+    let href = a.attr("href")?;
+    let url = base.join(href).ok()?.to_string();
+    Some((url, text_of(a)))
+}
+
+impl Backend for TemplateBackend {
+    /// A template backend can't know its URL patterns before being given a
+    /// template, so this returns an empty [`Vec`].
+    fn get_backend_regexps() -> Vec<Regex> {
+        Vec::new()
+    }
+
+    fn get_backend_name() -> &'static str {
+        "template"
+    }
+
+    fn capabilities() -> BackendCapabilities {
+        BackendCapabilities {
+            has_cover: true,
+            ..Default::default()
+        }
+    }
+
+    /// Always fails with [`BackendError::NoMatchingBackendFound`], since there
+    /// is no template to use. See [`TemplateBackend::new_with_template`].
+    fn new(url: &str) -> Result<Self, BackendError> {
+        Err(BackendError::NoMatchingBackendFound(url.to_string()))
+    }
+
+    /// Always fails like [`TemplateBackend::new`]. See
+    /// [`TemplateBackend::new_with_template_and_config`].
+    #[allow(unused_variables)]
+    fn new_with_config(url: &str, config: &ClientConfig) -> Result<Self, BackendError> {
+        Self::new(url)
+    }
+
+    fn http_client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    fn backend_options(&self) -> &BackendOptions {
+        &self.options
+    }
+
+    fn set_backend_options(&mut self, options: BackendOptions) {
+        self.options = options;
+    }
+
+    fn title(&self) -> Result<String, BackendError> {
+        let title = self
+            .page
+            .select(&self.template.title)
+            .next()
+            .map(|title| text_of(&title));
+        debug!("title: {:?}", title);
+        title.ok_or(BackendError::ParseError(
+            format!("Could not find the title of {}", self.url),
+            None,
+        ))
+    }
+
+    fn immutable_identifier(&self) -> Result<String, BackendError> {
+        let url = self.parsed_url()?;
+        Ok(format!(
+            "{}-{}",
+            url.host_str().unwrap_or_default(),
+            slugify(url.path())
+        ))
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn declared_language(&self) -> Option<String> {
+        declared_language(&self.page)
+    }
+
+    fn cover_url(&self) -> Result<String, BackendError> {
+        let cover = self
+            .page
+            .select(&self.template.cover)
+            .next()
+            .and_then(|cover| cover.attr("content").or(cover.attr("src")))
+            .ok_or(BackendError::ParseError(
+                format!("Could not find the cover of {}", self.url),
+                None,
+            ))?;
+        self.parsed_url()?
+            .join(cover)
+            .map(|url| url.to_string())
+            .map_err(|e| {
+                BackendError::parse_error_with_source(format!("Invalid cover URL {cover}"), e)
+            })
+    }
+
+    fn get_authors(&self) -> Result<Vec<String>, BackendError> {
+        Ok(self
+            .page
+            .select(&self.template.authors)
+            .map(|author| {
+                author
+                    .attr("content")
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| text_of(&author))
+            })
+            .filter(|author| !author.is_empty())
+            .collect())
+    }
+
+    fn get_chapter_list(&self) -> Result<Vec<ChapterListElem>, BackendError> {
+        Ok(self
+            .chapter_links()?
+            .into_iter()
+            .enumerate()
+            .map(|(index, (_, title))| (index + 1, chapter_title_or_untitled(&title, index + 1)))
+            .collect())
+    }
+
+    /// Returns the title, URL & lock status of a chapter, as listed on the
+    /// fiction page.
+    fn get_chapter_info(&self, chapter_number: usize) -> Result<ChapterInfo, BackendError> {
+        self.get_volumes()?
+            .into_iter()
+            .flat_map(|volume| volume.chapters().clone())
+            .find(|info| *info.index() == chapter_number)
+            .ok_or(BackendError::UnknownChapter(chapter_number))
+    }
+
+    fn get_chapter_infos(&self) -> Result<Vec<ChapterInfo>, BackendError> {
+        Ok(self
+            .get_volumes()?
+            .into_iter()
+            .flat_map(|volume| volume.chapters().clone())
+            .collect())
+    }
+
+    /// Groups the chapters under the volume headers selected by
+    /// [`SiteTemplate::volume_title_selector`], if any. Chapters listed before
+    /// the first header go to an unnamed volume.
+    fn get_volumes(&self) -> Result<Vec<Volume>, BackendError> {
+        self.chapter_links()?;
+        let base = self.parsed_url()?;
+        let mut volumes = vec![(None, Vec::new())];
+        for element in self
+            .page
+            .root_element()
+            .descendants()
+            .filter_map(ElementRef::wrap)
+        {
+            let matches = |selector: &Option<Selector>| {
+                selector
+                    .as_ref()
+                    .is_some_and(|selector| selector.matches(&element))
+            };
+            if matches(&self.template.volume_title) {
+                volumes.push((Some(text_of(&element)), Vec::new()));
+            } else if self.template.chapter_link.matches(&element) {
+                if let Some((url, title)) = chapter_link(&base, &element) {
+                    let is_locked = matches(&self.template.locked_chapter_link);
+                    volumes.last_mut().unwrap().1.push((url, title, is_locked));
+                }
+            }
+        }
+        if volumes.len() > 1 && volumes[0].1.is_empty() {
+            volumes.remove(0);
+        }
+        if self.template.chapters_newest_first {
+            volumes.reverse();
+            for (_, links) in &mut volumes {
+                links.reverse();
+            }
+        }
+        let mut index = 0;
+        Ok(volumes
+            .into_iter()
+            .map(|(title, links)| {
+                let mut volume = Volume::default();
+                volume.set_title(title);
+                volume.set_chapters(
+                    links
+                        .into_iter()
+                        .map(|(url, title, is_locked)| {
+                            index += 1;
+                            let mut info = ChapterInfo::default();
+                            info.set_index(index);
+                            info.set_title(Some(chapter_title_or_untitled(&title, index)));
+                            info.set_chapter_url(Some(url));
+                            info.set_is_locked(is_locked);
+                            info
+                        })
+                        .collect(),
+                );
+                volume
+            })
+            .collect())
+    }
+
+    fn get_chapter(&self, chapter_number: usize) -> Result<Chapter, BackendError> {
+        if chapter_number == 0 {
+            return Err(BackendError::UnknownChapter(chapter_number));
+        }
+        let (chapter_url, title) = self
+            .chapter_links()?
+            .into_iter()
+            .nth(chapter_number - 1)
+            .ok_or(BackendError::UnknownChapter(chapter_number))?;
+        let title = chapter_title_or_untitled(&title, chapter_number);
+        if let Some(print_url) = self.print_url(&chapter_url) {
+            match self.with_chapter_page(&print_url, |page| {
+                self.parse_chapter_with(
+                    &self.template.print_content,
+                    chapter_number,
+                    chapter_url.clone(),
+                    title.clone(),
+                    page,
+                )
+            }) {
+                Ok(chapter) => return Ok(chapter),
+                Err(e) => debug!(
+                    "Could not use the print version of {chapter_url} ({e}), using the standard page"
+                ),
+            }
+        }
+        self.with_chapter_page(&chapter_url.clone(), |page| {
+            self.parse_chapter(chapter_number, chapter_url, title, page)
+        })
+    }
+
+    fn first_chapter_url(&self) -> Result<String, BackendError> {
+        if self.template.next_chapter_link.is_none() {
+            return Err(BackendError::Unsupported(
+                "walking through chapters without a next chapter link selector".to_string(),
+            ));
+        }
+        self.chapter_links()?
+            .into_iter()
+            .next()
+            .map(|(url, _)| url)
+            .ok_or(BackendError::ParseError(
+                format!("Could not find the first chapter of {}", self.url),
+                None,
+            ))
+    }
+
+    fn get_linked_chapter(
+        &self,
+        chapter_number: usize,
+        chapter_url: &str,
+    ) -> Result<(Chapter, Option<String>), BackendError> {
+        self.with_chapter_page(chapter_url, |page| {
+            let next_url = self.next_chapter_url(chapter_url, page)?;
+            let chapter = self.parse_chapter(
+                chapter_number,
+                chapter_url.to_string(),
+                chapter_title_or_untitled("", chapter_number),
+                page,
+            )?;
+            Ok((chapter, next_url))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use indoc::indoc;
+    use scraper::Html;
+
+    use crate::backends::template::{SiteTemplate, TemplateBackend};
+    use crate::backends::{
+        BackendError, BackendOptions, ChapterListElem, BACKEND_NAME_METADATA_KEY,
+    };
+    use crate::test_support::MockServer;
+    use crate::{Backend, ClientConfig};
+
+    const TEST_URL: &str = "https://example.com/fiction/my-fiction";
+
+    fn template() -> SiteTemplate {
+        SiteTemplate {
+            url_patterns: vec![r"https?://example\.com/fiction/[\w-]+".to_string()],
+            title_selector: "h1.title".to_string(),
+            authors_selector: "span.author".to_string(),
+            cover_selector: "img.cover".to_string(),
+            chapter_link_selector: "ul.chapters li a".to_string(),
+            chapters_newest_first: false,
+            chapter_title_selector: "h2.chapter-title".to_string(),
+            chapter_content_selector: "div.chapter-content".to_string(),
+            next_chapter_link_selector: Some("a.next".to_string()),
+            volume_title_selector: Some("ul.chapters li.volume".to_string()),
+            locked_chapter_link_selector: Some("ul.chapters li.locked a".to_string()),
+            readable_fallback: false,
+            print_url_template: None,
+            print_content_selector: None,
+        }
+    }
+
+    fn fixture_backend() -> TemplateBackend {
+        TemplateBackend {
+            url: TEST_URL.to_string(),
+            page: Html::parse_document(indoc! {r#"
+                <h1 class="title">My Fiction</h1>
+                <span class="author">Some One</span>
+                <img class="cover" src="/covers/my-fiction.jpg">
+                <ul class="chapters">
+                  <li><a href="/fiction/my-fiction/1">Chapter 1</a></li>
+                  <li><a href="https://example.com/fiction/my-fiction/2">Chapter 2</a></li>
+                </ul>
+            "#}),
+            template: template().compile().unwrap(),
+            options: Default::default(),
+            chapter_pages: Default::default(),
+            client: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_template() {
+        let mut template = template();
+        template.chapter_content_selector = "div..content".to_string();
+        match TemplateBackend::new_with_template(TEST_URL, template) {
+            Err(BackendError::ParseError(msg, _)) => assert!(msg.contains("chapter content")),
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+        let mut template = self::template();
+        template.url_patterns.push("(unclosed".to_string());
+        match TemplateBackend::new_with_template(TEST_URL, template) {
+            Err(e @ BackendError::ParseError(_, Some(_))) => {
+                assert!(e.source().unwrap().is::<regex::Error>())
+            }
+            other => panic!("expected a ParseError with a source, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_url_not_matching_template() {
+        assert!(matches!(
+            TemplateBackend::new_with_template("https://example.org/fiction/x", template()),
+            Err(BackendError::NoMatchingBackendFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_fiction_page() {
+        let backend = fixture_backend();
+        assert_eq!(backend.title().unwrap(), "My Fiction");
+        assert_eq!(backend.get_authors().unwrap(), vec!["Some One"]);
+        assert_eq!(
+            backend.cover_url().unwrap(),
+            "https://example.com/covers/my-fiction.jpg"
+        );
+        assert_eq!(
+            backend.get_chapter_list().unwrap(),
+            vec![(1, "Chapter 1".to_string()), (2, "Chapter 2".to_string())]
+        );
+        assert_eq!(
+            backend.immutable_identifier().unwrap(),
+            "example.com-fiction-my-fiction"
+        );
+    }
+
+    #[test]
+    fn test_get_volumes() {
+        let mut backend = fixture_backend();
+        let volumes = backend.get_volumes().unwrap();
+        assert_eq!(volumes.len(), 1);
+        assert_eq!(volumes[0].title(), &None);
+        assert_eq!(volumes[0].chapters().len(), 2);
+
+        backend.page = Html::parse_document(indoc! {r#"
+            <ul class="chapters">
+              <li class="volume">Volume 1: The Beginning</li>
+              <li><a href="/fiction/my-fiction/1">Chapter 1</a></li>
+              <li><a href="/fiction/my-fiction/2">Chapter 2</a></li>
+              <li class="volume">Volume 2</li>
+              <li><a href="/fiction/my-fiction/3">Chapter 3</a></li>
+            </ul>
+        "#});
+        let volumes = backend.get_volumes().unwrap();
+        let summary: Vec<_> = volumes
+            .iter()
+            .map(|volume| {
+                (
+                    volume.title().as_deref(),
+                    volume
+                        .chapters()
+                        .iter()
+                        .map(|info| (*info.index(), info.chapter_url().as_deref().unwrap()))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    Some("Volume 1: The Beginning"),
+                    vec![
+                        (1, "https://example.com/fiction/my-fiction/1"),
+                        (2, "https://example.com/fiction/my-fiction/2")
+                    ]
+                ),
+                (
+                    Some("Volume 2"),
+                    vec![(3, "https://example.com/fiction/my-fiction/3")]
+                ),
+            ]
+        );
+        assert_eq!(
+            volumes[1].chapters()[0].title(),
+            &Some("Chapter 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_locked_chapters() {
+        let mut backend = fixture_backend();
+        assert!(!backend.get_chapter_info(2).unwrap().is_locked());
+
+        backend.page = Html::parse_document(indoc! {r#"
+            <ul class="chapters">
+              <li><a href="/fiction/my-fiction/1">Chapter 1</a></li>
+              <li><a href="/fiction/my-fiction/2">Chapter 2</a></li>
+              <li class="locked"><a href="/fiction/my-fiction/3">&#x1F512; Chapter 3</a></li>
+              <li class="locked"><a href="/fiction/my-fiction/4">&#x1F512; Chapter 4</a></li>
+            </ul>
+        "#});
+        let locked: Vec<bool> = (1..=4)
+            .map(|i| backend.get_chapter_info(i).unwrap().is_locked())
+            .collect();
+        assert_eq!(locked, vec![false, false, true, true]);
+        let first_locked = backend.get_chapter_info(3).unwrap();
+        assert_eq!(
+            first_locked.chapter_url(),
+            &Some("https://example.com/fiction/my-fiction/3".to_string())
+        );
+        assert!(matches!(
+            backend.get_chapter_info(5),
+            Err(BackendError::UnknownChapter(5))
+        ));
+    }
+
+    #[test]
+    fn test_next_chapter_url() {
+        let backend = fixture_backend();
+        let chapter_url = "https://example.com/fiction/my-fiction/1";
+        assert_eq!(
+            backend
+                .next_chapter_url(
+                    chapter_url,
+                    &Html::parse_document(
+                        r#"<a class="prev" href="0">Previous</a><a class="next" href="2">Next</a>"#
+                    )
+                )
+                .unwrap(),
+            Some("https://example.com/fiction/my-fiction/2".to_string())
+        );
+        assert_eq!(
+            backend
+                .next_chapter_url(
+                    chapter_url,
+                    &Html::parse_document(r#"<a class="prev" href="0">Previous</a>"#)
+                )
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            backend.first_chapter_url().unwrap(),
+            "https://example.com/fiction/my-fiction/1"
+        );
+    }
+
+    #[test]
+    fn test_newest_first_chapter_list() {
+        let mut template = template();
+        template.chapters_newest_first = true;
+        let backend = TemplateBackend {
+            template: template.compile().unwrap(),
+            ..fixture_backend()
+        };
+        assert_eq!(
+            backend.get_chapter_list().unwrap(),
+            vec![(1, "Chapter 2".to_string()), (2, "Chapter 1".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_declared_language() {
+        let backend = TemplateBackend {
+            page: Html::parse_document(
+                r#"<html lang="zh"><body><h1 class="title">我的小说</h1></body></html>"#,
+            ),
+            ..fixture_backend()
+        };
+        assert_eq!(backend.language().unwrap(), "zh");
+    }
+
+    #[test]
+    fn test_untitled_chapters() {
+        let backend = TemplateBackend {
+            page: Html::parse_document(indoc! {r#"
+                <h1 class="title">My Fiction</h1>
+                <ul class="chapters">
+                  <li><a href="/fiction/my-fiction/1"> </a></li>
+                  <li><a href="/fiction/my-fiction/2">2</a></li>
+                </ul>
+            "#}),
+            ..fixture_backend()
+        };
+        assert_eq!(
+            backend.get_chapter_list().unwrap(),
+            vec![(1, "Chapter 1".to_string()), (2, "2".to_string())]
+        );
+        let chapter = backend
+            .parse_chapter(
+                1,
+                "https://example.com/fiction/my-fiction/1".to_string(),
+                backend
+                    .get_chapter_info(1)
+                    .unwrap()
+                    .title()
+                    .clone()
+                    .unwrap(),
+                &Html::parse_document(r#"<div class="chapter-content"><p>むかしむかし</p></div>"#),
+            )
+            .unwrap();
+        assert_eq!(chapter.title(), &Some("Chapter 1".to_string()));
+        let mut untitled = chapter.clone();
+        untitled.set_title(None);
+        assert_eq!(
+            ChapterListElem::try_from(&untitled).unwrap(),
+            (1, "Chapter 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_chapter_pages_parsed_once() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let server_hits = Arc::clone(&hits);
+        let server = MockServer::start_with_handler(move |_, _| {
+            server_hits.fetch_add(1, Ordering::Relaxed);
+            (
+                200,
+                r#"<h2 class="chapter-title">1</h2><div class="chapter-content"><p>Text</p></div>"#
+                    .to_string(),
+            )
+        });
+        let backend = TemplateBackend {
+            page: Html::parse_document(&format!(
+                r#"<ul class="chapters"><li><a href="{}">1</a></li></ul>"#,
+                server.url("/1")
+            )),
+            ..fixture_backend()
+        };
+        let chapter = backend.get_chapter(1).unwrap();
+        assert_eq!(backend.get_chapter(1).unwrap(), chapter);
+        assert_eq!(hits.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_get_chapters_concurrent() {
+        let fiction_page_hits = Arc::new(AtomicUsize::new(0));
+        let server = MockServer::start_with_handler({
+            let fiction_page_hits = Arc::clone(&fiction_page_hits);
+            move |path, headers| {
+                if headers.get("x-token").map(String::as_str) != Some("secret") {
+                    return (403, "forbidden".to_string());
+                }
+                match path.strip_prefix("/fiction/my-fiction") {
+                    Some("") => {
+                        fiction_page_hits.fetch_add(1, Ordering::Relaxed);
+                        let links: String = (1..=6)
+                            .map(|i| {
+                                format!(r#"<li><a href="/fiction/my-fiction/{i}">{i}</a></li>"#)
+                            })
+                            .collect();
+                        (
+                            200,
+                            format!(
+                                r#"<h1 class="title">My Fiction</h1><ul class="chapters">{links}</ul>"#
+                            ),
+                        )
+                    }
+                    Some(chapter) => (
+                        200,
+                        format!(
+                            r#"<h2 class="chapter-title">{0}</h2><div class="chapter-content"><p>Chapter{0}</p></div>"#,
+                            &chapter[1..]
+                        ),
+                    ),
+                    None => (404, "not found".to_string()),
+                }
+            }
+        });
+        let template = SiteTemplate {
+            url_patterns: vec![r"http://127\.0\.0\.1:\d+/fiction/[\w-]+".to_string()],
+            ..template()
+        };
+        let config = ClientConfig {
+            headers: vec![("X-Token".to_string(), "secret".to_string())],
+            ..Default::default()
+        };
+        let backend = TemplateBackend::new_with_template_and_config(
+            &server.url("/fiction/my-fiction"),
+            template,
+            &config,
+        )
+        .unwrap();
+        let indices: Vec<usize> = (1..=6).collect();
+        let contents: Vec<String> = backend
+            .get_chapters_concurrent(&indices)
+            .into_iter()
+            .map(|result| result.unwrap().content().clone())
+            .collect();
+        assert_eq!(
+            contents,
+            indices
+                .iter()
+                .map(|i| format!("<p>Chapter{i}</p>"))
+                .collect::<Vec<_>>()
+        );
+        // the workers don't fetch the fiction page again
+        assert_eq!(fiction_page_hits.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_print_pages() {
+        let server = MockServer::start(&[
+            (
+                "/1",
+                200,
+                r#"<h2 class="chapter-title">1</h2><div class="chapter-content">
+                <p>Text</p><div class="ad">Buy now!</div></div>"#,
+            ),
+            (
+                "/1?print=1",
+                200,
+                r#"<h2 class="chapter-title">1</h2><main><p>Text</p></main>"#,
+            ),
+            (
+                "/2",
+                200,
+                r#"<h2 class="chapter-title">2</h2><div class="chapter-content"><p>More text</p></div>"#,
+            ),
+        ]);
+        let mut backend = TemplateBackend {
+            page: Html::parse_document(&format!(
+                r#"<ul class="chapters"><li><a href="{}">1</a></li><li><a href="{}">2</a></li></ul>"#,
+                server.url("/1"),
+                server.url("/2")
+            )),
+            template: SiteTemplate {
+                print_url_template: Some("{url}?print=1".to_string()),
+                print_content_selector: Some("main".to_string()),
+                ..template()
+            }
+            .compile()
+            .unwrap(),
+            ..fixture_backend()
+        };
+        // print pages are only used when asked for
+        assert!(backend
+            .get_chapter(1)
+            .unwrap()
+            .content()
+            .contains("Buy now!"));
+
+        backend.set_backend_options(BackendOptions {
+            prefer_print_pages: true,
+            ..Default::default()
+        });
+        let chapter = backend.get_chapter(1).unwrap();
+        assert_eq!(chapter.content(), "<p>Text</p>");
+        assert_eq!(chapter.chapter_url(), &server.url("/1"));
+        // no print version of the second chapter
+        let chapter = backend.get_chapter(2).unwrap();
+        assert_eq!(chapter.content(), "<p>More text</p>");
+    }
+
+    #[test]
+    fn test_parse_chapter() {
+        let backend = fixture_backend();
+        let chapter = backend
+            .parse_chapter(
+                1,
+                "https://example.com/fiction/my-fiction/1".to_string(),
+                "Chapter 1".to_string(),
+                &Html::parse_document(
+                    r#"<h2 class="chapter-title">1. The Beginning</h2><div class="chapter-content"><p>Once upon a time</p></div>"#,
+                ),
+            )
+            .unwrap();
+        assert_eq!(chapter.title(), &Some("1. The Beginning".to_string()));
+        assert_eq!(chapter.content(), "<p>Once upon a time</p>");
+        assert_eq!(chapter.fiction_url(), TEST_URL);
+        assert_eq!(
+            chapter.metadata().get(BACKEND_NAME_METADATA_KEY),
+            Some(&"template".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_chapter_readable_fallback() {
+        let page = &Html::parse_document(
+            r#"<h2 class="chapter-title">1. The Beginning</h2>
+            <ul class="menu"><li><a href="/">Home</a></li></ul>
+            <article><p>Once upon a time, in a land far away, there lived a king.</p></article>"#,
+        );
+        let mut backend = fixture_backend();
+        let url = "https://example.com/fiction/my-fiction/1".to_string();
+        assert!(matches!(
+            backend.parse_chapter(1, url.clone(), "Chapter 1".to_string(), page),
+            Err(BackendError::ParseError(..))
+        ));
+        backend.template = SiteTemplate {
+            readable_fallback: true,
+            ..template()
+        }
+        .compile()
+        .unwrap();
+        let chapter = backend
+            .parse_chapter(1, url, "Chapter 1".to_string(), page)
+            .unwrap();
+        assert_eq!(
+            chapter.content(),
+            "<p>Once upon a time, in a land far away, there lived a king.</p>"
+        );
+    }
+}
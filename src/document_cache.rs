@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+
+use scraper::Html;
+
+use crate::backends::BackendError;
+
+/// Number of parsed documents kept by a default [`DocumentCache`].
+pub(crate) const DOCUMENT_CACHE_CAPACITY: usize = 8;
+
+/// A bounded cache of parsed HTML documents, keyed by URL, so that a backend
+/// accessing the same page several times (such as when walking through
+/// chapters) only parses it once. The least recently used document is evicted
+/// when the cache is full.
+#[derive(Debug, Clone)]
+pub(crate) struct DocumentCache {
+    capacity: usize,
+    documents: VecDeque<(String, Html)>,
+}
+
+impl Default for DocumentCache {
+    fn default() -> Self {
+        Self::new(DOCUMENT_CACHE_CAPACITY)
+    }
+}
+
+impl DocumentCache {
+    /// Creates a cache keeping at most `capacity` documents (at least the
+    /// last one).
+    pub(crate) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            documents: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the document at `url`, calling `fetch` to get its HTML & parsing
+    /// it only if it is not cached yet.
+    pub(crate) fn get_or_parse(
+        &mut self,
+        url: &str,
+        fetch: impl FnOnce() -> Result<String, BackendError>,
+    ) -> Result<&Html, BackendError> {
+        match self.documents.iter().position(|(key, _)| key == url) {
+            Some(i) => {
+                let entry = self.documents.remove(i).unwrap();
+                self.documents.push_back(entry);
+            }
+            None => {
+                let document = Html::parse_document(&fetch()?);
+                if self.documents.len() >= self.capacity {
+                    self.documents.pop_front();
+                }
+                self.documents.push_back((url.to_string(), document));
+            }
+        }
+        Ok(&self.documents.back().unwrap().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backends::BackendError;
+    use crate::document_cache::DocumentCache;
+
+    #[test]
+    fn test_document_cache() {
+        let mut cache = DocumentCache::new(2);
+        let mut fetches = 0;
+        let mut fetch = |url: &str| {
+            cache
+                .get_or_parse(url, || {
+                    fetches += 1;
+                    Ok(format!("<p>{url}</p>"))
+                })
+                .unwrap()
+                .html()
+        };
+        let first = fetch("https://example.com/1");
+        let again = fetch("https://example.com/1");
+        assert_eq!(first, again);
+        fetch("https://example.com/2");
+        // 1 was used more recently than 2, so 2 gets evicted
+        fetch("https://example.com/1");
+        fetch("https://example.com/3");
+        fetch("https://example.com/1");
+        fetch("https://example.com/2");
+        assert_eq!(fetches, 4);
+
+        let mut cache = DocumentCache::new(1);
+        let error = cache.get_or_parse("https://example.com/1", || {
+            Err(BackendError::ParseError("nope".to_string(), None))
+        });
+        assert!(error.is_err());
+        assert!(cache.documents.is_empty());
+    }
+}
@@ -0,0 +1,732 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Seek, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use html_escape::{encode_double_quoted_attribute, encode_text};
+use scraper::{ElementRef, Html, Node};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::backends::{chapter_title_or_untitled, BackendError, DEFAULT_LANGUAGE};
+use crate::{slugify, Chapter, ChapterInfo, FictionMetadata, Volume};
+
+/// Name of the EPUB3 navigation document built by [`nav_document`].
+pub const NAV_FILE_NAME: &str = "nav.xhtml";
+
+/// Directory of the epub files written by [`write_epub`] holding the package
+/// document & its content.
+const CONTENT_DIR: &str = "EPUB";
+
+/// Elements without content, written as self-closing tags in XHTML.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// How [`write_epubs`] splits a fiction into epub files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EpubSplit {
+    /// A single epub with all the volumes.
+    #[default]
+    Single,
+    /// One epub per volume, named after the volume's title. They all share the
+    /// cover & metadata of the fiction.
+    PerVolume,
+}
+
+/// Builds the EPUB3 navigation document ([`NAV_FILE_NAME`]) of a fiction: a
+/// table of contents listing the chapters of each volume (see
+/// [`Backend::get_volumes`][crate::Backend::get_volumes]), and the landmarks
+/// (cover, table of contents & start of the text) used by readers to jump
+/// around.
+///
+/// Volumes are nested in the table of contents, unless there is a single
+/// untitled one, in which case chapters are listed directly. `chapter_href`
+/// gives the location of each chapter within the epub, and `cover_href` the
+/// location of the cover page, if any. Without any chapter, the table of
+/// contents only links to the cover page (or to itself), as it cannot be
+/// empty.
+///
+/// ```rust
+/// use libwebnovel::epub::nav_document;
+/// let nav = nav_document("Mother of Learning", "en", &[], |_| String::new(), None);
+/// assert!(nav.contains(r#"<nav epub:type="toc" id="toc">"#));
+/// ```
+pub fn nav_document(
+    title: &str,
+    language: &str,
+    volumes: &[Volume],
+    chapter_href: impl Fn(&ChapterInfo) -> String,
+    cover_href: Option<&str>,
+) -> String {
+    let chapter_item = |info: &ChapterInfo| {
+        let label = chapter_title_or_untitled(info.title().as_deref().unwrap_or(""), *info.index());
+        format!(
+            r#"<li><a href="{}">{}</a></li>"#,
+            encode_double_quoted_attribute(&chapter_href(info)),
+            encode_text(&label)
+        )
+    };
+    let chapter_list = |chapters: &[ChapterInfo]| {
+        let items: Vec<String> = chapters.iter().map(chapter_item).collect();
+        format!("<ol>\n{}\n</ol>", items.join("\n"))
+    };
+    // an empty list is not allowed, nor is a volume without chapters
+    let volumes: Vec<&Volume> = volumes
+        .iter()
+        .filter(|volume| !volume.chapters().is_empty())
+        .collect();
+    let toc = match volumes.as_slice() {
+        [] => format!(
+            "<ol>\n<li><a href=\"{}\">{}</a></li>\n</ol>",
+            encode_double_quoted_attribute(cover_href.unwrap_or("#toc")),
+            encode_text(title)
+        ),
+        [volume] if volume.title().is_none() => chapter_list(volume.chapters()),
+        volumes => {
+            let items: Vec<String> = volumes
+                .iter()
+                .enumerate()
+                .map(|(i, volume)| {
+                    let label = match volume.title() {
+                        Some(title) => title.clone(),
+                        None => format!("Volume {}", i + 1),
+                    };
+                    format!(
+                        "<li><span>{}</span>\n{}\n</li>",
+                        encode_text(&label),
+                        chapter_list(volume.chapters())
+                    )
+                })
+                .collect();
+            format!("<ol>\n{}\n</ol>", items.join("\n"))
+        }
+    };
+
+    let mut landmarks = Vec::new();
+    if let Some(cover_href) = cover_href {
+        landmarks.push(format!(
+            r#"<li><a epub:type="cover" href="{}">Cover</a></li>"#,
+            encode_double_quoted_attribute(cover_href)
+        ));
+    }
+    landmarks
+        .push(r##"<li><a epub:type="toc" href="#toc">Table of Contents</a></li>"##.to_string());
+    if let Some(first_chapter) = volumes.first().and_then(|volume| volume.chapters().first()) {
+        landmarks.push(format!(
+            r#"<li><a epub:type="bodymatter" href="{}">Start of Content</a></li>"#,
+            encode_double_quoted_attribute(&chapter_href(first_chapter))
+        ));
+    }
+
+    let title = encode_text(title);
+    let language = encode_double_quoted_attribute(language);
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" lang="{language}" xml:lang="{language}">
+<head>
+<title>{title}</title>
+</head>
+<body>
+<nav epub:type="toc" id="toc">
+<h1>{title}</h1>
+{toc}
+</nav>
+<nav epub:type="landmarks" id="landmarks" hidden="hidden">
+<h2>Landmarks</h2>
+<ol>
+{landmarks}
+</ol>
+</nav>
+</body>
+</html>
+"#,
+        landmarks = landmarks.join("\n")
+    )
+}
+
+/// Returns the name of the file of the chapter with the given index in an
+/// epub written by [`write_epub`].
+fn chapter_file_name(index: usize) -> String {
+    format!("chapter-{index:05}.xhtml")
+}
+
+/// Returns the extension & media type of the given cover image, guessed from
+/// its first bytes. Defaults to JPEG, the format of most covers.
+fn cover_format(cover: &[u8]) -> (&'static str, &'static str) {
+    if cover.starts_with(b"\x89PNG") {
+        ("png", "image/png")
+    } else if cover.starts_with(b"GIF8") {
+        ("gif", "image/gif")
+    } else if cover.len() >= 12 && &cover[..4] == b"RIFF" && &cover[8..12] == b"WEBP" {
+        ("webp", "image/webp")
+    } else {
+        ("jpg", "image/jpeg")
+    }
+}
+
+/// Writes the children of `element` to `out` as XHTML: elements are always
+/// closed & text is escaped, so that the HTML scraped from sites becomes
+/// well-formed XML. Scripts & styles are dropped.
+fn push_xhtml_of(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(&encode_text(&**text)),
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).unwrap();
+                let name = child.value().name();
+                if matches!(name, "script" | "style") {
+                    continue;
+                }
+                out.push('<');
+                out.push_str(name);
+                for (attribute, value) in child.value().attrs() {
+                    let _ = write!(
+                        out,
+                        r#" {attribute}="{}""#,
+                        encode_double_quoted_attribute(value)
+                    );
+                }
+                if VOID_ELEMENTS.contains(&name) {
+                    out.push_str("/>");
+                } else {
+                    out.push('>');
+                    push_xhtml_of(child, out);
+                    let _ = write!(out, "</{name}>");
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Builds the XHTML document of a chapter, its title as a heading followed by
+/// its content.
+fn chapter_document(chapter: &Chapter, language: &str) -> String {
+    let title = encode_text(&chapter_title_or_untitled(
+        chapter.title().as_deref().unwrap_or(""),
+        *chapter.index(),
+    ))
+    .to_string();
+    let mut content = String::new();
+    push_xhtml_of(
+        Html::parse_fragment(chapter.content()).root_element(),
+        &mut content,
+    );
+    let language = encode_double_quoted_attribute(language);
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" lang="{language}" xml:lang="{language}">
+<head>
+<title>{title}</title>
+</head>
+<body>
+<h1>{title}</h1>
+{content}
+</body>
+</html>
+"#
+    )
+}
+
+/// Builds the XHTML page showing the cover image at `image_href`.
+fn cover_document(title: &str, language: &str, image_href: &str) -> String {
+    let title = encode_text(title);
+    let language = encode_double_quoted_attribute(language);
+    let image_href = encode_double_quoted_attribute(image_href);
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" lang="{language}" xml:lang="{language}">
+<head>
+<title>{title}</title>
+</head>
+<body>
+<img src="{image_href}" alt="{title}"/>
+</body>
+</html>
+"#
+    )
+}
+
+/// Builds the package document of an epub, describing the fiction & listing
+/// its files (the nav document, the cover if `cover_file` is given, and the
+/// chapters) in reading order.
+fn package_document(
+    metadata: &FictionMetadata,
+    identifier: &str,
+    language: &str,
+    cover_file: Option<(&str, &str)>,
+    chapters: &[Chapter],
+) -> String {
+    let mut items = vec![format!(
+        r#"<item id="nav" href="{NAV_FILE_NAME}" media-type="application/xhtml+xml" properties="nav"/>"#
+    )];
+    let mut spine = Vec::new();
+    if let Some((file_name, media_type)) = cover_file {
+        items.push(format!(
+            r#"<item id="cover-image" href="{file_name}" media-type="{media_type}" properties="cover-image"/>"#
+        ));
+        items.push(
+            r#"<item id="cover" href="cover.xhtml" media-type="application/xhtml+xml"/>"#
+                .to_string(),
+        );
+        spine.push(r#"<itemref idref="cover"/>"#.to_string());
+    }
+    spine.push(r#"<itemref idref="nav"/>"#.to_string());
+    for chapter in chapters {
+        let id = format!("chapter-{}", chapter.index());
+        items.push(format!(
+            r#"<item id="{id}" href="{}" media-type="application/xhtml+xml"/>"#,
+            chapter_file_name(*chapter.index())
+        ));
+        spine.push(format!(r#"<itemref idref="{id}"/>"#));
+    }
+    let creators: Vec<String> = metadata
+        .authors()
+        .iter()
+        .map(|author| format!("<dc:creator>{}</dc:creator>", encode_text(author)))
+        .collect();
+    let cover_meta = if cover_file.is_some() {
+        "\n<meta name=\"cover\" content=\"cover-image\"/>"
+    } else {
+        ""
+    };
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id" xml:lang="{language}">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+<dc:identifier id="id">{identifier}</dc:identifier>
+<dc:title>{title}</dc:title>
+<dc:language>{language}</dc:language>
+{creators}
+<meta property="dcterms:modified">{modified}</meta>{cover_meta}
+</metadata>
+<manifest>
+{items}
+</manifest>
+<spine>
+{spine}
+</spine>
+</package>
+"#,
+        identifier = encode_text(identifier),
+        title = encode_text(metadata.title()),
+        language = encode_double_quoted_attribute(language),
+        creators = creators.join("\n"),
+        modified = Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+        items = items.join("\n"),
+        spine = spine.join("\n"),
+    )
+}
+
+/// Writes an EPUB3 file to `writer`, holding the given chapters (in that order)
+/// & cover image, described by `metadata`: its title, authors & language
+/// ([`DEFAULT_LANGUAGE`] if unknown). `identifier` is the `dc:identifier` of
+/// the epub, which should not change when it is generated again (such as the
+/// fiction's URL, or [its UUID][crate::Backend::fiction_uuid]). The table of
+/// contents lists the chapters of each of the given volumes, see
+/// [`nav_document`].
+pub fn write_epub<W: Write + Seek>(
+    writer: W,
+    metadata: &FictionMetadata,
+    identifier: &str,
+    cover: Option<&[u8]>,
+    volumes: &[Volume],
+    chapters: &[Chapter],
+) -> Result<(), BackendError> {
+    let language = metadata.language().as_deref().unwrap_or(DEFAULT_LANGUAGE);
+    let cover_file = cover.map(|cover| {
+        let (extension, media_type) = cover_format(cover);
+        (format!("cover.{extension}"), media_type)
+    });
+
+    let mut zip = ZipWriter::new(writer);
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let mut add_file = |name: &str, content: &[u8]| -> Result<(), BackendError> {
+        zip.start_file(name, stored).map_err(io::Error::from)?;
+        zip.write_all(content)?;
+        Ok(())
+    };
+    // must be the first file, & stored uncompressed
+    add_file("mimetype", b"application/epub+zip")?;
+    add_file(
+        "META-INF/container.xml",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+<rootfiles>
+<rootfile full-path="{CONTENT_DIR}/content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles>
+</container>
+"#
+        )
+        .as_bytes(),
+    )?;
+    add_file(
+        &format!("{CONTENT_DIR}/content.opf"),
+        package_document(
+            metadata,
+            identifier,
+            language,
+            cover_file
+                .as_ref()
+                .map(|(file_name, media_type)| (file_name.as_str(), *media_type)),
+            chapters,
+        )
+        .as_bytes(),
+    )?;
+    add_file(
+        &format!("{CONTENT_DIR}/{NAV_FILE_NAME}"),
+        nav_document(
+            metadata.title(),
+            language,
+            volumes,
+            |info| chapter_file_name(*info.index()),
+            cover_file.as_ref().map(|_| "cover.xhtml"),
+        )
+        .as_bytes(),
+    )?;
+    if let (Some(cover), Some((file_name, _))) = (cover, &cover_file) {
+        add_file(&format!("{CONTENT_DIR}/{file_name}"), cover)?;
+        add_file(
+            &format!("{CONTENT_DIR}/cover.xhtml"),
+            cover_document(metadata.title(), language, file_name).as_bytes(),
+        )?;
+    }
+    for chapter in chapters {
+        add_file(
+            &format!("{CONTENT_DIR}/{}", chapter_file_name(*chapter.index())),
+            chapter_document(chapter, language).as_bytes(),
+        )?;
+    }
+    zip.finish().map_err(io::Error::from)?;
+    Ok(())
+}
+
+/// Writes the given volumes (along with their chapters, in reading order) to
+/// epub files in `dir` with [`write_epub`], as a single `{file_stem}.epub` or
+/// as one epub per volume, depending on `split`. Per-volume epubs are named
+/// `{file_stem}-{number}-{volume title}.epub` and titled after their volume,
+/// their identifier being the fiction's URL followed by `#volume-{number}`.
+/// Returns the written files, in reading order.
+pub fn write_epubs(
+    dir: &Path,
+    file_stem: &str,
+    metadata: &FictionMetadata,
+    cover: Option<&[u8]>,
+    volumes: &[(Volume, Vec<Chapter>)],
+    split: EpubSplit,
+) -> Result<Vec<PathBuf>, BackendError> {
+    fs::create_dir_all(dir)?;
+    match split {
+        EpubSplit::Single => {
+            let path = dir.join(format!("{file_stem}.epub"));
+            let (volumes, chapters): (Vec<Volume>, Vec<Vec<Chapter>>) =
+                volumes.iter().cloned().unzip();
+            write_epub(
+                fs::File::create(&path)?,
+                metadata,
+                metadata.url(),
+                cover,
+                &volumes,
+                &chapters.concat(),
+            )?;
+            Ok(vec![path])
+        }
+        EpubSplit::PerVolume => volumes
+            .iter()
+            .enumerate()
+            .map(|(i, (volume, chapters))| {
+                let number = i + 1;
+                let volume_title = volume
+                    .title()
+                    .clone()
+                    .unwrap_or_else(|| format!("Volume {number}"));
+                let path = dir.join(format!(
+                    "{file_stem}-{number:02}-{}.epub",
+                    slugify(&volume_title)
+                ));
+                let mut volume_metadata = metadata.clone();
+                volume_metadata.set_title(format!("{} - {volume_title}", metadata.title()));
+                // the volume is the whole book: its chapters are not nested
+                let mut volume = volume.clone();
+                volume.set_title(None);
+                write_epub(
+                    fs::File::create(&path)?,
+                    &volume_metadata,
+                    &format!("{}#volume-{number}", metadata.url()),
+                    cover,
+                    &[volume],
+                    chapters,
+                )?;
+                Ok(path)
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::path::Path;
+
+    use regex::Regex;
+    use scraper::{Html, Selector};
+
+    use crate::epub::{nav_document, write_epubs, EpubSplit};
+    use crate::{Chapter, ChapterInfo, FictionMetadata, Volume};
+
+    fn volume(title: Option<&str>, indices: &[usize]) -> Volume {
+        let mut volume = Volume::default();
+        volume.set_title(title.map(String::from));
+        volume.set_chapters(
+            indices
+                .iter()
+                .map(|index| {
+                    let mut info = ChapterInfo::default();
+                    info.set_index(*index);
+                    info.set_title((*index != 3).then(|| format!("Chapter {index} & more")));
+                    info
+                })
+                .collect(),
+        );
+        volume
+    }
+
+    fn chapter(index: usize) -> Chapter {
+        let mut chapter = Chapter::default();
+        chapter.set_index(index);
+        chapter.set_title(Some(format!("Chapter {index}")));
+        chapter.set_content(format!("<p>Content of chapter {index}<br>& more</p>"));
+        chapter
+    }
+
+    /// Returns the names & contents of the files in the epub at `path`, in
+    /// archive order.
+    fn read_epub(path: &Path) -> Vec<(String, String)> {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut file = archive.by_index(i).unwrap();
+                let mut content = Vec::new();
+                file.read_to_end(&mut content).unwrap();
+                (
+                    file.name().to_string(),
+                    String::from_utf8_lossy(&content).to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Makes sure the given XML is well-formed: tags are balanced & properly
+    /// nested, and there are no raw ampersands.
+    fn assert_well_formed(xml: &str) {
+        let tag = Regex::new(r"<(/?)([A-Za-z][\w:-]*)[^>]*?(/?)>").unwrap();
+        let mut open = Vec::new();
+        for captures in tag.captures_iter(xml) {
+            let name = &captures[2];
+            if &captures[3] == "/" {
+                continue;
+            }
+            if &captures[1] == "/" {
+                assert_eq!(open.pop(), Some(name.to_string()), "unbalanced </{name}>");
+            } else {
+                open.push(name.to_string());
+            }
+        }
+        assert!(open.is_empty(), "unclosed tags: {open:?}");
+        let entity = Regex::new(r"^&(amp|lt|gt|quot|#\d+|#x[0-9a-fA-F]+);").unwrap();
+        for (i, _) in xml.match_indices('&') {
+            assert!(entity.is_match(&xml[i..]), "raw ampersand at {i}");
+        }
+    }
+
+    #[test]
+    fn test_nav_document() {
+        let volumes = [
+            volume(Some("Book 1: Zorian"), &[1, 2]),
+            volume(None, &[]),
+            volume(None, &[3, 4]),
+        ];
+        let nav = nav_document(
+            "Mother & Learning",
+            "en",
+            &volumes,
+            |info| format!("chapter-{}.xhtml", info.index()),
+            Some("cover.xhtml"),
+        );
+        assert!(nav.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(nav.contains(r#"xmlns:epub="http://www.idpf.org/2007/ops""#));
+        assert_well_formed(&nav);
+
+        let document = Html::parse_document(&nav);
+        let select = |selector: &str| -> Vec<String> {
+            document
+                .select(&Selector::parse(selector).unwrap())
+                .map(|element| element.text().collect())
+                .collect()
+        };
+        assert_eq!(select("title"), vec!["Mother & Learning"]);
+        assert_eq!(
+            select(r#"nav[epub\:type="toc"] > ol > li > span"#),
+            vec!["Book 1: Zorian", "Volume 2"]
+        );
+        assert_eq!(
+            select(r#"nav[epub\:type="toc"] > ol > li > ol > li > a"#),
+            vec![
+                "Chapter 1 & more",
+                "Chapter 2 & more",
+                "Chapter 3",
+                "Chapter 4 & more"
+            ]
+        );
+        let landmarks: Vec<(String, String)> = document
+            .select(&Selector::parse(r#"nav[epub\:type="landmarks"] a"#).unwrap())
+            .map(|a| {
+                (
+                    a.attr("epub:type").unwrap().to_string(),
+                    a.attr("href").unwrap().to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            landmarks,
+            vec![
+                ("cover".to_string(), "cover.xhtml".to_string()),
+                ("toc".to_string(), "#toc".to_string()),
+                ("bodymatter".to_string(), "chapter-1.xhtml".to_string()),
+            ]
+        );
+
+        // a single untitled volume is not nested
+        let nav = nav_document(
+            "Mother of Learning",
+            "en",
+            &[volume(None, &[1, 2])],
+            |info| format!("chapter-{}.xhtml", info.index()),
+            None,
+        );
+        assert_well_formed(&nav);
+        let document = Html::parse_document(&nav);
+        let chapters = Selector::parse(r#"nav[epub\:type="toc"] > ol > li > a"#).unwrap();
+        assert_eq!(document.select(&chapters).count(), 2);
+        let cover = Selector::parse(r#"a[epub\:type="cover"]"#).unwrap();
+        assert_eq!(document.select(&cover).count(), 0);
+
+        // the table of contents is never empty
+        for (cover_href, expected_href) in [(Some("cover.xhtml"), "cover.xhtml"), (None, "#toc")] {
+            let nav = nav_document(
+                "Mother & Learning",
+                "en",
+                &[volume(None, &[])],
+                |info| format!("chapter-{}.xhtml", info.index()),
+                cover_href,
+            );
+            assert_well_formed(&nav);
+            let document = Html::parse_document(&nav);
+            let items: Vec<(String, String)> = document
+                .select(&Selector::parse(r#"nav[epub\:type="toc"] > ol > li > a"#).unwrap())
+                .map(|a| (a.attr("href").unwrap().to_string(), a.text().collect()))
+                .collect();
+            assert_eq!(
+                items,
+                vec![(expected_href.to_string(), "Mother & Learning".to_string())]
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_epubs() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut metadata = FictionMetadata::default();
+        metadata.set_url("https://example.com/fiction/1".to_string());
+        metadata.set_title("Mother of Learning".to_string());
+        metadata.set_authors(vec!["nobody103".to_string()]);
+        let volumes = vec![
+            (
+                volume(Some("Book 1: Zorian"), &[1, 2]),
+                vec![chapter(1), chapter(2)],
+            ),
+            (volume(None, &[3]), vec![chapter(3)]),
+        ];
+        let cover = b"\x89PNG\r\n";
+
+        let paths = write_epubs(
+            dir.path(),
+            "mother-of-learning",
+            &metadata,
+            Some(cover),
+            &volumes,
+            EpubSplit::PerVolume,
+        )
+        .unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("mother-of-learning-01-book-1-zorian.epub"),
+                dir.path().join("mother-of-learning-02-volume-2.epub"),
+            ]
+        );
+        let chapter_files = |files: &[(String, String)]| -> Vec<String> {
+            files
+                .iter()
+                .map(|(name, _)| name.clone())
+                .filter(|name| name.starts_with("EPUB/chapter-"))
+                .collect()
+        };
+        let first = read_epub(&paths[0]);
+        assert_eq!(
+            first[0],
+            ("mimetype".to_string(), "application/epub+zip".to_string())
+        );
+        assert_eq!(
+            chapter_files(&first),
+            vec!["EPUB/chapter-00001.xhtml", "EPUB/chapter-00002.xhtml"]
+        );
+        let second = read_epub(&paths[1]);
+        assert_eq!(chapter_files(&second), vec!["EPUB/chapter-00003.xhtml"]);
+        for files in [&first, &second] {
+            for (name, content) in files.iter().filter(|(name, _)| name.ends_with("ml")) {
+                assert!(content.starts_with("<?xml"), "{name} is not XML");
+                assert_well_formed(content);
+            }
+            // both share the cover
+            assert!(files.iter().any(|(name, content)| name == "EPUB/cover.png"
+                && *content == String::from_utf8_lossy(cover)));
+        }
+        let file = |files: &[(String, String)], name: &str| -> String {
+            files.iter().find(|(n, _)| n == name).unwrap().1.clone()
+        };
+        let package = file(&first, "EPUB/content.opf");
+        assert!(package.contains("<dc:title>Mother of Learning - Book 1: Zorian</dc:title>"));
+        assert!(package.contains("<dc:creator>nobody103</dc:creator>"));
+        assert!(package.contains(
+            r#"<dc:identifier id="id">https://example.com/fiction/1#volume-1</dc:identifier>"#
+        ));
+        assert!(file(&second, "EPUB/content.opf")
+            .contains("<dc:title>Mother of Learning - Volume 2</dc:title>"));
+        assert!(file(&first, "EPUB/chapter-00002.xhtml")
+            .contains("<h1>Chapter 2</h1>\n<p>Content of chapter 2<br/>&amp; more</p>"));
+
+        // a single epub with both volumes
+        let paths = write_epubs(
+            dir.path(),
+            "mother-of-learning",
+            &metadata,
+            None,
+            &volumes,
+            EpubSplit::Single,
+        )
+        .unwrap();
+        assert_eq!(paths, vec![dir.path().join("mother-of-learning.epub")]);
+        let files = read_epub(&paths[0]);
+        assert_eq!(chapter_files(&files).len(), 3);
+        assert!(!files.iter().any(|(name, _)| name.starts_with("EPUB/cover")));
+        assert!(file(&files, "EPUB/nav.xhtml").contains("<span>Book 1: Zorian</span>"));
+    }
+}
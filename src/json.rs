@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use html_escape::encode_text;
+use serde_json::Value;
+
+use crate::{Chapter, ChapterParseError};
+
+/// How the content of a chapter is formatted in a site's JSON.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ContentFormat {
+    /// The content is HTML, which is sanitized like scraped content.
+    #[default]
+    Html,
+    /// The content is plain text: paragraphs (separated by blank lines) are
+    /// wrapped in `<p>` tags, and line breaks within them become `<br>`.
+    PlainText,
+}
+
+/// Where the fields of a chapter are in a site's JSON, as
+/// [JSON pointers](https://datatracker.ietf.org/doc/html/rfc6901) such as
+/// `/data/chapter/title`. See [`Chapter::from_json_fields`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChapterJsonFields {
+    /// Pointer to the title of the chapter, if the site has any.
+    pub title: Option<String>,
+    /// Pointer to the content of the chapter.
+    pub content: String,
+    /// How the content is formatted.
+    pub content_format: ContentFormat,
+    /// Pointer to the publication date of the chapter, if the site has any.
+    /// Dates can be RFC 3339 strings or UNIX timestamps (in seconds).
+    pub published_at: Option<String>,
+}
+
+/// Turns plain text into HTML paragraphs: paragraphs are separated by blank
+/// lines, and line breaks within a paragraph become `<br>`.
+fn text_to_html(text: &str) -> String {
+    let text = text.replace("\r\n", "\n");
+    let mut html = String::with_capacity(text.len());
+    let mut paragraph = Vec::new();
+    for line in text.lines().map(str::trim).chain([""]) {
+        if !line.is_empty() {
+            paragraph.push(encode_text(line).to_string());
+        } else if !paragraph.is_empty() {
+            html.push_str(&format!("<p>{}</p>\n", paragraph.join("<br>")));
+            paragraph.clear();
+        }
+    }
+    html.trim_end().to_string()
+}
+
+impl Chapter {
+    /// Builds a chapter from the JSON served by an API-based site, taking the
+    /// title, content & publication date where `fields` points to. This is
+    /// the standard way for backends of such sites to populate chapters; the
+    /// other fields are left to the backend.
+    ///
+    /// ```rust
+    /// use libwebnovel::json::{ChapterJsonFields, ContentFormat};
+    /// use libwebnovel::Chapter;
+    /// let json = serde_json::json!({"data": {"name": "Prologue", "text": "It was dark.\n\nAnd cold."}});
+    /// let chapter = Chapter::from_json_fields(
+    ///     &json,
+    ///     &ChapterJsonFields {
+    ///         title: Some("/data/name".to_string()),
+    ///         content: "/data/text".to_string(),
+    ///         content_format: ContentFormat::PlainText,
+    ///         published_at: None,
+    ///     },
+    /// )
+    /// .unwrap();
+    /// assert_eq!(chapter.title(), &Some("Prologue".to_string()));
+    /// assert_eq!(chapter.content(), "<p>It was dark.</p>\n<p>And cold.</p>");
+    /// ```
+    pub fn from_json_fields(
+        json: &Value,
+        fields: &ChapterJsonFields,
+    ) -> Result<Chapter, ChapterParseError> {
+        let field = |pointer: &str| {
+            json.pointer(pointer).ok_or(ChapterParseError::new(format!(
+                "Could not find chapter field {pointer}"
+            )))
+        };
+        let string_field = |pointer: &str| {
+            field(pointer)?
+                .as_str()
+                .ok_or(ChapterParseError::new(format!(
+                    "Chapter field {pointer} is not a string"
+                )))
+        };
+        let mut chapter = Chapter::default();
+        if let Some(pointer) = &fields.title {
+            chapter.set_title(Some(string_field(pointer)?.to_string()));
+        }
+        let content = string_field(&fields.content)?;
+        match fields.content_format {
+            ContentFormat::Html => chapter.set_content(content),
+            ContentFormat::PlainText => chapter.set_content(text_to_html(content)),
+        }
+        if let Some(pointer) = &fields.published_at {
+            let published_at = match field(pointer)? {
+                Value::String(date) => DateTime::parse_from_rfc3339(date)
+                    .ok()
+                    .map(|date| date.with_timezone(&Utc)),
+                Value::Number(timestamp) => timestamp
+                    .as_i64()
+                    .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+                _ => None,
+            };
+            chapter.set_published_at(Some(published_at.ok_or(ChapterParseError::new(
+                format!("Chapter field {pointer} is not a valid date"),
+            ))?));
+        }
+        Ok(chapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use serde_json::json;
+
+    use crate::json::{ChapterJsonFields, ContentFormat};
+    use crate::Chapter;
+
+    #[test]
+    fn test_from_json_fields() {
+        let json = json!({
+            "chapter": {
+                "title": "1. The Storm",
+                "body": "  The wind <howled>.\r\nAll night.\n\n\n\"Run!\" she said.\n",
+                "date": 1700000000,
+            }
+        });
+        let mut fields = ChapterJsonFields {
+            title: Some("/chapter/title".to_string()),
+            content: "/chapter/body".to_string(),
+            content_format: ContentFormat::PlainText,
+            published_at: Some("/chapter/date".to_string()),
+        };
+        let chapter = Chapter::from_json_fields(&json, &fields).unwrap();
+        assert_eq!(chapter.title(), &Some("1. The Storm".to_string()));
+        assert_eq!(
+            chapter.content(),
+            "<p>The wind &lt;howled&gt;.<br>All night.</p>\n<p>\"Run!\" she said.</p>"
+        );
+        assert_eq!(
+            chapter.published_at(),
+            &DateTime::from_timestamp(1700000000, 0)
+        );
+
+        let json =
+            json!({"chapter": {"body": "<p>Some <b>HTML</p>", "date": "2024-01-01T00:00:00Z"}});
+        fields.title = None;
+        fields.content_format = ContentFormat::Html;
+        let chapter = Chapter::from_json_fields(&json, &fields).unwrap();
+        assert_eq!(chapter.title(), &None);
+        assert_eq!(chapter.content(), "<p>Some <b>HTML</b></p>");
+        assert!(chapter.published_at().is_some());
+
+        fields.content = "/chapter/missing".to_string();
+        assert!(Chapter::from_json_fields(&json, &fields).is_err());
+    }
+}
@@ -37,7 +37,7 @@
 //!     let chapters = fiction_backend.get_chapters().unwrap();
 //!
 //!     // write the resulting epub
-//!     let epub_path = format!("{}.epub", fiction_backend.title().unwrap());
+//!     let epub_path = format!("{}.epub", fiction_backend.output_file_stem().unwrap());
 //!     let mut f = File::create(&epub_path).unwrap();
 //!     write_chapters_to_epub(&mut f, &chapters).unwrap();
 //!
@@ -62,6 +62,13 @@
 //! - [FreeWebNovel](https://freewebnovel.com/)
 //! - [LibRead](https://libread.com/)
 //! - [lightnovelworld](https://www.lightnovelworld.com/)
+//! - [lightnovelpub](https://www.lightnovelpub.com/) & [webnovelpub](https://www.webnovelpub.com/)
+//! - [Dreame](https://www.dreame.com/), through its mobile app's API
+//! - [ScribbleHub](https://www.scribblehub.com/)
+//!
+//! Other sites may be supported without writing a new backend, by describing
+//! them with a `SiteTemplate` used by a `TemplateBackend` (requires the
+//! *template* feature).
 //!
 //! ## Cargo features
 //!
@@ -70,7 +77,30 @@
 //!
 //! By default, only the *royalroad* and *freewebnovel* are enabled. *libread*
 //! is disabled by default since (in my meager experience) it is simply a
-//! different frontend for *freewebnovel*.
+//! different frontend for *freewebnovel*. Likewise, *lightnovelpub* (disabled
+//! by default) makes the *lightnovelworld* backend handle its sister sites.
+//!
+//! The *serde* feature enables exporting a fiction as a JSON manifest plus
+//! chapter files, see `Backends::export_manifest`.
+//!
+//! The *dreame* backend (disabled by default) uses Dreame's JSON API, so it
+//! enables the *serde* feature.
+//!
+//! The *scribblehub* backend is disabled by default: ScribbleHub is behind
+//! Cloudflare, whose challenges may require a `cf_clearance` cookie obtained in
+//! a browser (see `CloudflareClearance`).
+//!
+//! The *uuid* feature adds `Backend::fiction_uuid`, a stable UUID to use as
+//! the identifier of generated epubs.
+//!
+//! The *epub* feature adds the `epub` module, which builds the EPUB3
+//! navigation document (table of contents & landmarks) of a fiction and writes
+//! epub files, and `Backends::export_epub`, which exports a fiction as a single
+//! epub or as one epub per volume.
+//!
+//! The *async* feature adds `AsyncBackend`, fetching chapters & covers with
+//! `reqwest`'s async client (only supported by the *royalroad* backend for
+//! now).
 //!
 //! if you want all features, including the default ones:
 //! ```toml
@@ -117,10 +147,11 @@
 //!   - [x] freewebnovel
 //!   - [x] royalroad
 //!   - [x] lightnovelworld
-//!   - [ ] scribblehub - May be complicated because of cloudflare
+//!   - [x] dreame
+//!   - [x] scribblehub
 //!   - [ ] suggestions?
 //! - [ ] implement an `async` version to get a better throughput. May be
-//!   important for images?
+//!   important for images? `AsyncBackend` only supports royalroad so far.
 //! - [x] ~create a binary using this lib to save webnovels to disk. It may also
 //!   serve as a sample implementation?~ See [libwebnovel-storage](https://crates.io/crates/libwebnovel-storage)
 //! - [x] implement a way to get an [`Ordering`][std::cmp::Ordering] between
@@ -153,18 +184,46 @@ use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use getset::{CopyGetters, Getters, Setters};
-use log::{debug, trace};
+use log::{debug, trace, warn};
 use scraper::{Html, Selector};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
+use url::Url;
 
 /// implementations of backends
 pub mod backends;
+#[cfg(feature = "async")]
+pub use backends::AsyncBackend;
+use backends::BackendError;
 pub use backends::{Backend, Backends};
 
+/// Export of downloaded fictions as a JSON manifest & chapter files
+#[cfg(feature = "serde")]
+pub mod manifest;
+
+/// Building chapters from the JSON served by API-based sites
+#[cfg(feature = "serde")]
+pub mod json;
+
+/// Generation of epub files & of the documents making them up
+#[cfg(feature = "epub")]
+pub mod epub;
+
 pub(crate) mod utils;
 
+#[cfg(feature = "template")]
+pub(crate) mod document_cache;
+pub use utils::{
+    extract_readable, retry, set_circuit_breaker_config, set_rate_limit, set_user_agent_rotation,
+    slugify, CircuitBreakerConfig, ClientConfig, CloudflareClearance, DashStyle, HttpClient,
+    QuoteStyle, RateLimit, RetryPolicy, TypographyOptions, UserAgentRotation,
+};
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
 /// A chapter of a webnovel
-#[derive(Getters, Setters, CopyGetters, Default, Clone, PartialEq)]
+#[derive(Getters, Setters, CopyGetters, Default, Clone)]
 pub struct Chapter {
     /// Index of this chapter in the grand scheme of things.
     #[getset(get = "pub", set = "pub")]
@@ -175,6 +234,18 @@ pub struct Chapter {
     /// Content of this chapter.
     #[getset(get = "pub")]
     content: String,
+    /// Content of this chapter as served by the site, before any cleaning.
+    /// Only kept when
+    /// [`BackendOptions::keep_raw_content`][crate::backends::BackendOptions::keep_raw_content]
+    /// is set, and not part of the chapter's text representation.
+    #[getset(get = "pub", set)]
+    raw_content: Option<String>,
+    /// Note of the author shown before the content of the chapter, as HTML.
+    #[getset(get = "pub", set)]
+    author_notes_start: Option<String>,
+    /// Note of the author shown after the content of the chapter, as HTML.
+    #[getset(get = "pub", set)]
+    author_notes_end: Option<String>,
     /// Where can this chapter be found?
     #[getset(get = "pub", set)]
     chapter_url: String,
@@ -184,9 +255,21 @@ pub struct Chapter {
     /// date this chapter was published.
     #[getset(get = "pub", set)]
     published_at: Option<DateTime<Utc>>,
+    /// date this chapter was last edited, for sites telling it apart from its
+    /// publication date.
+    #[getset(get = "pub", set)]
+    updated_at: Option<DateTime<Utc>>,
     /// Arbitrary metadata added by the backend.
     #[getset(get = "pub", set)]
     metadata: HashMap<String, String>,
+    /// URLs of the images of the chapter, resolved against its
+    /// [`Chapter::chapter_url`]. Only collected once enabled by
+    /// [`Chapter::collect_images`], and not part of the chapter's text
+    /// representation.
+    #[getset(get = "pub")]
+    images: Vec<String>,
+    /// Whether [`Chapter::images`] is updated when the content changes.
+    collect_images: bool,
 }
 
 impl Debug for Chapter {
@@ -199,16 +282,23 @@ impl Debug for Chapter {
             chapter_url: &'a String,
             fiction_url: &'a String,
             published_at: &'a Option<DateTime<Utc>>,
+            updated_at: &'a Option<DateTime<Utc>>,
             metadata: &'a HashMap<String, String>,
         }
         let Self {
             index,
             title,
             content: _,
+            raw_content: _,
+            author_notes_start: _,
+            author_notes_end: _,
             chapter_url,
             fiction_url,
             published_at,
+            updated_at,
             metadata,
+            images: _,
+            collect_images: _,
         } = self;
         Debug::fmt(
             &Chapter {
@@ -217,6 +307,7 @@ impl Debug for Chapter {
                 chapter_url,
                 fiction_url,
                 published_at,
+                updated_at,
                 metadata,
             },
             f,
@@ -224,22 +315,676 @@ impl Debug for Chapter {
     }
 }
 
+impl PartialEq for Chapter {
+    /// Compares everything that is part of the chapter's text representation,
+    /// so that a chapter equals its own round-trip through
+    /// [`Chapter::from_str`]. Collected images are derived from the content, so
+    /// they aren't compared either.
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            index,
+            title,
+            content,
+            raw_content: _,
+            author_notes_start,
+            author_notes_end,
+            chapter_url,
+            fiction_url,
+            published_at,
+            updated_at,
+            metadata,
+            images: _,
+            collect_images: _,
+        } = self;
+        (
+            index,
+            title,
+            content,
+            author_notes_start,
+            author_notes_end,
+            chapter_url,
+            fiction_url,
+            published_at,
+            updated_at,
+            metadata,
+        ) == (
+            &other.index,
+            &other.title,
+            &other.content,
+            &other.author_notes_start,
+            &other.author_notes_end,
+            &other.chapter_url,
+            &other.fiction_url,
+            &other.published_at,
+            &other.updated_at,
+            &other.metadata,
+        )
+    }
+}
+
 impl Chapter {
+    /// Sets the content of the chapter, normalizing it by parsing it as an HTML
+    /// fragment. This is what should be used for scraped content, which may be
+    /// malformed. See [`Chapter::set_content_raw`] for content that is already
+    /// clean.
     fn set_content(&mut self, s: impl Into<String>) {
-        self.content = Html::parse_fragment(&s.into())
-            .html()
-            .strip_prefix("<html>")
-            .unwrap()
-            .strip_suffix("</html>")
-            .unwrap()
-            .trim()
-            .to_string();
+        self.replace_content(normalize_fragment(&s.into()));
+    }
+
+    /// Replaces the content of the chapter, keeping [`Chapter::images`] up to
+    /// date. Every change to the content must go through here.
+    fn replace_content(&mut self, content: String) {
+        self.content = content;
+        self.update_images();
+    }
+
+    /// Inserts the given HTML before the content of the chapter, for instance
+    /// to credit its source. The HTML is normalized first, so that unclosed
+    /// tags cannot swallow the rest of the chapter.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw("<p>Once upon a time…</p>");
+    /// chapter.prepend_header("<p>Source: https://example.com");
+    /// assert_eq!(
+    ///     chapter.content(),
+    ///     "<p>Source: https://example.com</p>\n<p>Once upon a time…</p>"
+    /// );
+    /// ```
+    pub fn prepend_header(&mut self, html: &str) {
+        self.replace_content(format!("{}\n{}", normalize_fragment(html), self.content));
+    }
+
+    /// Inserts the given HTML after the content of the chapter. See
+    /// [`Chapter::prepend_header`].
+    pub fn append_footer(&mut self, html: &str) {
+        self.replace_content(format!("{}\n{}", self.content, normalize_fragment(html)));
+    }
+
+    /// Sets the content of the chapter as-is, without parsing nor normalizing
+    /// it. This is faster than re-parsing and lossless, which is useful when
+    /// importing content that has already been sanitized (for instance,
+    /// chapters that were previously stored by this crate). The caller is
+    /// responsible for giving well-formed HTML.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw("<p>Some <em>pre-sanitized</em> content</p>\n");
+    /// assert_eq!(
+    ///     chapter.content(),
+    ///     "<p>Some <em>pre-sanitized</em> content</p>\n"
+    /// );
+    /// ```
+    pub fn set_content_raw(&mut self, content: impl Into<String>) {
+        self.replace_content(content.into());
+    }
+
+    /// Collects the URLs of the images of the chapter into
+    /// [`Chapter::images`], and keeps them up to date whenever the content
+    /// changes. Site-relative `src` attributes are resolved against the
+    /// chapter's URL; images whose URL can't be resolved that way, or that
+    /// are embedded in the content (`data:` URLs), are left out.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.collect_images();
+    /// chapter.set_content_raw(
+    ///     r#"<p><img src="https://example.com/map.png"><img src="data:image/png;base64,AAAA"></p>"#,
+    /// );
+    /// assert_eq!(chapter.images(), &["https://example.com/map.png"]);
+    /// ```
+    pub fn collect_images(&mut self) {
+        self.collect_images = true;
+        self.update_images();
+    }
+
+    /// Refreshes [`Chapter::images`] from the content, if image collection is
+    /// enabled.
+    fn update_images(&mut self) {
+        if !self.collect_images {
+            return;
+        }
+        let base = Url::parse(&self.chapter_url).ok();
+        let mut images: Vec<String> = Vec::new();
+        for (src, _) in self.image_alts() {
+            let url = match &base {
+                Some(base) => base.join(&src),
+                None => Url::parse(&src),
+            };
+            match url {
+                Ok(url) if matches!(url.scheme(), "http" | "https") => {
+                    if !images.contains(&url.to_string()) {
+                        images.push(url.to_string());
+                    }
+                }
+                _ => debug!("Not collecting image {src:?} of {}", self.chapter_url),
+            }
+        }
+        self.images = images;
+    }
+
+    /// Downloads the images found by [`Chapter::collect_images`] with the given
+    /// client (usually the [backend's][Backend::http_client], so that its
+    /// [`ClientConfig`] applies), returning each URL along with the image's
+    /// bytes, to embed them in an epub for instance. Images that can't be
+    /// downloaded are skipped.
+    pub fn download_images(&self, client: &HttpClient) -> Vec<(String, Vec<u8>)> {
+        let mut images = Vec::with_capacity(self.images.len());
+        for url in &self.images {
+            let bytes = client.get(url.as_str()).and_then(|resp| {
+                if !resp.status().is_success() {
+                    return Err(BackendError::RequestFailed {
+                        message: "Could not download image".to_string(),
+                        status: resp.status(),
+                        content: resp.text()?,
+                    });
+                }
+                Ok(resp.bytes()?.to_vec())
+            });
+            match bytes {
+                Ok(bytes) => images.push((url.clone(), bytes)),
+                Err(e) => warn!("Could not download image {url}: {e}"),
+            }
+        }
+        images
+    }
+
+    /// Shrinks the chapter's content by collapsing insignificant whitespace
+    /// between tags, and removing comments & empty attributes. The text of the
+    /// chapter is preserved, as well as the content of `<pre>` elements.
+    pub fn minify_content(&mut self) {
+        self.replace_content(utils::minify_html(&self.content));
+    }
+
+    /// Makes the typography of the chapter consistent: HTML entities are
+    /// decoded to the characters they stand for, and quotes & dashes are
+    /// rewritten to the style chosen in `options`. The content of `<pre>` &
+    /// `<code>` elements is left untouched.
+    ///
+    /// ```rust
+    /// use libwebnovel::{Chapter, QuoteStyle, TypographyOptions};
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw("<p>&ldquo;It&#8217;s late,” she said.</p>");
+    /// chapter.normalize_typography(TypographyOptions {
+    ///     quotes: QuoteStyle::Straight,
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(chapter.content(), r#"<p>"It's late," she said.</p>"#);
+    /// ```
+    pub fn normalize_typography(&mut self, options: TypographyOptions) {
+        self.replace_content(utils::normalize_typography(&self.content, options));
+    }
+
+    /// Removes the paragraphs of the chapter whose text is exactly one of
+    /// `phrases`, ignoring differences in whitespace. Sites inserting
+    /// boilerplate in chapters (such as RoyalRoad's anti-theft notices) can be
+    /// cleaned up this way, with a list of known phrases.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw(
+    ///     "<p>Read this on example.com!</p><p>Once upon a time…</p><p>Support the author.</p>",
+    /// );
+    /// chapter.strip_phrases(&["Read this on example.com!", "Support the author."]);
+    /// assert_eq!(chapter.content(), "<p>Once upon a time…</p>");
+    /// ```
+    pub fn strip_phrases(&mut self, phrases: &[&str]) {
+        self.replace_content(utils::strip_phrases(&self.content, phrases));
+    }
+
+    /// Removes the navigation blocks left at the start & at the end of the
+    /// chapter's content by over-capturing selectors, such as "Previous
+    /// Chapter | Table of Contents | Next Chapter": `<nav>` elements, and
+    /// blocks made only of links to other chapters (or to the index). Links
+    /// within the text are kept. See also
+    /// [`BackendOptions::strip_navigation`][crate::backends::BackendOptions::strip_navigation].
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw(
+    ///     r#"<p>Once upon a time…</p><p><a href="/1">Previous Chapter</a> | <a href="/3">Next Chapter</a></p>"#,
+    /// );
+    /// chapter.strip_navigation();
+    /// assert_eq!(chapter.content(), "<p>Once upon a time…</p>");
+    /// ```
+    pub fn strip_navigation(&mut self) {
+        self.replace_content(utils::strip_navigation(&self.content));
+    }
+
+    /// Sets the chapter's publication date from a string, which may be in any
+    /// of the formats commonly found on fiction sites: RFC 3339
+    /// (`2023-11-14T22:13:20Z`), `2023-11-14T22:13:20` or
+    /// `2023-11-14 22:13:20` (assumed to be UTC), `2023-11-14`, or relative to
+    /// now (`3 days ago`, `yesterday`). The date is left untouched when it
+    /// can't be parsed.
+    ///
+    /// ```rust
+    /// use chrono::DateTime;
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter
+    ///     .set_published_at_from_str("2023-11-14 22:13:20")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     *chapter.published_at(),
+    ///     DateTime::from_timestamp(1700000000, 0)
+    /// );
+    /// assert!(chapter.set_published_at_from_str("not a date").is_err());
+    /// ```
+    pub fn set_published_at_from_str(&mut self, s: &str) -> Result<(), BackendError> {
+        self.published_at = Some(utils::parse_date(s)?);
+        Ok(())
+    }
+
+    /// Guesses the direction of the chapter's text, by comparing the number of
+    /// letters from right-to-left scripts to the number of other letters.
+    /// Returns [`TextDirection::Auto`] when there are no letters, or as many
+    /// of each. This can be used to set the `dir` attribute of the chapter
+    /// when generating an epub.
+    ///
+    /// ```rust
+    /// use libwebnovel::{Chapter, TextDirection};
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw("<p>Once upon a time…</p>");
+    /// assert_eq!(chapter.text_direction(), TextDirection::Ltr);
+    /// ```
+    pub fn text_direction(&self) -> TextDirection {
+        let (mut rtl, mut ltr) = (0usize, 0usize);
+        let fragment = Html::parse_fragment(&self.content);
+        for c in fragment
+            .root_element()
+            .text()
+            .flat_map(|t| t.chars())
+            .filter(|c| c.is_alphabetic())
+        {
+            if is_rtl_char(c) {
+                rtl += 1;
+            } else {
+                ltr += 1;
+            }
+        }
+        match rtl.cmp(&ltr) {
+            std::cmp::Ordering::Greater => TextDirection::Rtl,
+            std::cmp::Ordering::Less => TextDirection::Ltr,
+            std::cmp::Ordering::Equal => TextDirection::Auto,
+        }
+    }
+
+    /// Returns the `src` & `alt` attributes of the images of the chapter, in
+    /// order. Images without a `src` get an empty one.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw(r#"<p><img src="map.png" alt="A map of the city"></p>"#);
+    /// assert_eq!(
+    ///     chapter.image_alts(),
+    ///     vec![("map.png".to_string(), Some("A map of the city".to_string()))]
+    /// );
+    /// ```
+    pub fn image_alts(&self) -> Vec<(String, Option<String>)> {
+        Html::parse_fragment(&self.content)
+            .select(&Selector::parse("img").unwrap())
+            .map(|img| {
+                (
+                    img.attr("src").unwrap_or_default().to_string(),
+                    img.attr("alt").map(str::to_string),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the content of the chapter as plain text, paragraphs being
+    /// separated by blank lines. Images are replaced by `[image: alt]` when
+    /// they have an alt text, so that text-only exports stay readable by
+    /// screen readers, and dropped otherwise.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_content_raw(
+    ///     r#"<p>Once upon a <em>time</em>…</p><p><img src="map.png" alt="A map"></p>"#,
+    /// );
+    /// assert_eq!(
+    ///     chapter.content_as_text(),
+    ///     "Once upon a time…\n\n[image: A map]"
+    /// );
+    /// ```
+    pub fn content_as_text(&self) -> String {
+        utils::html_to_text(&self.content)
+    }
+
+    /// Returns the chapter as Markdown, its title being the top-level
+    /// heading. Paragraphs, headings, emphasis, links, images, horizontal
+    /// rules & block quotes are converted; other elements are rendered as
+    /// their text.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let mut chapter = Chapter::default();
+    /// chapter.set_index(1);
+    /// chapter.set_content_raw("<p>Once upon a <em>time</em>…</p><hr><p>The end.</p>");
+    /// assert_eq!(
+    ///     chapter.to_markdown(),
+    ///     "# Chapter 1\n\nOnce upon a *time*…\n\n---\n\nThe end."
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let title =
+            backends::chapter_title_or_untitled(self.title.as_deref().unwrap_or(""), self.index);
+        let content = utils::html_to_markdown(&self.content);
+        format!("# {}\n\n{content}", utils::escape_markdown(&title))
+            .trim_end()
+            .to_string()
+    }
+
+    /// Returns the hexadecimal SHA-256 digest of the chapter's content. It can
+    /// be stored to later detect whether a chapter has been changed upstream.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let chapter = Chapter::default();
+    /// assert_eq!(
+    ///     chapter.content_hash(),
+    ///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    /// );
+    /// ```
+    pub fn content_hash(&self) -> String {
+        format!("{:x}", Sha256::digest(self.content.as_bytes()))
+    }
+
+    /// Checks that the chapter's URLs (both [`Chapter::chapter_url`] and
+    /// [`Chapter::fiction_url`]) are well-formed absolute URLs, which may not
+    /// be the case of manually edited chapters.
+    ///
+    /// ```rust
+    /// use libwebnovel::Chapter;
+    /// let chapter = Chapter::default();
+    /// assert!(chapter.validate_urls().is_err());
+    /// ```
+    pub fn validate_urls(&self) -> Result<(), ChapterParseError> {
+        for (name, value) in [
+            ("chapter url", &self.chapter_url),
+            ("fiction url", &self.fiction_url),
+        ] {
+            let url = Url::parse(value)
+                .map_err(|e| ChapterParseError::new(format!("Invalid {name} {value:?}: {e}")))?;
+            if !url.has_host() {
+                return Err(ChapterParseError::new(format!(
+                    "Invalid {name} {value:?}: no host"
+                )));
+            }
+        }
+        Ok(())
     }
 
     /// Add a key/value pair to the chapter's metadata
     pub fn add_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
         self.metadata.insert(key.into(), value.into());
     }
+
+    /// Returns the non-metadata fields of the chapter header, one `key: value`
+    /// per line.
+    fn header_fields(&self) -> String {
+        let mut s = String::new();
+        s.push_str(&format!("index: {}\n", self.index));
+        s.push_str(&format!("chapter_url: {}\n", self.chapter_url));
+        s.push_str(&format!("fiction_url: {}\n", self.fiction_url));
+        s.push_str(&format!(
+            "published_at: {}\n",
+            if let Some(dt) = self.published_at {
+                dt.to_rfc3339()
+            } else {
+                "not_found".to_string()
+            }
+        ));
+        if let Some(dt) = self.updated_at {
+            s.push_str(&format!("updated_at: {}\n", dt.to_rfc3339()));
+        }
+        s
+    }
+
+    /// Serializes the chapter in a more compact form than its [`Display`]
+    /// implementation: metadata fits on a single line (as `key="value"` pairs,
+    /// sorted by key) and the content is not surrounded by newlines. The
+    /// result can be parsed back using [`Chapter::from_str`].
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    ///
+    /// use libwebnovel::Chapter;
+    /// let chapter = Chapter::from_str(
+    ///     r#"<!--
+    /// index: 1
+    /// chapter_url: https://fiction.url/chapter-1
+    /// fiction_url: https://fiction.url
+    /// published_at: not_found
+    /// metadata:
+    ///   authors: Ye Fei Ran, 叶斐然
+    /// -->
+    /// <div class="content">
+    /// <p>content</p>
+    /// </div>"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     chapter.to_string_compact(),
+    ///     r#"<!--
+    /// index: 1
+    /// chapter_url: https://fiction.url/chapter-1
+    /// fiction_url: https://fiction.url
+    /// published_at: not_found
+    /// metadata: authors="Ye Fei Ran, 叶斐然"
+    /// -->
+    /// <div class="content"><p>content</p></div>"#
+    /// );
+    /// ```
+    pub fn to_string_compact(&self) -> String {
+        let mut s = String::new();
+        s.push_str("<!--\n");
+        s.push_str(&self.header_fields());
+        if !self.metadata.is_empty() {
+            let mut metadata: Vec<(&String, &String)> = self.metadata.iter().collect();
+            metadata.sort();
+            let metadata: Vec<String> = metadata
+                .into_iter()
+                .map(|(key, value)| format!("{}=\"{}\"", key, escape_metadata_value(value)))
+                .collect();
+            s.push_str(&format!("metadata: {}\n", metadata.join(" ")));
+        }
+        s.push_str("-->\n");
+        if let Some(title) = &self.title {
+            s.push_str(&format!("<h1 class=\"mainTitle\">{}</h1>\n", title));
+        }
+        if let Some(notes) = &self.author_notes_start {
+            s.push_str(&format!(
+                "<div class=\"authorNotesStart\">{}</div>\n",
+                notes
+            ));
+        }
+        s.push_str(&format!("<div class=\"content\">{}</div>", self.content));
+        if let Some(notes) = &self.author_notes_end {
+            s.push_str(&format!("\n<div class=\"authorNotesEnd\">{}</div>", notes));
+        }
+        s
+    }
+}
+
+/// Escapes a metadata value so it can be written between double quotes on a
+/// single line.
+fn escape_metadata_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Parses single-line metadata, as written by [`Chapter::to_string_compact`]:
+/// `key1="value1" key2="value2"`.
+fn parse_inline_metadata(s: &str) -> Result<HashMap<String, String>, ChapterParseError> {
+    let mut metadata = HashMap::new();
+    let mut chars = s.trim().chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+        let key: String = chars.by_ref().take_while(|c| *c != '=').collect();
+        if chars.next() != Some('"') {
+            return Err(ChapterParseError::new(format!(
+                "Invalid metadata: expected a quoted value for key \"{}\"",
+                key
+            )));
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next() {
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => {
+                    return Err(ChapterParseError::new(format!(
+                        "Invalid metadata: unterminated value for key \"{}\"",
+                        key
+                    )))
+                }
+            }
+        }
+        metadata.insert(key.trim().to_string(), value);
+    }
+    Ok(metadata)
+}
+
+/// The direction in which a text is written, see [`Chapter::text_direction`].
+/// Maps to the values of the HTML `dir` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Left-to-right, such as latin scripts
+    Ltr,
+    /// Right-to-left, such as arabic or hebrew scripts
+    Rtl,
+    /// Could not be determined
+    Auto,
+}
+
+impl Display for TextDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextDirection::Ltr => write!(f, "ltr"),
+            TextDirection::Rtl => write!(f, "rtl"),
+            TextDirection::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// Tells whether the given character belongs to a right-to-left script.
+fn is_rtl_char(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}' // hebrew, arabic, syriac, thaana, n'ko, samaritan…
+        | '\u{FB1D}'..='\u{FDFF}' // hebrew & arabic presentation forms
+        | '\u{FE70}'..='\u{FEFF}' // arabic presentation forms B
+        | '\u{10800}'..='\u{10FFF}' // various historic RTL scripts
+        | '\u{1E800}'..='\u{1EFFF}' // mende kikakui, adlam, arabic mathematical symbols…
+    )
+}
+
+/// Metadata about a fiction, as returned by [`Backend::metadata`].
+#[derive(Getters, Setters, Default, Debug, Clone, PartialEq)]
+pub struct FictionMetadata {
+    /// Where can the fiction be found?
+    #[getset(get = "pub", set)]
+    url: String,
+    /// Title of the fiction.
+    #[getset(get = "pub", set)]
+    title: String,
+    /// Authors of the fiction.
+    #[getset(get = "pub", set)]
+    authors: Vec<String>,
+    /// URL of the fiction's cover image, if any.
+    #[getset(get = "pub", set)]
+    cover_url: Option<String>,
+    /// Number of chapters of the fiction.
+    #[getset(get = "pub", set)]
+    chapter_count: usize,
+    /// Language of the fiction, as a BCP-47 tag, if it could be found. See
+    /// [`Backend::language`].
+    #[getset(get = "pub", set)]
+    language: Option<String>,
+}
+
+/// A cover image, along with the URL it was downloaded from.
+#[derive(Getters, Setters, Default, Debug, Clone, PartialEq)]
+pub struct CoverImage {
+    /// Where the image was downloaded from.
+    #[getset(get = "pub", set)]
+    url: String,
+    /// Content of the image file.
+    #[getset(get = "pub", set)]
+    bytes: Vec<u8>,
+}
+
+/// Everything about a fiction: its metadata, its cover & all of its chapters
+/// in reading order, as returned by [`Backends::download_full`].
+#[derive(Getters, Setters, Default, Debug, Clone, PartialEq)]
+pub struct FictionPackage {
+    /// Metadata of the fiction.
+    #[getset(get = "pub", set)]
+    metadata: FictionMetadata,
+    /// Cover of the fiction, if it has one.
+    #[getset(get = "pub", set)]
+    cover: Option<CoverImage>,
+    /// All the chapters of the fiction, in reading order.
+    #[getset(get = "pub", set)]
+    chapters: Vec<Chapter>,
+}
+
+/// What is known about a chapter without downloading it, as returned by
+/// [`Backend::get_chapter_info`].
+#[derive(Getters, Setters, CopyGetters, Default, Debug, Clone, PartialEq)]
+pub struct ChapterInfo {
+    /// Index of the chapter.
+    #[getset(get = "pub", set)]
+    index: usize,
+    /// Title of the chapter, if any.
+    #[getset(get = "pub", set)]
+    title: Option<String>,
+    /// Where can this chapter be found, if known?
+    #[getset(get = "pub", set)]
+    chapter_url: Option<String>,
+    /// Date this chapter was published, if known.
+    #[getset(get = "pub", set)]
+    published_at: Option<DateTime<Utc>>,
+    /// Whether the chapter is locked (behind a paywall, for instance). Always
+    /// `false` for backends unable to tell.
+    #[getset(get_copy = "pub", set)]
+    is_locked: bool,
+}
+
+/// A volume (or arc, book…) of a fiction, grouping consecutive chapters. See
+/// [`Backend::get_volumes`].
+#[derive(Getters, Setters, Default, Debug, Clone, PartialEq)]
+pub struct Volume {
+    /// Title of the volume, if the site gives one.
+    #[getset(get = "pub", set)]
+    title: Option<String>,
+    /// The chapters of the volume, in reading order.
+    #[getset(get = "pub", set)]
+    chapters: Vec<ChapterInfo>,
 }
 
 /// Returned when parsing a chapter fails.
@@ -316,29 +1061,43 @@ impl FromStr for Chapter {
         let mut in_content = false;
         let mut content = String::new();
 
+        // Header markers and keys are matched on trimmed lines, so that
+        // whitespace variations (CRLF line endings, trailing spaces, tab
+        // indentation…) introduced while editing a stored chapter are
+        // tolerated.
         for line in s.lines() {
             trace!("line: {}", line);
-            if line.starts_with("<!--") {
+            let trimmed = line.trim();
+            if in_content {
+                content.push_str(&format!("{}\n", line));
+                continue;
+            }
+            if trimmed.starts_with("<!--") {
                 in_chapter_data = true;
                 debug!("found chapter data start");
                 continue;
-            } else if line.starts_with("-->") {
+            } else if trimmed.starts_with("-->") {
                 in_chapter_data = false;
                 debug!("found chapter data end");
                 continue;
             }
 
             if in_chapter_data {
-                if line.starts_with("metadata:") {
-                    debug!("found metadata start");
-                    in_metadata = true;
+                if let Some(inline_metadata) = trimmed.strip_prefix("metadata:") {
+                    if inline_metadata.trim().is_empty() {
+                        debug!("found metadata start");
+                        in_metadata = true;
+                    } else {
+                        debug!("found inline metadata");
+                        metadata.extend(parse_inline_metadata(inline_metadata)?);
+                    }
                     continue;
                 }
-                if !line.starts_with("  ") && in_metadata {
+                if !line.starts_with([' ', '\t']) && in_metadata {
                     debug!("found metadata end");
                     in_metadata = false;
                 }
-                let parts: Vec<&str> = line.trim().splitn(2, ':').collect();
+                let parts: Vec<&str> = trimmed.splitn(2, ':').collect();
                 if parts.len() == 2 {
                     let key = parts[0].trim();
                     let value = parts[1].trim();
@@ -350,13 +1109,13 @@ impl FromStr for Chapter {
                         chapter_data.insert(key.to_string(), value.to_string());
                     }
                 }
-            } else if let Some(title) = line.strip_prefix("<h1 class=\"mainTitle\">") {
+            } else if let Some(title) = trimmed.strip_prefix("<h1 class=\"mainTitle\">") {
                 chapter.set_title(Some(title.trim_end_matches("</h1>").to_string()));
-            } else if line.starts_with("<div class=\"content\">") {
-                content.push_str("<div class=\"content\">");
+            } else if trimmed.starts_with("<div class=\"content\">")
+                || trimmed.starts_with("<div class=\"authorNotesStart\">")
+            {
+                content.push_str(&format!("{}\n", trimmed));
                 in_content = true;
-            } else if in_content {
-                content.push_str(&format!("{}\n", line));
             }
         }
         chapter.set_index(
@@ -393,34 +1152,47 @@ impl FromStr for Chapter {
                 Some(DateTime::parse_from_rfc3339(s).ok()?.with_timezone(&Utc))
             }
         }));
-        chapter.set_metadata(metadata);
-        chapter.set_content(
-            Html::parse_fragment(&content)
-                .select(&Selector::parse("div.content").unwrap())
-                .nth(0)
-                .unwrap()
-                .inner_html(),
+        chapter.set_updated_at(
+            chapter_data
+                .get("updated_at")
+                .and_then(|s| Some(DateTime::parse_from_rfc3339(s).ok()?.with_timezone(&Utc))),
         );
+        chapter.set_metadata(metadata);
+        // Only top-level blocks are considered, since the author notes may
+        // contain anything
+        let content = Html::parse_fragment(&content);
+        let block = |class: &str| {
+            content
+                .select(&Selector::parse(&format!("html > div.{class}")).unwrap())
+                .next()
+                .map(|block| block.inner_html().trim().to_string())
+        };
+        chapter.set_author_notes_start(block("authorNotesStart"));
+        chapter.set_author_notes_end(block("authorNotesEnd"));
+        chapter.set_content(block("content").unwrap());
         Ok(chapter)
     }
 }
 
+/// Parses the given HTML fragment, and returns it well-formed.
+fn normalize_fragment(html: &str) -> String {
+    Html::parse_fragment(html)
+        .html()
+        .strip_prefix("<html>")
+        .unwrap()
+        .strip_suffix("</html>")
+        .unwrap()
+        .trim()
+        .to_string()
+}
+
 /// Implement [`Display`] for [`Chapter`] (and consequentially, [`ToString`]).
+/// See [`Chapter::to_string_compact`] for a more compact alternative.
 impl Display for Chapter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = String::new();
         s.push_str("<!--\n");
-        s.push_str(&format!("index: {}\n", self.index));
-        s.push_str(&format!("chapter_url: {}\n", self.chapter_url));
-        s.push_str(&format!("fiction_url: {}\n", self.fiction_url));
-        s.push_str(&format!(
-            "published_at: {}\n",
-            if let Some(dt) = self.published_at {
-                dt.to_rfc3339()
-            } else {
-                "not_found".to_string()
-            }
-        ));
+        s.push_str(&self.header_fields());
         if !self.metadata.is_empty() {
             s.push_str("metadata:\n");
             for (key, value) in &self.metadata {
@@ -431,10 +1203,22 @@ impl Display for Chapter {
         if let Some(title) = &self.title {
             s.push_str(&format!("<h1 class=\"mainTitle\">{}</h1>\n", title));
         }
+        if let Some(notes) = &self.author_notes_start {
+            s.push_str(&format!(
+                "<div class=\"authorNotesStart\">\n{}\n</div>\n",
+                notes
+            ));
+        }
         s.push_str(&format!(
             "<div class=\"content\">\n{}\n</div>",
             self.content
         ));
+        if let Some(notes) = &self.author_notes_end {
+            s.push_str(&format!(
+                "\n<div class=\"authorNotesEnd\">\n{}\n</div>",
+                notes
+            ));
+        }
         write!(f, "{}", s)
     }
 }
@@ -443,10 +1227,13 @@ impl Display for Chapter {
 mod tests {
     use std::str::FromStr;
 
+    use chrono::DateTime;
     use indoc::indoc;
+    use scraper::{Html, Selector};
     use test_log::test;
 
-    use crate::Chapter;
+    use crate::test_support::MockServer;
+    use crate::{Chapter, HttpClient, TextDirection};
 
     #[test]
     fn test_chapter_display() {
@@ -491,11 +1278,91 @@ mod tests {
             .metadata
             .insert("authors".to_string(), "Ye Fei Ran, 叶斐然".to_string());
         chapter.set_content("<p>test content</p>".to_string());
+        chapter.set_raw_content(Some("<p class=\"x7fq2b\">test content</p>".to_string()));
+        let s = chapter.to_string();
+        let chapter_2 = Chapter::from_str(&s).unwrap();
+        assert_eq!(chapter, chapter_2);
+    }
+
+    #[test]
+    fn test_chapter_updated_at() {
+        let mut chapter = Chapter::default();
+        chapter.set_index(1);
+        chapter.set_chapter_url("https://chapter.url/".to_string());
+        chapter.set_fiction_url("https://fiction.url".to_string());
+        chapter.set_published_at(DateTime::from_timestamp(1700000000, 0));
+        chapter.set_updated_at(DateTime::from_timestamp(1700086400, 0));
+        chapter.set_content("<p>Edited content</p>".to_string());
         let s = chapter.to_string();
+        assert!(s.contains(
+            "published_at: 2023-11-14T22:13:20+00:00\nupdated_at: 2023-11-15T22:13:20+00:00\n"
+        ));
+        assert_eq!(Chapter::from_str(&s).unwrap(), chapter);
+        assert_eq!(
+            Chapter::from_str(&chapter.to_string_compact()).unwrap(),
+            chapter
+        );
+    }
+
+    #[test]
+    fn test_chapter_to_string_compact_and_back() {
+        let mut chapter = Chapter::default();
+        chapter.set_title(Some("title".to_string()));
+        chapter.set_chapter_url("https://chapter.url/".to_string());
+        chapter.set_fiction_url("https://fiction.url".to_string());
+        chapter.set_index(1);
+        chapter
+            .metadata
+            .insert("authors".to_string(), "Ye Fei Ran, 叶斐然".to_string());
+        chapter
+            .metadata
+            .insert("tricky".to_string(), r#"a "quoted" \ value"#.to_string());
+        chapter.set_content("<p>test content</p>\n<p>more content</p>".to_string());
+        let s = chapter.to_string_compact();
+        assert_eq!(s.lines().filter(|l| l.starts_with("metadata:")).count(), 1);
+        assert!(!s.contains("\n  "));
         let chapter_2 = Chapter::from_str(&s).unwrap();
         assert_eq!(chapter, chapter_2);
     }
 
+    /// Builds the string representation of a chapter, with configurable
+    /// whitespace around its header.
+    fn chapter_string(newline: &str, indent: &str, trailing: &str) -> String {
+        [
+            &format!("<!--{trailing}"),
+            &format!("index: 1{trailing}"),
+            "chapter_url: https://chapter.url/",
+            "fiction_url: https://fiction.url",
+            &format!("published_at: not_found{trailing}"),
+            &format!("metadata:{trailing}"),
+            &format!("{indent}authors: Ye Fei Ran, 叶斐然{trailing}"),
+            &format!("{indent}chapter_id: 12{trailing}"),
+            &format!("-->{trailing}"),
+            &format!("<h1 class=\"mainTitle\">title</h1>{trailing}"),
+            &format!("<div class=\"content\">{trailing}"),
+            "<p>Test content</p>",
+            "</div>",
+        ]
+        .join(newline)
+    }
+
+    #[test]
+    fn test_chapter_from_str_whitespace_variations() {
+        let expected = Chapter::from_str(&chapter_string("\n", "  ", "")).unwrap();
+        assert_eq!(expected.metadata().len(), 2);
+        for (newline, indent, trailing) in [
+            ("\r\n", "  ", ""),
+            ("\n", "\t", ""),
+            ("\n", "  ", "  "),
+            ("\n", "  ", "\t"),
+            ("\r\n", "\t", " "),
+        ] {
+            let s = chapter_string(newline, indent, trailing);
+            let chapter = Chapter::from_str(&s).unwrap();
+            assert_eq!(chapter, expected, "failed to parse {:?}", s);
+        }
+    }
+
     #[test]
     fn test_chapter_no_metadata() {
         let mut chapter = Chapter::default();
@@ -509,4 +1376,237 @@ mod tests {
         let s = chapter.to_string();
         assert!(!s.contains("metadata:"));
     }
+
+    #[test]
+    fn test_chapter_minify_content() {
+        let mut chapter = Chapter::default();
+        chapter.set_content(indoc! {r#"
+            <div class="chapter-inner">
+                <p class="">It was a   dark and <em>stormy</em> night.</p>
+                <!-- some comment -->
+                <p style="">The rain fell in torrents.</p>
+                <pre>  fn main() {
+                    println!("hello");
+                }</pre>
+            </div>
+        "#});
+        let text = |chapter: &Chapter| {
+            Html::parse_fragment(chapter.content())
+                .root_element()
+                .text()
+                .flat_map(|text| text.split_whitespace())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let pre = |chapter: &Chapter| {
+            Html::parse_fragment(chapter.content())
+                .select(&Selector::parse("pre").unwrap())
+                .next()
+                .unwrap()
+                .html()
+        };
+        let (original_text, original_pre, original_len) =
+            (text(&chapter), pre(&chapter), chapter.content().len());
+        chapter.minify_content();
+        assert_eq!(text(&chapter), original_text);
+        assert_eq!(pre(&chapter), original_pre);
+        assert!(chapter.content().len() < original_len);
+        assert!(!chapter.content().contains("class=\"\""));
+    }
+
+    #[test]
+    fn test_chapter_set_content_raw() {
+        let content = "<p class=\"\">  unclosed <b>bold\n<br/>";
+        let mut chapter = Chapter::default();
+        chapter.set_content_raw(content);
+        assert_eq!(chapter.content(), content);
+        chapter.set_content(content);
+        assert_ne!(chapter.content(), content);
+    }
+
+    #[test]
+    fn test_chapter_text_direction() {
+        let direction = |content: &str| {
+            let mut chapter = Chapter::default();
+            chapter.set_content(content);
+            chapter.text_direction()
+        };
+        assert_eq!(
+            direction("<p>كان يا ما كان في قديم الزمان</p><p>Chapter 1</p>"),
+            TextDirection::Rtl
+        );
+        assert_eq!(direction("<p>היה היה פעם</p>"), TextDirection::Rtl);
+        assert_eq!(
+            direction("<p>Once upon a time, <span dir=\"rtl\">كان</span></p>"),
+            TextDirection::Ltr
+        );
+        assert_eq!(direction("<p>1234 …</p>"), TextDirection::Auto);
+    }
+
+    #[test]
+    fn test_chapter_image_alts() {
+        let mut chapter = Chapter::default();
+        chapter.set_content(indoc! {r#"
+            <p>The map read:
+            <img src="map.png" alt="A map of  the city">
+            </p>
+            <div><img src="divider.png"><img alt=""></div>
+            <p>She left.<br>Again.</p>"#});
+        assert_eq!(
+            chapter.image_alts(),
+            vec![
+                (
+                    "map.png".to_string(),
+                    Some("A map of  the city".to_string())
+                ),
+                ("divider.png".to_string(), None),
+                (String::new(), Some(String::new())),
+            ]
+        );
+        assert_eq!(
+            chapter.content_as_text(),
+            "The map read: [image: A map of  the city]\n\nShe left.\nAgain."
+        );
+    }
+
+    #[test]
+    fn test_chapter_collect_images() {
+        let server = MockServer::start(&[
+            ("/images/map.png", 200, "map"),
+            ("/chapter-1/divider.png", 200, "divider"),
+        ]);
+        let content = format!(
+            r#"<p><img src="/images/map.png"><img src="divider.png"></p>
+            <p><img src="{}"><img src="data:image/png;base64,AAAA"></p>
+            <p><img src="/images/missing.png"></p>"#,
+            server.url("/images/map.png")
+        );
+        let mut chapter = Chapter::default();
+        chapter.set_chapter_url(server.url("/chapter-1/"));
+        chapter.set_content(&content);
+        assert!(chapter.images().is_empty());
+
+        chapter.collect_images();
+        assert_eq!(
+            chapter.images(),
+            &[
+                server.url("/images/map.png"),
+                server.url("/chapter-1/divider.png"),
+                server.url("/images/missing.png"),
+            ]
+        );
+        assert_eq!(
+            chapter.download_images(&HttpClient::default()),
+            vec![
+                (server.url("/images/map.png"), b"map".to_vec()),
+                (server.url("/chapter-1/divider.png"), b"divider".to_vec()),
+            ]
+        );
+
+        // the images follow the content, however it is changed
+        let mut uncollected = chapter.clone();
+        uncollected.images.clear();
+        uncollected.collect_images = false;
+        assert_eq!(chapter, uncollected);
+        chapter.append_footer(&format!(
+            r#"<p><img src="{}"></p>"#,
+            server.url("/footer.png")
+        ));
+        assert_eq!(chapter.images().last(), Some(&server.url("/footer.png")));
+        chapter.strip_phrases(&[]);
+        chapter.minify_content();
+        assert_eq!(chapter.images().len(), 4);
+        chapter.set_content_raw("<p>No images</p>");
+        assert!(chapter.images().is_empty());
+    }
+
+    #[test]
+    fn test_chapter_to_markdown() {
+        let mut chapter = Chapter::default();
+        chapter.set_title(Some("1. Good *Morning* Brother".to_string()));
+        chapter.set_content(indoc! {r#"
+            <h2>Part <b>one</b></h2>
+            <p>He was <strong>very</strong> <em> tired</em>, and<i></i> read
+            <a href="https://example.com/a b">the <b>manual</b></a>.<br>Again.</p>
+            <div><p>Nested <span>paragraph</span></p><div><p>Deeper_one</p></div></div>
+            <blockquote><p>Quoted</p><p>twice</p></blockquote>
+            <hr>
+            <p><img src="map.png" alt="A map"></p>
+            <p>1. Good morning</p><p>- Who?<br># 2) Me.</p><p>3 > 2</p>"#});
+        assert_eq!(
+            chapter.to_markdown(),
+            indoc! {r#"
+                # 1. Good \*Morning\* Brother
+
+                ## Part **one**
+
+                He was **very** *tired*, and read [the **manual**](https://example.com/a%20b).\
+                Again.
+
+                Nested paragraph
+
+                Deeper\_one
+
+                > Quoted
+                >
+                > twice
+
+                ---
+
+                ![A map](map.png)
+
+                1\. Good morning
+
+                \- Who?\
+                \# 2) Me.
+
+                3 > 2"#}
+        );
+
+        let mut chapter = Chapter::default();
+        chapter.set_index(3);
+        assert_eq!(chapter.to_markdown(), "# Chapter 3");
+    }
+
+    #[test]
+    fn test_chapter_header_footer() {
+        let mut chapter = Chapter::default();
+        chapter.set_index(1);
+        chapter.set_chapter_url("https://example.com/fiction/chapter-1".to_string());
+        chapter.set_fiction_url("https://example.com/fiction".to_string());
+        chapter.set_content("<p>Once upon a time…</p>");
+        chapter.prepend_header("<p class=\"source\">Source: https://example.com</p>");
+        chapter.append_footer("<div class=\"retrieved\"><p>Retrieved on 2024-09-30");
+        assert_eq!(
+            chapter.content(),
+            indoc! {r#"
+            <p class="source">Source: https://example.com</p>
+            <p>Once upon a time…</p>
+            <div class="retrieved"><p>Retrieved on 2024-09-30</p></div>"#}
+        );
+        let parsed = Chapter::from_str(&chapter.to_string()).unwrap();
+        assert_eq!(parsed, chapter);
+    }
+
+    #[test]
+    fn test_chapter_validate_urls() {
+        let mut chapter = Chapter::default();
+        chapter.set_chapter_url("https://example.com/fiction/chapter-1".to_string());
+        chapter.set_fiction_url("https://example.com/fiction".to_string());
+        assert!(chapter.validate_urls().is_ok());
+
+        for invalid_url in [
+            "/fiction/chapter-1",
+            "not a url",
+            "mailto:someone@example.com",
+        ] {
+            let mut invalid = chapter.clone();
+            invalid.set_chapter_url(invalid_url.to_string());
+            let e = invalid.validate_urls().unwrap_err();
+            assert!(e.to_string().contains("chapter url"), "{e}");
+        }
+        chapter.set_fiction_url("https//example.com/fiction".to_string());
+        let e = chapter.validate_urls().unwrap_err();
+        assert!(e.to_string().contains("fiction url"), "{e}");
+    }
 }
@@ -0,0 +1,341 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::backends::BackendError;
+use crate::{Chapter, ChapterInfo, FictionMetadata};
+
+/// Name of the manifest file written by
+/// [`Backends::export_manifest`][crate::Backends::export_manifest].
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// A machine-readable index of a downloaded fiction, written as
+/// [`MANIFEST_FILE_NAME`] alongside the chapter files. Sync tools can diff it
+/// against the live site.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FictionManifest {
+    /// Where can the fiction be found?
+    pub url: String,
+    /// Title of the fiction.
+    pub title: String,
+    /// Authors of the fiction.
+    pub authors: Vec<String>,
+    /// Language of the fiction, as a BCP-47 tag, if known.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Name of the backend the fiction was downloaded with.
+    pub backend_name: String,
+    /// Name of the cover image file, if it has been written.
+    pub cover: Option<String>,
+    /// URL of the cover image, if any, so that it can be fetched later when
+    /// it has not been written.
+    #[serde(default)]
+    pub cover_url: Option<String>,
+    /// The exported chapters, in reading order.
+    pub chapters: Vec<ManifestChapter>,
+}
+
+/// Options of
+/// [`Backends::export_manifest_with_options`][crate::Backends::export_manifest_with_options].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Whether to download & write the cover image. Skipping it saves a
+    /// request when only the text is wanted; its URL is still listed in the
+    /// manifest. Enabled by default.
+    pub include_cover: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            include_cover: true,
+        }
+    }
+}
+
+/// A chapter, as listed in a [`FictionManifest`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestChapter {
+    /// Index of the chapter.
+    pub index: usize,
+    /// Title of the chapter, if any.
+    pub title: Option<String>,
+    /// Where can this chapter be found?
+    pub url: String,
+    /// Date this chapter was published, if known.
+    pub published_at: Option<DateTime<Utc>>,
+    /// Date this chapter was last edited, if known.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// See [`Chapter::content_hash`].
+    pub content_hash: String,
+    /// Name of the file the chapter has been written to.
+    pub file: String,
+}
+
+impl From<&Chapter> for ManifestChapter {
+    fn from(chapter: &Chapter) -> Self {
+        Self {
+            index: *chapter.index(),
+            title: chapter.title().clone(),
+            url: chapter.chapter_url().clone(),
+            published_at: *chapter.published_at(),
+            updated_at: *chapter.updated_at(),
+            content_hash: chapter.content_hash(),
+            file: chapter_file_name(chapter),
+        }
+    }
+}
+
+/// A chapter, as stored in a chapter list cached by
+/// [`Backends::cache_chapter_list`][crate::Backends::cache_chapter_list].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct CachedChapterInfo {
+    index: usize,
+    title: Option<String>,
+    url: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    is_locked: bool,
+}
+
+impl From<&ChapterInfo> for CachedChapterInfo {
+    fn from(info: &ChapterInfo) -> Self {
+        Self {
+            index: *info.index(),
+            title: info.title().clone(),
+            url: info.chapter_url().clone(),
+            published_at: *info.published_at(),
+            is_locked: info.is_locked(),
+        }
+    }
+}
+
+impl From<CachedChapterInfo> for ChapterInfo {
+    fn from(cached: CachedChapterInfo) -> Self {
+        let mut info = ChapterInfo::default();
+        info.set_index(cached.index);
+        info.set_title(cached.title);
+        info.set_chapter_url(cached.url);
+        info.set_published_at(cached.published_at);
+        info.set_is_locked(cached.is_locked);
+        info
+    }
+}
+
+/// Writes the given chapter list to `path`, as JSON. See
+/// [`read_chapter_list`].
+pub(crate) fn write_chapter_list(
+    path: &Path,
+    chapters: &[ChapterInfo],
+) -> Result<(), BackendError> {
+    let cached: Vec<CachedChapterInfo> = chapters.iter().map(CachedChapterInfo::from).collect();
+    let file = fs::File::create(path)?;
+    serde_json::to_writer_pretty(file, &cached).map_err(std::io::Error::from)?;
+    Ok(())
+}
+
+/// Reads a chapter list previously written by
+/// [`Backends::cache_chapter_list`][crate::Backends::cache_chapter_list].
+pub fn read_chapter_list(path: &Path) -> Result<Vec<ChapterInfo>, BackendError> {
+    let file = fs::File::open(path)?;
+    let cached: Vec<CachedChapterInfo> =
+        serde_json::from_reader(file).map_err(std::io::Error::from)?;
+    Ok(cached.into_iter().map(ChapterInfo::from).collect())
+}
+
+/// Returns the name of the file a chapter is exported to.
+pub fn chapter_file_name(chapter: &Chapter) -> String {
+    format!("chapter-{:05}.html", chapter.index())
+}
+
+/// Returns the name of the file a cover is exported to, keeping the extension
+/// of its URL when there is a sensible one.
+fn cover_file_name(cover_url: &str) -> String {
+    let path = cover_url.split(['?', '#']).next().unwrap_or_default();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    match file_name.rsplit_once('.') {
+        Some((_, extension))
+            if (1..=4).contains(&extension.len())
+                && extension.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            format!("cover.{}", extension.to_lowercase())
+        }
+        _ => "cover".to_string(),
+    }
+}
+
+impl FictionManifest {
+    /// Writes the given chapters (see [`chapter_file_name`]) and cover image in
+    /// `dir`, along with a [`MANIFEST_FILE_NAME`] listing them, in the given
+    /// order. The cover is only written if the metadata has a cover URL. `dir`
+    /// is created if needed.
+    pub(crate) fn write(
+        dir: &Path,
+        metadata: &FictionMetadata,
+        backend_name: &str,
+        cover: Option<&[u8]>,
+        chapters: &[Chapter],
+    ) -> Result<Self, BackendError> {
+        fs::create_dir_all(dir)?;
+        let cover = match (cover, metadata.cover_url()) {
+            (Some(cover), Some(cover_url)) => {
+                let file_name = cover_file_name(cover_url);
+                fs::write(dir.join(&file_name), cover)?;
+                Some(file_name)
+            }
+            _ => None,
+        };
+        for chapter in chapters {
+            fs::write(dir.join(chapter_file_name(chapter)), chapter.to_string())?;
+        }
+        let manifest = Self {
+            url: metadata.url().clone(),
+            title: metadata.title().clone(),
+            authors: metadata.authors().clone(),
+            language: metadata.language().clone(),
+            backend_name: backend_name.to_string(),
+            cover,
+            cover_url: metadata.cover_url().clone(),
+            chapters: chapters.iter().map(ManifestChapter::from).collect(),
+        };
+        let manifest_file = fs::File::create(dir.join(MANIFEST_FILE_NAME))?;
+        serde_json::to_writer_pretty(manifest_file, &manifest).map_err(std::io::Error::from)?;
+        Ok(manifest)
+    }
+
+    /// Reads the manifest previously exported in `dir`.
+    pub fn read(dir: &Path) -> Result<Self, BackendError> {
+        let manifest_file = fs::File::open(dir.join(MANIFEST_FILE_NAME))?;
+        Ok(serde_json::from_reader(manifest_file).map_err(std::io::Error::from)?)
+    }
+
+    /// Reads the chapter files listed in this manifest from `dir`, in the
+    /// order of the manifest.
+    pub fn read_chapters(&self, dir: &Path) -> Result<Vec<Chapter>, BackendError> {
+        self.chapters
+            .iter()
+            .map(|listed| {
+                let file = fs::read_to_string(dir.join(&listed.file))?;
+                Chapter::from_str(&file).map_err(|e| {
+                    BackendError::parse_error_with_source(
+                        format!("Invalid chapter file {}", listed.file),
+                        e,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<&FictionManifest> for FictionMetadata {
+    fn from(manifest: &FictionManifest) -> Self {
+        let mut metadata = FictionMetadata::default();
+        metadata.set_url(manifest.url.clone());
+        metadata.set_title(manifest.title.clone());
+        metadata.set_authors(manifest.authors.clone());
+        metadata.set_cover_url(manifest.cover_url.clone());
+        metadata.set_chapter_count(manifest.chapters.len());
+        metadata.set_language(manifest.language.clone());
+        metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::str::FromStr;
+
+    use chrono::DateTime;
+
+    use crate::manifest::{
+        cover_file_name, read_chapter_list, write_chapter_list, FictionManifest, MANIFEST_FILE_NAME,
+    };
+    use crate::{Chapter, ChapterInfo, FictionMetadata};
+
+    fn chapter(index: usize) -> Chapter {
+        let mut chapter = Chapter::default();
+        chapter.set_index(index);
+        chapter.set_title(Some(format!("Chapter {index}")));
+        chapter.set_chapter_url(format!("https://example.com/fiction/chapter-{index}"));
+        chapter.set_fiction_url("https://example.com/fiction".to_string());
+        chapter.set_content(format!("<p>Content of chapter {index}</p>"));
+        chapter
+    }
+
+    #[test]
+    fn test_cover_file_name() {
+        assert_eq!(
+            cover_file_name("https://example.com/covers/1.JPG?time=1637247458"),
+            "cover.jpg"
+        );
+        assert_eq!(cover_file_name("https://example.com/covers/1"), "cover");
+        assert_eq!(
+            cover_file_name("https://example.com/covers/my.cover.of-doom"),
+            "cover"
+        );
+    }
+
+    #[test]
+    fn test_write_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut metadata = FictionMetadata::default();
+        metadata.set_url("https://example.com/fiction".to_string());
+        metadata.set_title("Fiction".to_string());
+        metadata.set_authors(vec!["Some One".to_string()]);
+        metadata.set_cover_url(Some("https://example.com/cover.png".to_string()));
+        let chapters: Vec<Chapter> = (1..=3).map(chapter).collect();
+
+        let manifest = FictionManifest::write(
+            dir.path(),
+            &metadata,
+            "test",
+            Some(b"not really a png".as_slice()),
+            &chapters,
+        )
+        .unwrap();
+        assert!(dir.path().join(MANIFEST_FILE_NAME).exists());
+        assert_eq!(manifest.cover, Some("cover.png".to_string()));
+        assert_eq!(
+            fs::read(dir.path().join("cover.png")).unwrap(),
+            b"not really a png"
+        );
+
+        let read_manifest = FictionManifest::read(dir.path()).unwrap();
+        assert_eq!(read_manifest, manifest);
+        assert_eq!(read_manifest.title, "Fiction");
+        assert_eq!(read_manifest.chapters.len(), chapters.len());
+        for (listed, chapter) in read_manifest.chapters.iter().zip(&chapters) {
+            assert_eq!(listed.index, *chapter.index());
+            assert_eq!(&listed.url, chapter.chapter_url());
+            assert_eq!(listed.content_hash, chapter.content_hash());
+            let written =
+                Chapter::from_str(&fs::read_to_string(dir.path().join(&listed.file)).unwrap())
+                    .unwrap();
+            assert_eq!(&written, chapter);
+        }
+    }
+
+    #[test]
+    fn test_chapter_list_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("chapters.json");
+        let chapters: Vec<ChapterInfo> = (1..=3)
+            .map(|index| {
+                let mut info = ChapterInfo::default();
+                info.set_index(index);
+                info.set_title(Some(format!("Chapter {index}")));
+                info.set_chapter_url(Some(format!("https://example.com/fiction/chapter-{index}")));
+                info.set_published_at(DateTime::from_timestamp(1700000000 + index as i64, 0));
+                info.set_is_locked(index == 3);
+                info
+            })
+            .collect();
+        write_chapter_list(&path, &chapters).unwrap();
+        assert_eq!(read_chapter_list(&path).unwrap(), chapters);
+        assert!(read_chapter_list(&dir.path().join("missing.json")).is_err());
+    }
+}
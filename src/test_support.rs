@@ -0,0 +1,100 @@
+//! Helpers to test backends against canned HTTP responses instead of the
+//! actual websites, enabled by the `test-support` feature. Backends only fetch
+//! the URLs they are given, so pointing one at a [`MockServer`] is a matter of
+//! building it from [`MockServer::url`]:
+//!
+//! ```rust
+//! use libwebnovel::backends::RoyalRoad;
+//! use libwebnovel::test_support::MockServer;
+//! use libwebnovel::Backend;
+//!
+//! let server = MockServer::start(&[(
+//!     "/fiction/21220/mother-of-learning",
+//!     200,
+//!     r#"<div class="row fic-header"><div class="fic-title"><div class="col">
+//!     <h1 class="font-white">Mother of Learning</h1>
+//!     </div></div></div>"#,
+//! )]);
+//! let backend = RoyalRoad::new(&server.url("/fiction/21220/mother-of-learning")).unwrap();
+//! assert_eq!(backend.title().unwrap(), "Mother of Learning");
+//! ```
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+
+/// A minimal HTTP server, serving canned responses on localhost so that
+/// network code can be tested without relying on the actual websites.
+pub struct MockServer {
+    address: String,
+}
+
+impl MockServer {
+    /// Starts serving the given routes, as `path -> (status code, body)`, until
+    /// the end of the test process. Unknown paths get a `404`. For redirections
+    /// (`3xx` status codes), the body is the URL to redirect to.
+    pub fn start(routes: &[(&str, u16, &str)]) -> Self {
+        let routes: HashMap<String, (u16, String)> = routes
+            .iter()
+            .map(|(path, status, body)| (path.to_string(), (*status, body.to_string())))
+            .collect();
+        Self::start_with_handler(move |path, _| {
+            routes
+                .get(path)
+                .cloned()
+                .unwrap_or((404, "not found".to_string()))
+        })
+    }
+
+    /// Starts answering requests with the given handler, which is given the
+    /// path & the headers (with lowercase names) of each request, and returns
+    /// the status code & body of the response (or the URL to redirect to, for
+    /// `3xx` status codes).
+    pub fn start_with_handler(
+        handler: impl Fn(&str, &HashMap<String, String>) -> (u16, String) + Send + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                let mut headers = HashMap::new();
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                    if let Some((name, value)) = line.split_once(':') {
+                        headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+                    }
+                    line.clear();
+                }
+                let path = request_line.split(' ').nth(1).unwrap_or("/");
+                let (status, body) = handler(path, &headers);
+                let _ = if (300..400).contains(&status) {
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} MOCK\r\nLocation: {body}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    )
+                } else {
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} MOCK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                };
+            }
+        });
+        Self { address }
+    }
+
+    /// Returns the absolute URL of the given path on this server.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.address)
+    }
+}
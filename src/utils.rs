@@ -1,38 +1,1666 @@
-use std::sync::LazyLock;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+use html_escape::{encode_double_quoted_attribute, encode_text};
 use log::{error, warn};
+use regex::Regex;
 use reqwest::blocking::{Client, ClientBuilder, Response};
-use reqwest::{IntoUrl, StatusCode};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{header, IntoUrl, Proxy, StatusCode, Url};
+use scraper::{ElementRef, Html, Node, Selector};
 
 use crate::backends::BackendError;
 
 static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
-static HTTP_CLIENT: LazyLock<Client> =
-    LazyLock::new(|| ClientBuilder::new().user_agent(USER_AGENT).build().unwrap());
+static DEFAULT_CLIENT: LazyLock<Client> =
+    LazyLock::new(|| ClientConfig::default().build_client().unwrap());
+/// The client used when no [`ClientConfig`] is given
+pub(crate) static HTTP_CLIENT: HttpClient = HttpClient {
+    client: None,
+    #[cfg(feature = "async")]
+    async_client: None,
+    custom_user_agent: false,
+    rate_limiter: None,
+};
+#[cfg(feature = "async")]
+static DEFAULT_ASYNC_CLIENT: LazyLock<reqwest::Client> =
+    LazyLock::new(|| ClientConfig::default().build_async_client().unwrap());
 
-/// Just a custom get that sets a correct User-Agent & follows redirects
+static CIRCUIT_BREAKER: LazyLock<Mutex<CircuitBreaker>> =
+    LazyLock::new(|| Mutex::new(CircuitBreaker::new(CircuitBreakerConfig::default())));
+
+/// Configures the HTTP client a backend sends its requests with. See
+/// [`Backend::new_with_config`][crate::Backend::new_with_config].
+///
+/// ```rust
+/// use libwebnovel::ClientConfig;
+/// let config = ClientConfig {
+///     user_agent: "Mozilla/5.0 (X11; Linux x86_64; rv:130.0) Gecko/20100101 Firefox/130.0"
+///         .to_string(),
+///     headers: vec![("Accept-Language".to_string(), "en-US".to_string())],
+///     ..Default::default()
+/// };
+/// assert!(config.build().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// User-Agent sent with every request. Defaults to
+    /// `libwebnovel/<version>`, which some sites reject. Unlike the default
+    /// one, a custom User-Agent is never replaced by the ones of
+    /// [`UserAgentRotation`].
+    pub user_agent: String,
+    /// Additional headers sent with every request, as `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+    /// Timeout of each request. `None` (the default) keeps the one of
+    /// `reqwest`, 30 seconds.
+    pub timeout: Option<Duration>,
+    /// Minimum delay between two requests to the same host. No delay by
+    /// default.
+    pub rate_limit: RateLimit,
+    /// URL of the proxy every request is sent through, such as
+    /// `http://proxy.example.com:3128`. `None` (the default) uses the proxies
+    /// of the environment (`HTTP_PROXY`, `HTTPS_PROXY`…), if any. SOCKS
+    /// proxies (`socks5h://127.0.0.1:9050` for Tor) need the `socks` feature
+    /// of `reqwest`.
+    pub proxy: Option<String>,
+}
+
+/// Spaces out the requests an [`HttpClient`] sends to each host, on top of
+/// the process-wide limit of [`set_rate_limit`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Minimum time between two successive requests to the same host. Zero
+    /// (the default) means no delay.
+    pub min_interval: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: USER_AGENT.to_string(),
+            headers: Vec::new(),
+            timeout: None,
+            rate_limit: RateLimit::default(),
+            proxy: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Builds the HTTP client described by this configuration. Returns
+    /// [`BackendError::InvalidClientConfig`] if the User-Agent, a header or
+    /// the proxy URL is not valid.
+    pub fn build(&self) -> Result<HttpClient, BackendError> {
+        // the default client is shared, along with its connection pool
+        if *self == ClientConfig::default() {
+            return Ok(HttpClient::default());
+        }
+        let min_interval = self.rate_limit.min_interval;
+        Ok(HttpClient {
+            client: Some(Arc::new(LazyClient::new(self.clone())?)),
+            #[cfg(feature = "async")]
+            async_client: Some(self.build_async_client()?),
+            custom_user_agent: self.user_agent != USER_AGENT,
+            rate_limiter: (!min_interval.is_zero()).then(|| {
+                Arc::new(Mutex::new(RateLimiter {
+                    interval: Some(min_interval),
+                    ..Default::default()
+                }))
+            }),
+        })
+    }
+
+    /// Returns the User-Agent & the additional headers to send with every
+    /// request.
+    fn default_headers(&self) -> Result<(HeaderValue, HeaderMap), BackendError> {
+        let invalid_header = |name: &str, e: &dyn std::error::Error| {
+            BackendError::InvalidClientConfig(format!("invalid {name} header: {e}"))
+        };
+        let user_agent = HeaderValue::from_str(&self.user_agent)
+            .map_err(|e| invalid_header(header::USER_AGENT.as_str(), &e))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let header_name =
+                HeaderName::from_bytes(name.as_bytes()).map_err(|e| invalid_header(name, &e))?;
+            let header_value =
+                HeaderValue::from_str(value).map_err(|e| invalid_header(name, &e))?;
+            headers.append(header_name, header_value);
+        }
+        Ok((user_agent, headers))
+    }
+
+    /// Returns the proxy to send every request through, if any.
+    fn proxy(&self) -> Result<Option<Proxy>, BackendError> {
+        self.proxy
+            .as_ref()
+            .map(|proxy| {
+                Proxy::all(proxy).map_err(|e| {
+                    BackendError::InvalidClientConfig(format!("invalid proxy {proxy}: {e}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Builds the underlying `reqwest` client.
+    fn build_client(&self) -> Result<Client, BackendError> {
+        let (user_agent, headers) = self.default_headers()?;
+        let mut builder = ClientBuilder::new()
+            .user_agent(user_agent)
+            .default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        builder
+            .build()
+            .map_err(|e| BackendError::InvalidClientConfig(e.to_string()))
+    }
+
+    /// Builds the underlying async `reqwest` client, configured like the
+    /// blocking one.
+    #[cfg(feature = "async")]
+    fn build_async_client(&self) -> Result<reqwest::Client, BackendError> {
+        let (user_agent, headers) = self.default_headers()?;
+        let mut builder = reqwest::ClientBuilder::new()
+            .user_agent(user_agent)
+            .default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        builder
+            .build()
+            .map_err(|e| BackendError::InvalidClientConfig(e.to_string()))
+    }
+}
+
+/// An HTTP client built from a [`ClientConfig`], which backends send their
+/// requests with. Clones share the same connection pool & rate limit.
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    /// The `reqwest` client, `None` for the default one
+    client: Option<Arc<LazyClient>>,
+    /// The async `reqwest` client, `None` for the default one
+    #[cfg(feature = "async")]
+    async_client: Option<reqwest::Client>,
+    /// Whether the User-Agent has been chosen by the user, in which case it
+    /// must be kept
+    custom_user_agent: bool,
+    /// Enforces [`ClientConfig::rate_limit`], if there is one
+    rate_limiter: Option<Arc<Mutex<RateLimiter>>>,
+}
+
+/// A blocking `reqwest` client, only built when first used: blocking clients
+/// can neither be built nor dropped within an async runtime, which async
+/// backends run in.
+#[derive(Debug)]
+struct LazyClient {
+    config: ClientConfig,
+    client: OnceLock<Client>,
+}
+
+impl LazyClient {
+    /// Makes sure a client can be built from `config`, without building it.
+    fn new(config: ClientConfig) -> Result<Self, BackendError> {
+        config.default_headers()?;
+        config.proxy()?;
+        Ok(Self {
+            config,
+            client: OnceLock::new(),
+        })
+    }
+
+    fn get(&self) -> Result<&Client, BackendError> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = self.config.build_client()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+}
+
+impl Default for HttpClient {
+    /// Returns the client used when no [`ClientConfig`] is given.
+    fn default() -> Self {
+        HTTP_CLIENT.clone()
+    }
+}
+
+/// Configures when requests to a host stop being sent because it keeps
+/// failing (connection errors or HTTP 5xx). See
+/// [`set_circuit_breaker_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failures after which requests to a host are
+    /// short-circuited. `0` disables the circuit breaker.
+    pub failure_threshold: usize,
+    /// Failures older than this are forgotten.
+    pub window: Duration,
+    /// For how long requests to a host are short-circuited once the threshold
+    /// has been reached.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+static USER_AGENT_ROTATION: LazyLock<Mutex<UserAgentRotation>> =
+    LazyLock::new(|| Mutex::new(UserAgentRotation::default()));
+
+/// Configures retrying requests answered with HTTP 403 (which often means the
+/// User-Agent has been blocked) with other User-Agents. See
+/// [`set_user_agent_rotation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAgentRotation {
+    /// Whether to retry with other User-Agents at all. Disabled by default.
+    pub enabled: bool,
+    /// User-Agents tried in turn after a 403, before giving up.
+    pub user_agents: Vec<String>,
+}
+
+impl Default for UserAgentRotation {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            user_agents: [
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:130.0) Gecko/20100101 Firefox/130.0",
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36",
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.0 Safari/605.1.15",
+            ]
+            .map(String::from)
+            .to_vec(),
+        }
+    }
+}
+
+/// Changes how requests answered with HTTP 403 are retried, for all requests.
+pub fn set_user_agent_rotation(rotation: UserAgentRotation) {
+    *USER_AGENT_ROTATION.lock().unwrap() = rotation;
+}
+
+/// Changes the configuration of the circuit breaker used for all requests.
+/// The failures already recorded are kept.
+pub fn set_circuit_breaker_config(config: CircuitBreakerConfig) {
+    CIRCUIT_BREAKER.lock().unwrap().config = config;
+}
+
+static RATE_LIMITER: LazyLock<Mutex<RateLimiter>> =
+    LazyLock::new(|| Mutex::new(RateLimiter::default()));
+
+/// Limits the number of requests sent to each host to `requests_per_second`,
+/// for all requests of the process: several backends (or several threads)
+/// downloading from the same site share the limit. `None` (the default)
+/// removes the limit, as do non-positive values.
+pub fn set_rate_limit(requests_per_second: Option<f64>) {
+    RATE_LIMITER.lock().unwrap().interval = requests_per_second
+        .filter(|rps| *rps > 0.0)
+        .map(|rps| Duration::from_secs_f64(1.0 / rps));
+}
+
+/// Spaces out the requests sent to each host. See [`set_rate_limit`] &
+/// [`RateLimit`].
+#[derive(Debug, Default)]
+struct RateLimiter {
+    /// Minimum time between two requests to the same host, if any
+    interval: Option<Duration>,
+    /// When the next request to each host may be sent, for hosts whose next
+    /// request has to wait
+    next_request_at: HashMap<String, Instant>,
+}
+
+impl RateLimiter {
+    /// Books the next slot to send a request to `host` in, and returns how
+    /// long to wait for it.
+    fn reserve(&mut self, host: &str) -> Duration {
+        let Some(interval) = self.interval else {
+            return Duration::ZERO;
+        };
+        let now = Instant::now();
+        // hosts that may be requested right away need no entry
+        self.next_request_at.retain(|_, at| *at > now);
+        let next_request_at = self.next_request_at.entry(host.to_string()).or_insert(now);
+        let slot = (*next_request_at).max(now);
+        *next_request_at = slot + interval;
+        slot - now
+    }
+}
+
+/// Failures recorded for a host
+#[derive(Debug, Default)]
+struct HostFailures {
+    failed_at: Vec<Instant>,
+    open_until: Option<Instant>,
+}
+
+/// Keeps track of failing hosts, to stop sending requests to a site that is
+/// down instead of wasting time (both ours and the site's).
+#[derive(Debug)]
+struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    hosts: HashMap<String, HostFailures>,
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            hosts: HashMap::new(),
+        }
+    }
+
+    /// Returns [`BackendError::CircuitOpen`] if requests to `host` must not be
+    /// sent right now.
+    fn check(&mut self, host: &str) -> Result<(), BackendError> {
+        let Some(failures) = self.hosts.get_mut(host) else {
+            return Ok(());
+        };
+        match failures.open_until {
+            Some(open_until) if open_until > Instant::now() => Err(BackendError::CircuitOpen {
+                host: host.to_string(),
+                retry_after: open_until - Instant::now(),
+            }),
+            Some(_) => {
+                failures.open_until = None;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn record_success(&mut self, host: &str) {
+        self.hosts.remove(host);
+    }
+
+    fn record_failure(&mut self, host: &str) {
+        if self.config.failure_threshold == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let failures = self.hosts.entry(host.to_string()).or_default();
+        failures
+            .failed_at
+            .retain(|failed_at| now.duration_since(*failed_at) < self.config.window);
+        failures.failed_at.push(now);
+        if failures.failed_at.len() >= self.config.failure_threshold {
+            warn!(
+                "{host} failed {} times in a row, not sending requests to it for {:?}",
+                failures.failed_at.len(),
+                self.config.cooldown
+            );
+            failures.failed_at.clear();
+            failures.open_until = Some(now + self.config.cooldown);
+        }
+    }
+}
+
+/// Returns the `host:port` part of the URL, used to identify hosts in the
+/// circuit breaker.
+fn host_key(url: &Url) -> String {
+    format!(
+        "{}:{}",
+        url.host_str().unwrap_or_default(),
+        url.port_or_known_default().unwrap_or_default()
+    )
+}
+
+/// Formats a setting as a `name: value` line, followed by its default value
+/// when it has been changed, for [`describe_network_settings`] &
+/// [`BackendOptions::describe`][crate::backends::BackendOptions::describe].
+pub(crate) fn setting_line(name: &str, value: impl Debug, default: impl Debug) -> String {
+    let (value, default) = (format!("{value:?}"), format!("{default:?}"));
+    if value == default {
+        format!("{name}: {value}")
+    } else {
+        format!("{name}: {value} (default: {default})")
+    }
+}
+
+/// Describes the settings of the requests sent with `client`: its User-Agent,
+/// then the ones shared by all the requests of the process, the [rate
+/// limit][set_rate_limit], the [circuit breaker][set_circuit_breaker_config] &
+/// the [User-Agent rotation][set_user_agent_rotation], one per line.
+pub(crate) fn describe_network_settings(client: &HttpClient) -> String {
+    let rate_limit = RATE_LIMITER
+        .lock()
+        .unwrap()
+        .interval
+        .map(|interval| 1.0 / interval.as_secs_f64());
+    let circuit_breaker = CIRCUIT_BREAKER.lock().unwrap().config;
+    let rotation = USER_AGENT_ROTATION.lock().unwrap().clone();
+    [
+        setting_line("user_agent", client.user_agent(), USER_AGENT),
+        setting_line("rate_limit", rate_limit, None::<f64>),
+        setting_line(
+            "circuit_breaker",
+            circuit_breaker,
+            CircuitBreakerConfig::default(),
+        ),
+        setting_line(
+            "user_agent_rotation",
+            rotation,
+            UserAgentRotation::default(),
+        ),
+    ]
+    .join("\n")
+}
+
+/// A `cf_clearance` cookie, obtained by passing a Cloudflare challenge in a
+/// browser, along with the User-Agent of that browser: Cloudflare only honors
+/// the cookie when both are sent together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloudflareClearance {
+    /// Value of the `cf_clearance` cookie
+    pub cf_clearance: String,
+    /// User-Agent of the browser the cookie was obtained with
+    pub user_agent: String,
+}
+
+impl CloudflareClearance {
+    /// Returns the `Cookie` & `User-Agent` headers to send for the clearance
+    /// to be honored, to be passed to [`HttpClient::get_with_headers`].
+    #[cfg_attr(not(feature = "scribblehub"), allow(dead_code))]
+    pub(crate) fn headers(&self) -> [(&'static str, String); 2] {
+        [
+            ("cookie", format!("cf_clearance={}", self.cf_clearance)),
+            ("user-agent", self.user_agent.clone()),
+        ]
+    }
+}
+
+/// Snippets only found in the interstitial page ("Just a moment...") served
+/// by Cloudflare while it checks the browser.
+const CLOUDFLARE_CHALLENGE_MARKERS: &[&str] = &[
+    "<title>Just a moment...</title>",
+    "/cdn-cgi/challenge-platform/",
+    "window._cf_chl_opt",
+    "cf-browser-verification",
+];
+
+/// Number of times a request answered with a Cloudflare challenge (HTTP 503)
+/// is sent again before giving up: the challenge sometimes clears itself
+/// after a few seconds, but usually requires a [`CloudflareClearance`].
+const MAX_CLOUDFLARE_CHALLENGE_RETRIES: usize = 2;
+
+/// Tells whether the given page is a Cloudflare challenge rather than the
+/// requested content.
+pub(crate) fn is_cloudflare_challenge(html: &str) -> bool {
+    CLOUDFLARE_CHALLENGE_MARKERS
+        .iter()
+        .any(|marker| html.contains(marker))
+}
+
+/// The error returned when `url` keeps answering with a Cloudflare
+/// challenge.
+pub(crate) fn cloudflare_challenge_error(
+    url: &str,
+    status: StatusCode,
+    content: String,
+) -> BackendError {
+    BackendError::RequestFailed {
+        message: format!(
+            "Could not get past the Cloudflare challenge of {url}: a cf_clearance cookie & the User-Agent it was obtained with may be needed"
+        ),
+        status,
+        content,
+    }
+}
+
+/// Just a custom get that sets a correct User-Agent & follows redirects, using
+/// the default [`HttpClient`]. See [`HttpClient::get`].
+#[cfg_attr(not(feature = "royalroad"), allow(dead_code))]
 pub(crate) fn get(url: impl IntoUrl) -> Result<Response, BackendError> {
-    let url = url.into_url()?;
-    let mut fibonacci_iterator = FibonacciIterator::new();
-    let _ = fibonacci_iterator.next(); // get rid of the first value, which is 0
+    HTTP_CLIENT.get(url)
+}
+
+impl HttpClient {
+    /// Returns the User-Agent requests are sent with (before any
+    /// [rotation][set_user_agent_rotation]), as configured by
+    /// [`ClientConfig::user_agent`].
+    pub(crate) fn user_agent(&self) -> &str {
+        self.client
+            .as_ref()
+            .map_or(USER_AGENT, |client| &client.config.user_agent)
+    }
+
+    /// Sends a GET request to the given URL & follows redirects.
+    /// Returns [`BackendError::UrlNotFound`] on HTTP 404, and
+    /// [`BackendError::CircuitOpen`] if the host has been failing too much
+    /// lately (see [`CircuitBreakerConfig`]). On HTTP 403, other
+    /// User-Agents may be tried (see [`UserAgentRotation`]). Requests are
+    /// spaced out according to [`set_rate_limit`] & the client's
+    /// [`RateLimit`], whichever is stricter. Cloudflare challenges
+    /// are retried a few times before giving up with a
+    /// [`BackendError::RequestFailed`].
+    pub(crate) fn get(&self, url: impl IntoUrl) -> Result<Response, BackendError> {
+        self.get_with_headers(url, &[])
+    }
+
+    /// Like [`HttpClient::get`], with additional headers, such as the ones
+    /// required by some APIs.
+    pub(crate) fn get_with_headers(
+        &self,
+        url: impl IntoUrl,
+        headers: &[(&str, &str)],
+    ) -> Result<Response, BackendError> {
+        let url = url.into_url()?;
+        let host = host_key(&url);
+        CIRCUIT_BREAKER.lock().unwrap().check(&host)?;
+        let mut fibonacci_iterator = FibonacciIterator::new();
+        let _ = fibonacci_iterator.next(); // get rid of the first value, which is 0
+        let rotation = USER_AGENT_ROTATION.lock().unwrap().clone();
+        // a User-Agent given by the caller (such as the one of a
+        // `CloudflareClearance`) or by the user must be kept
+        let has_user_agent = self.custom_user_agent
+            || headers
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case(header::USER_AGENT.as_str()));
+        let user_agents: &[String] = if rotation.enabled && !has_user_agent {
+            &rotation.user_agents
+        } else {
+            &[]
+        };
+        let mut user_agents = user_agents.iter();
+        let mut user_agent = None;
+        let mut challenge_retries = 0;
+        loop {
+            let mut wait_time = RATE_LIMITER.lock().unwrap().reserve(&host);
+            if let Some(rate_limiter) = &self.rate_limiter {
+                wait_time = wait_time.max(rate_limiter.lock().unwrap().reserve(&host));
+            }
+            if !wait_time.is_zero() {
+                sleep(wait_time);
+            }
+            let client = match &self.client {
+                Some(client) => client.get()?,
+                None => &DEFAULT_CLIENT,
+            };
+            let mut request = client.get(url.clone());
+            if let Some(user_agent) = user_agent {
+                request = request.header(header::USER_AGENT, user_agent);
+            }
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            let response = match request.send() {
+                Ok(response) => response,
+                Err(e) => {
+                    CIRCUIT_BREAKER.lock().unwrap().record_failure(&host);
+                    return Err(e.into());
+                }
+            };
+            if response.status().is_server_error() {
+                let status = response.status();
+                let content = response.text()?;
+                if is_cloudflare_challenge(&content) {
+                    if challenge_retries < MAX_CLOUDFLARE_CHALLENGE_RETRIES {
+                        challenge_retries += 1;
+                        let wait_time = fibonacci_iterator.next().unwrap();
+                        warn!("URL {url} answered with a Cloudflare challenge. Will wait for {wait_time}s before trying again.");
+                        sleep(Duration::from_secs(wait_time as u64));
+                        continue;
+                    }
+                    return Err(cloudflare_challenge_error(url.as_str(), status, content));
+                }
+                CIRCUIT_BREAKER.lock().unwrap().record_failure(&host);
+                return Err(BackendError::RequestFailed {
+                    message: format!("Could not fetch {url}"),
+                    status,
+                    content,
+                });
+            }
+            CIRCUIT_BREAKER.lock().unwrap().record_success(&host);
+            if response.status().is_success() {
+                return Ok(response);
+            }
+            if response.status() == StatusCode::NOT_FOUND {
+                return Err(BackendError::UrlNotFound);
+            }
+            if response.status() == StatusCode::FORBIDDEN {
+                if let Some(next_user_agent) = user_agents.next() {
+                    warn!("URL {url} is forbidden, trying again with User-Agent {next_user_agent}");
+                    user_agent = Some(next_user_agent);
+                    continue;
+                }
+                let status = response.status();
+                let content = response.text()?;
+                if is_cloudflare_challenge(&content) {
+                    return Err(cloudflare_challenge_error(url.as_str(), status, content));
+                }
+                return Err(BackendError::RequestFailed {
+                    message: format!("Access to {url} is forbidden"),
+                    status,
+                    content,
+                });
+            }
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                let wait_time = fibonacci_iterator.next().unwrap();
+                if wait_time > 60 {
+                    error!("URL {url} replied we sent too many requests too many times.");
+                    return Err(BackendError::RequestFailed {message: format!("Could not fetch {url}. Backend said we sent too many requests, and we have exhausted our number of retries"), status: response.status(), content: response.text()?});
+                }
+                warn!("URL {url} replied we sent too many requests. Will wait for {wait_time}s before trying again.");
+                sleep(Duration::from_secs(wait_time as u64));
+                continue;
+            }
+            return Err(BackendError::RequestFailed {
+                message: format!("Could not fetch {url}"),
+                status: response.status(),
+                content: response.text()?,
+            });
+        }
+    }
+}
+
+/// Configures how [`retry`] retries an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of times the operation is run, including the first
+    /// one. `0` and `1` both mean it is never retried.
+    pub max_attempts: usize,
+    /// Time waited before the first retry. It is doubled for each subsequent
+    /// retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the time waited between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Time to wait before the given retry (the first retry being `1`).
+    fn backoff(&self, retry: usize) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1) as u32);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Runs `f` until it succeeds, fails with an error that is not
+/// [retryable](BackendError::is_retryable), or `policy.max_attempts` is
+/// reached, waiting longer and longer between attempts. Returns the last
+/// result.
+///
+/// Unlike the retries done for each request, this retries whole operations,
+/// parsing included:
+///
+/// ```rust,no_run
+/// use libwebnovel::{retry, Backend, Backends, RetryPolicy};
+/// let backend =
+///     Backends::new("https://www.royalroad.com/fiction/21220/mother-of-learning").unwrap();
+/// let chapter = retry(|| backend.get_chapter(1), &RetryPolicy::default()).unwrap();
+/// ```
+pub fn retry<T, F: FnMut() -> Result<T, BackendError>>(
+    mut f: F,
+    policy: &RetryPolicy,
+) -> Result<T, BackendError> {
+    let mut attempt = 1;
     loop {
-        // FIXME: dont use clone()
-        let response = HTTP_CLIENT.get(url.clone()).send()?;
-        if response.status().is_success() {
-            return Ok(response);
-        }
-        if response.status() == StatusCode::TOO_MANY_REQUESTS {
-            let wait_time = fibonacci_iterator.next().unwrap();
-            if wait_time > 60 {
+        match f() {
+            Err(e) if e.is_retryable() && attempt < policy.max_attempts => {
+                let wait_time = policy.backoff(attempt);
+                warn!("Attempt {attempt} failed ({e}), trying again in {wait_time:?}");
+                sleep(wait_time);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl HttpClient {
+    /// Async counterpart of [`HttpClient::get`], using `reqwest`'s async
+    /// client configured like the blocking one. Requests share the rate limit
+    /// & circuit breaker of [`HttpClient::get`], but are not retried with
+    /// other User-Agents on HTTP 403. Any other unsuccessful status is returned
+    /// as a [`BackendError::RequestFailed`].
+    pub(crate) async fn get_async(
+        &self,
+        url: impl IntoUrl,
+    ) -> Result<reqwest::Response, BackendError> {
+        let url = url.into_url()?;
+        let host = host_key(&url);
+        CIRCUIT_BREAKER.lock().unwrap().check(&host)?;
+        let mut fibonacci_iterator = FibonacciIterator::new();
+        let _ = fibonacci_iterator.next(); // get rid of the first value, which is 0
+        let client = self.async_client.as_ref().unwrap_or(&DEFAULT_ASYNC_CLIENT);
+        loop {
+            let mut wait_time = RATE_LIMITER.lock().unwrap().reserve(&host);
+            if let Some(rate_limiter) = &self.rate_limiter {
+                wait_time = wait_time.max(rate_limiter.lock().unwrap().reserve(&host));
+            }
+            if !wait_time.is_zero() {
+                tokio::time::sleep(wait_time).await;
+            }
+            let response = match client.get(url.clone()).send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    CIRCUIT_BREAKER.lock().unwrap().record_failure(&host);
+                    return Err(e.into());
+                }
+            };
+            let status = response.status();
+            if status.is_server_error() {
+                CIRCUIT_BREAKER.lock().unwrap().record_failure(&host);
+            } else {
+                CIRCUIT_BREAKER.lock().unwrap().record_success(&host);
+            }
+            if status.is_success() {
+                return Ok(response);
+            }
+            if status == StatusCode::NOT_FOUND {
+                return Err(BackendError::UrlNotFound);
+            }
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let wait_time = fibonacci_iterator.next().unwrap();
+                if wait_time <= 60 {
+                    warn!("URL {url} replied we sent too many requests. Will wait for {wait_time}s before trying again.");
+                    tokio::time::sleep(Duration::from_secs(wait_time as u64)).await;
+                    continue;
+                }
                 error!("URL {url} replied we sent too many requests too many times.");
-                return Err(BackendError::RequestFailed {message: format!("Could not fetch {url}. Backend said we sent too many requests, and we have exhausted our number of retries"), status: response.status(), content: response.text()?});
             }
-            warn!("URL {url} replied we sent too many requests. Will wait for {wait_time}s before trying again.");
-            sleep(Duration::from_secs(wait_time as u64));
+            return Err(BackendError::RequestFailed {
+                message: format!("Could not fetch {url}"),
+                status,
+                content: response.text().await?,
+            });
+        }
+    }
+}
+
+/// Elements that never have a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+/// Attributes whose mere presence is meaningful, even without a value.
+const BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "allowfullscreen",
+    "checked",
+    "controls",
+    "default",
+    "disabled",
+    "hidden",
+    "open",
+    "readonly",
+    "reversed",
+    "selected",
+];
+/// Elements whose content must be written out untouched.
+const RAW_ELEMENTS: &[&str] = &["pre", "textarea", "script", "style"];
+/// Elements around which whitespace is not rendered.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "div",
+    "dl",
+    "dd",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "html",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// Minifies an HTML fragment: whitespace between tags is collapsed (or
+/// removed around block elements), comments and empty attributes are removed.
+/// The content of `<pre>` elements (and the likes) is kept as-is.
+pub(crate) fn minify_html(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut minified = String::with_capacity(html.len());
+    minify_children(fragment.root_element(), &mut minified);
+    minified.trim().to_string()
+}
+
+fn minify_children(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => {
+                let is_block =
+                    |element: ElementRef| BLOCK_ELEMENTS.contains(&element.value().name());
+                let text = collapse_whitespace(text);
+                if text == " " {
+                    // comments are removed, so they count as block elements
+                    let [previous, next] = [child.prev_sibling(), child.next_sibling()].map(|s| {
+                        s.map(|s| {
+                            s.value().is_comment() || ElementRef::wrap(s).is_some_and(is_block)
+                        })
+                    });
+                    if previous.unwrap_or(true)
+                        && next.unwrap_or(true)
+                        && (previous.is_some() || next.is_some() || is_block(element))
+                    {
+                        continue;
+                    }
+                }
+                out.push_str(&encode_text(&text));
+            }
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).unwrap();
+                if RAW_ELEMENTS.contains(&child.value().name()) {
+                    out.push_str(&child.html());
+                    continue;
+                }
+                let name = child.value().name();
+                out.push('<');
+                out.push_str(name);
+                for (attribute, value) in child.value().attrs() {
+                    if BOOLEAN_ATTRIBUTES.contains(&attribute) {
+                        out.push(' ');
+                        out.push_str(attribute);
+                    } else if !value.trim().is_empty() {
+                        out.push_str(&format!(
+                            " {attribute}=\"{}\"",
+                            encode_double_quoted_attribute(value)
+                        ));
+                    }
+                }
+                out.push('>');
+                if VOID_ELEMENTS.contains(&name) {
+                    continue;
+                }
+                minify_children(child, out);
+                out.push_str(&format!("</{name}>"));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Replaces every run of whitespace with a single space.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
+
+/// Converts an HTML fragment to plain text: block elements become paragraphs
+/// separated by blank lines, `<br>` becomes a line break, and images are
+/// replaced by `[image: alt]` when they have an alt text. Whitespace is
+/// collapsed, except in `<pre>` elements.
+pub(crate) fn html_to_text(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut text = String::with_capacity(html.len());
+    push_text_of(fragment.root_element(), &mut text);
+    text.trim().to_string()
+}
+
+/// Makes sure the plain text ends with `newlines` line breaks, unless it is
+/// empty.
+fn push_line_breaks(out: &mut String, newlines: usize) {
+    out.truncate(out.trim_end_matches(' ').len());
+    if out.is_empty() {
+        return;
+    }
+    let existing = out.len() - out.trim_end_matches('\n').len();
+    for _ in existing..newlines {
+        out.push('\n');
+    }
+}
+
+fn push_text_of(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => {
+                let text = collapse_whitespace(text);
+                if out.is_empty() || out.ends_with(['\n', ' ']) {
+                    out.push_str(text.trim_start());
+                } else {
+                    out.push_str(&text);
+                }
+            }
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).unwrap();
+                let name = child.value().name();
+                match name {
+                    "script" | "style" => {}
+                    "br" => {
+                        out.truncate(out.trim_end_matches(' ').len());
+                        out.push('\n');
+                    }
+                    "img" => {
+                        if let Some(alt) =
+                            child.attr("alt").map(str::trim).filter(|a| !a.is_empty())
+                        {
+                            out.push_str(&format!("[image: {alt}]"));
+                        }
+                    }
+                    "pre" => {
+                        push_line_breaks(out, 2);
+                        out.push_str(&child.text().collect::<String>());
+                        push_line_breaks(out, 2);
+                    }
+                    _ if BLOCK_ELEMENTS.contains(&name) => {
+                        push_line_breaks(out, 2);
+                        push_text_of(child, out);
+                        push_line_breaks(out, 2);
+                    }
+                    _ => push_text_of(child, out),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Converts an HTML fragment to Markdown: block elements become paragraphs,
+/// and headings, emphasis, links, images, horizontal rules & block quotes
+/// their Markdown counterparts. Other elements are rendered as their text.
+/// Whitespace is collapsed, except in `<pre>` elements, which become code
+/// blocks.
+pub(crate) fn html_to_markdown(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut markdown = String::with_capacity(html.len());
+    push_markdown_of(fragment.root_element(), &mut markdown);
+    markdown.trim().to_string()
+}
+
+/// Escapes the characters of a text that Markdown would take for formatting.
+pub(crate) fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Matches the start of an ordered list item, such as `1.` or `2)`.
+static ORDERED_LIST_MARKER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,9})([.)])").unwrap());
+
+/// Escapes the characters starting a line of text that Markdown would take
+/// for a block (heading, block quote, list item…).
+fn escape_line_start(line: &str) -> String {
+    if line.starts_with(['#', '>', '-', '+']) {
+        return format!("\\{line}");
+    }
+    ORDERED_LIST_MARKER_REGEX
+        .replace(line, "$1\\$2")
+        .into_owned()
+}
+
+/// Appends inline Markdown, without doubling the spaces between words nor
+/// starting lines with one.
+fn push_inline(out: &mut String, markdown: &str) {
+    if out.is_empty() || out.ends_with(['\n', ' ']) {
+        out.push_str(markdown.trim_start());
+    } else {
+        out.push_str(markdown);
+    }
+}
+
+/// Appends the Markdown of an inline element between `open` & `close`, such
+/// as `**` for bold text. Markdown doesn't allow whitespace right inside them,
+/// so it is moved outside.
+fn push_wrapped(element: ElementRef, out: &mut String, open: &str, close: &str) {
+    let mut inner = String::new();
+    push_markdown_of(element, &mut inner);
+    let text: String = element.text().collect();
+    if text.trim().is_empty() {
+        push_inline(out, inner.trim());
+        return;
+    }
+    if text.starts_with(char::is_whitespace) {
+        push_inline(out, " ");
+    }
+    push_inline(out, &format!("{open}{}{close}", inner.trim()));
+    if text.ends_with(char::is_whitespace) {
+        out.push(' ');
+    }
+}
+
+/// Appends the Markdown of the children of `element`. See
+/// [`html_to_markdown`].
+fn push_markdown_of(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => {
+                let text = escape_markdown(&collapse_whitespace(text));
+                if out.is_empty() || out.ends_with('\n') {
+                    push_inline(out, &escape_line_start(text.trim_start()));
+                } else {
+                    push_inline(out, &text);
+                }
+            }
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).unwrap();
+                let name = child.value().name();
+                match name {
+                    "script" | "style" => {}
+                    "br" => {
+                        out.truncate(out.trim_end_matches(' ').len());
+                        if !out.is_empty() && !out.ends_with('\n') {
+                            out.push_str("\\\n");
+                        }
+                    }
+                    "hr" => {
+                        push_line_breaks(out, 2);
+                        out.push_str("---");
+                        push_line_breaks(out, 2);
+                    }
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                        let mut inner = String::new();
+                        push_markdown_of(child, &mut inner);
+                        let inner: Vec<&str> = inner
+                            .lines()
+                            .map(|line| line.trim_end_matches('\\').trim())
+                            .filter(|line| !line.is_empty())
+                            .collect();
+                        push_line_breaks(out, 2);
+                        out.push_str(&"#".repeat(name[1..].parse().unwrap()));
+                        out.push(' ');
+                        out.push_str(&inner.join(" "));
+                        push_line_breaks(out, 2);
+                    }
+                    "strong" | "b" => push_wrapped(child, out, "**", "**"),
+                    "em" | "i" => push_wrapped(child, out, "*", "*"),
+                    "a" => match child.attr("href") {
+                        Some(href) => push_wrapped(
+                            child,
+                            out,
+                            "[",
+                            &format!("]({})", href.trim().replace(' ', "%20")),
+                        ),
+                        None => push_markdown_of(child, out),
+                    },
+                    "img" => {
+                        let alt = child.attr("alt").unwrap_or_default().trim();
+                        let src = child.attr("src").unwrap_or_default().trim();
+                        push_inline(
+                            out,
+                            &format!("![{}]({})", escape_markdown(alt), src.replace(' ', "%20")),
+                        );
+                    }
+                    "pre" => {
+                        push_line_breaks(out, 2);
+                        out.push_str("```\n");
+                        out.push_str(child.text().collect::<String>().trim_matches('\n'));
+                        out.push_str("\n```");
+                        push_line_breaks(out, 2);
+                    }
+                    "blockquote" => {
+                        let mut inner = String::new();
+                        push_markdown_of(child, &mut inner);
+                        push_line_breaks(out, 2);
+                        for line in inner.trim().lines() {
+                            out.push_str(format!("> {line}").trim_end());
+                            out.push('\n');
+                        }
+                        push_line_breaks(out, 2);
+                    }
+                    _ if BLOCK_ELEMENTS.contains(&name) => {
+                        push_line_breaks(out, 2);
+                        push_markdown_of(child, out);
+                        push_line_breaks(out, 2);
+                    }
+                    _ => push_markdown_of(child, out),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Elements never holding the main content of a page.
+const UNLIKELY_ELEMENTS: &[&str] = &[
+    "aside", "footer", "form", "header", "nav", "noscript", "script", "style",
+];
+
+/// Matches the classes & IDs of elements unlikely to hold the main content of
+/// a page.
+static UNLIKELY_CLASS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(ad|ads|advert\w*|banner|comments?|footer|menu|nav\w*|related|share|sidebar|social|sponsor\w*|widget)\b").unwrap()
+});
+
+/// Matches the classes & IDs of elements likely to hold the main content of a
+/// page.
+static LIKELY_CLASS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(article|body|chapter\w*|content|entry|main|post|reader|story|text)\b")
+        .unwrap()
+});
+
+/// Used to find the paragraphs to score
+static PARAGRAPH_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("p").unwrap());
+/// Used to compute the link density of content candidates
+static LINK_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("a").unwrap());
+
+/// Paragraphs with less text than this (in characters) are not scored.
+const MIN_PARAGRAPH_LENGTH: usize = 25;
+
+/// Returns `+25` for elements whose class or ID look like the main content,
+/// `-25` for those looking like navigation, ads…, `0` otherwise.
+fn class_weight(element: ElementRef) -> f64 {
+    let names = [element.value().attr("class"), element.value().id()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut weight = 0.;
+    if UNLIKELY_CLASS_REGEX.is_match(&names) {
+        weight -= 25.;
+    }
+    if LIKELY_CLASS_REGEX.is_match(&names) {
+        weight += 25.;
+    }
+    weight
+}
+
+/// Tells whether the element is obviously not part of the main content.
+fn is_unlikely(element: ElementRef) -> bool {
+    UNLIKELY_ELEMENTS.contains(&element.value().name()) || class_weight(element) < 0.
+}
+
+/// Extracts the main content of an HTML page, using readability-style
+/// heuristics: paragraphs are scored by their length & number of commas, and
+/// the element containing the best-scoring paragraphs (weighted by its class &
+/// ID, and penalized by the density of its links) is deemed the content.
+/// Navigation, sidebars, scripts & the likes are left out. Returns an empty
+/// string if no content could be found.
+///
+/// This is meant as a fallback for pages whose layout is unknown, such as when
+/// a `SiteTemplate`'s content selector matches nothing (see its
+/// `readable_fallback`).
+///
+/// ```rust
+/// use libwebnovel::extract_readable;
+/// let page = r#"<html><body>
+///   <nav><a href="/">Home</a> | <a href="/fictions">Fictions</a></nav>
+///   <div class="post">
+///     <p>The rain had not stopped for three days, and the river was rising.</p>
+///     <p>Nobody in the village, not even the elders, remembered such a flood.</p>
+///   </div>
+/// </body></html>"#;
+/// let content = extract_readable(page);
+/// assert!(content.contains("The rain had not stopped"));
+/// assert!(!content.contains("Fictions"));
+/// ```
+pub fn extract_readable(html: &str) -> String {
+    readable_content(&Html::parse_document(html))
+}
+
+/// See [`extract_readable`].
+pub(crate) fn readable_content(document: &Html) -> String {
+    let mut scores = HashMap::new();
+    for paragraph in document.select(&PARAGRAPH_SELECTOR) {
+        if paragraph
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(is_unlikely)
+        {
+            continue;
+        }
+        let text = paragraph.text().collect::<String>();
+        let length = text.trim().chars().count();
+        if length < MIN_PARAGRAPH_LENGTH {
             continue;
         }
+        let score = 1. + text.matches(',').count() as f64 + (length as f64 / 100.).min(3.);
+        let mut ancestors = paragraph.ancestors().filter_map(ElementRef::wrap);
+        if let Some(parent) = ancestors.next() {
+            *scores.entry(parent.id()).or_insert(class_weight(parent)) += score;
+        }
+        if let Some(grandparent) = ancestors.next() {
+            *scores
+                .entry(grandparent.id())
+                .or_insert(class_weight(grandparent)) += score / 2.;
+        }
+    }
+    let best = scores
+        .into_iter()
+        .filter_map(|(id, score)| {
+            let element = ElementRef::wrap(document.tree.get(id)?)?;
+            let text_length = element.text().map(str::len).sum::<usize>().max(1);
+            let link_length: usize = element
+                .select(&LINK_SELECTOR)
+                .flat_map(|link| link.text())
+                .map(str::len)
+                .sum();
+            let link_density = link_length as f64 / text_length as f64;
+            Some((element, score * (1. - link_density)))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b));
+    let Some((content, _)) = best else {
+        return String::new();
+    };
+    content
+        .children()
+        .filter(|child| ElementRef::wrap(*child).is_none_or(|element| !is_unlikely(element)))
+        .map(|child| match child.value() {
+            Node::Text(text) => encode_text(&text.text).to_string(),
+            _ => ElementRef::wrap(child)
+                .map(|element| element.html())
+                .unwrap_or_default(),
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// How quotes are written by
+/// [`Chapter::normalize_typography`][crate::Chapter::normalize_typography].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quotes are left untouched.
+    #[default]
+    Keep,
+    /// Curly quotes are replaced by straight ones (`'` & `"`).
+    Straight,
+    /// Straight quotes are replaced by curly ones (`‘’` & `“”`), depending
+    /// on whether they open or close a quotation.
+    Curly,
+}
+
+/// How dashes are written by
+/// [`Chapter::normalize_typography`][crate::Chapter::normalize_typography].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DashStyle {
+    /// Dashes are left untouched.
+    #[default]
+    Keep,
+    /// Em dashes are replaced by `---` & en dashes by `--`.
+    Ascii,
+    /// `---` is replaced by an em dash (`—`) & `--` by an en dash (`–`).
+    Unicode,
+}
+
+/// Options of
+/// [`Chapter::normalize_typography`][crate::Chapter::normalize_typography]. The
+/// default options only decode HTML entities.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TypographyOptions {
+    /// How quotes are written.
+    pub quotes: QuoteStyle,
+    /// How dashes are written.
+    pub dashes: DashStyle,
+}
+
+/// Decodes the entities of an HTML fragment & rewrites the quotes & dashes of
+/// its text as requested. The content of `<pre>` & `<code>` elements (and the
+/// likes) is kept as-is.
+pub(crate) fn normalize_typography(html: &str, options: TypographyOptions) -> String {
+    let mut fragment = Html::parse_fragment(html);
+    let text_nodes: Vec<_> = fragment
+        .root_element()
+        .descendants()
+        .filter(|node| node.value().is_text())
+        .filter(|node| {
+            !node
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|element| {
+                    let name = element.value().name();
+                    name == "code" || RAW_ELEMENTS.contains(&name)
+                })
+        })
+        .map(|node| node.id())
+        .collect();
+    let mut previous_char = None;
+    for id in text_nodes {
+        let mut node = fragment.tree.get_mut(id).unwrap();
+        let Node::Text(text) = node.value() else {
+            continue;
+        };
+        let normalized = normalize_text(&text.text, previous_char, options);
+        previous_char = normalized.chars().last().or(previous_char);
+        text.text = normalized.as_str().into();
+    }
+    fragment.root_element().inner_html().trim().to_string()
+}
+
+/// Rewrites the quotes & dashes of the given text. `previous_char` is the
+/// character preceding the text, used to tell opening quotes from closing
+/// ones.
+fn normalize_text(text: &str, previous_char: Option<char>, options: TypographyOptions) -> String {
+    let text = match options.dashes {
+        DashStyle::Keep => text.to_string(),
+        DashStyle::Ascii => text.replace('—', "---").replace('–', "--"),
+        DashStyle::Unicode => text.replace("---", "—").replace("--", "–"),
+    };
+    let mut normalized = String::with_capacity(text.len());
+    let mut previous_char = previous_char;
+    for c in text.chars() {
+        let is_opening =
+            || previous_char.is_none_or(|p| p.is_whitespace() || "([{“‘—–-".contains(p));
+        let c = match (options.quotes, c) {
+            (QuoteStyle::Straight, '‘' | '’' | '‚' | '‛') => '\'',
+            (QuoteStyle::Straight, '“' | '”' | '„' | '‟') => '"',
+            (QuoteStyle::Curly, '\'') if is_opening() => '‘',
+            (QuoteStyle::Curly, '\'') => '’',
+            (QuoteStyle::Curly, '"') if is_opening() => '“',
+            (QuoteStyle::Curly, '"') => '”',
+            _ => c,
+        };
+        normalized.push(c);
+        previous_char = Some(c);
+    }
+    normalized
+}
+
+/// Matches the text of a link to another chapter or to the table of contents
+static NAVIGATION_LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\W*(prev(ious)?|next|index|(table of )?contents|toc|chapter list|home)\b")
+        .unwrap()
+});
+
+/// Tells whether a block of a chapter's content is a navigation block, such as
+/// "Previous Chapter | Index | Next Chapter": a `<nav>`, or links to other
+/// chapters only separated by punctuation.
+fn is_navigation(block: &ElementRef) -> bool {
+    if block.value().name() == "nav" {
+        return true;
+    }
+    let links: Vec<String> = block
+        .select(&LINK_SELECTOR)
+        .map(|a| a.text().collect::<String>())
+        .collect();
+    if !links
+        .iter()
+        .any(|text| NAVIGATION_LINK_REGEX.is_match(text))
+    {
+        return false;
+    }
+    // everything that is not a link must be separators
+    block
+        .descendants()
+        .all(|node| match node.value().as_text() {
+            Some(text) => {
+                text.chars().all(|c| !c.is_alphanumeric())
+                    || node
+                        .ancestors()
+                        .filter_map(ElementRef::wrap)
+                        .any(|element| element.value().name() == "a")
+            }
+            None => true,
+        })
+}
+
+/// Removes the navigation blocks (see [`is_navigation`]) found at the start &
+/// at the end of an HTML fragment, such as the links to the previous & next
+/// chapters some sites put around the content. The fragment is returned as-is
+/// if there are none.
+pub(crate) fn strip_navigation(html: &str) -> String {
+    let mut fragment = Html::parse_fragment(html);
+    let blocks: Vec<_> = fragment
+        .root_element()
+        .children()
+        .filter(|node| !node.value().as_text().is_some_and(|t| t.trim().is_empty()))
+        .collect();
+    let navigation: Vec<bool> = blocks
+        .iter()
+        .map(|node| ElementRef::wrap(*node).is_some_and(|block| is_navigation(&block)))
+        .collect();
+    let leading = navigation.iter().take_while(|is_nav| **is_nav).count();
+    let trailing = navigation[leading..]
+        .iter()
+        .rev()
+        .take_while(|is_nav| **is_nav)
+        .count();
+    if leading == 0 && trailing == 0 {
+        return html.to_string();
+    }
+    let ids: Vec<_> = blocks[..leading]
+        .iter()
+        .chain(&blocks[blocks.len() - trailing..])
+        .map(|node| node.id())
+        .collect();
+    for id in ids {
+        fragment.tree.get_mut(id).unwrap().detach();
+    }
+    fragment.root_element().inner_html().trim().to_string()
+}
+
+/// Removes the paragraphs of an HTML fragment whose text, once its whitespace
+/// is collapsed, is exactly one of `phrases`. The fragment is returned as-is
+/// if there are none.
+pub(crate) fn strip_phrases(html: &str, phrases: &[&str]) -> String {
+    let normalize = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let phrases: Vec<String> = phrases.iter().map(|phrase| normalize(phrase)).collect();
+    let mut fragment = Html::parse_fragment(html);
+    let paragraphs: Vec<_> = fragment
+        .select(&PARAGRAPH_SELECTOR)
+        .filter(|p| phrases.contains(&normalize(&p.text().collect::<String>())))
+        .map(|p| p.id())
+        .collect();
+    if paragraphs.is_empty() {
+        return html.to_string();
+    }
+    for id in paragraphs {
+        fragment.tree.get_mut(id).unwrap().detach();
+    }
+    fragment.root_element().inner_html().trim().to_string()
+}
+
+/// Returns the language declared by the `lang` attribute of a page's `<html>`
+/// element, as a BCP-47 tag.
+#[cfg_attr(
+    not(any(
+        feature = "royalroad",
+        feature = "freewebnovel",
+        feature = "lightnovelworld",
+        feature = "template",
+        feature = "scribblehub"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn declared_language(page: &Html) -> Option<String> {
+    let root = page.root_element();
+    let lang = root.attr("lang").or(root.attr("xml:lang"))?.trim();
+    (!lang.is_empty()).then(|| lang.replace('_', "-"))
+}
+
+/// Guesses the language of a text from the scripts of its letters. Returns
+/// `None` when most of them are latin, cyrillic or arabic, since too many
+/// languages share those scripts to tell them apart this way.
+pub(crate) fn guess_language(text: &str) -> Option<&'static str> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut latin = 0;
+    for c in text.chars().filter(|c| c.is_alphabetic()) {
+        let language = match c {
+            '\u{3040}'..='\u{30FF}' => "ja", // hiragana & katakana
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => "ko",
+            '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' => "zh",
+            '\u{0370}'..='\u{03FF}' => "el",
+            '\u{0590}'..='\u{05FF}' => "he",
+            '\u{0E00}'..='\u{0E7F}' => "th",
+            // russian, ukrainian, bulgarian, persian, urdu…
+            '\u{0400}'..='\u{04FF}' | '\u{0600}'..='\u{06FF}' => "",
+            _ => {
+                latin += 1;
+                continue;
+            }
+        };
+        *counts.entry(language).or_default() += 1;
+    }
+    // japanese mixes kanji with kana, which chinese doesn't use
+    if let Some(kana) = counts.get("ja").copied() {
+        let kanji = counts.remove("zh").unwrap_or_default();
+        if kana * 10 >= kanji {
+            counts.insert("ja", kana + kanji);
+        } else {
+            counts.insert("zh", kanji);
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .filter(|(language, count)| !language.is_empty() && *count > latin)
+        .map(|(language, _)| language)
+}
+
+/// Formats of the dates without timezone understood by [`parse_date`], which
+/// are assumed to be UTC.
+const NAIVE_DATE_FORMATS: [&str; 5] = [
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+static RELATIVE_DATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(\d+|an?|one) (second|minute|hour|day|week|month|year)s? ago$").unwrap()
+});
+
+/// Parses a date as found on fiction sites: RFC 3339 (`2023-11-14T22:13:20Z`),
+/// `2023-11-14T22:13:20` or `2023-11-14 22:13:20` (assumed to be UTC),
+/// `2023-11-14`, or relative to now (`3 days ago`, `an hour ago`, `yesterday`,
+/// `just now`). Months are counted as 30 days and years as 365.
+pub(crate) fn parse_date(s: &str) -> Result<DateTime<Utc>, BackendError> {
+    let s = s.trim();
+    let rfc3339_error = match DateTime::parse_from_rfc3339(s) {
+        Ok(date) => return Ok(date.to_utc()),
+        Err(e) => e,
+    };
+    for format in NAIVE_DATE_FORMATS {
+        if let Ok(date) = NaiveDateTime::parse_from_str(s, format) {
+            return Ok(date.and_utc());
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(date.and_time(Default::default()).and_utc());
+    }
+    let now = Utc::now();
+    match s.to_lowercase().as_str() {
+        "just now" | "now" | "today" => return Ok(now),
+        "yesterday" => return Ok(now - TimeDelta::days(1)),
+        _ => {}
+    }
+    let Some(captures) = RELATIVE_DATE_REGEX.captures(s) else {
+        return Err(rfc3339_error.into());
+    };
+    let too_far = || format!("date too far in the past: {s:?}");
+    // "a", "an" or "one"
+    let amount = if captures[1].starts_with(|c: char| c.is_ascii_digit()) {
+        captures[1]
+            .parse::<i32>()
+            .map_err(|e| BackendError::parse_error_with_source(too_far(), e))?
+    } else {
+        1
+    };
+    let unit = match captures[2].to_lowercase().as_str() {
+        "second" => TimeDelta::seconds(1),
+        "minute" => TimeDelta::minutes(1),
+        "hour" => TimeDelta::hours(1),
+        "day" => TimeDelta::days(1),
+        "week" => TimeDelta::weeks(1),
+        "month" => TimeDelta::days(30),
+        _ => TimeDelta::days(365),
+    };
+    unit.checked_mul(amount)
+        .and_then(|delta| now.checked_sub_signed(delta))
+        .ok_or_else(|| BackendError::ParseError(too_far(), None))
+}
+
+/// Maximum length of a slug, in bytes. Most filesystems limit file names to
+/// 255 bytes, this leaves some room for an extension or a suffix.
+const MAX_SLUG_LENGTH: usize = 200;
+
+/// Names that cannot be used as file names on windows, whatever their
+/// extension.
+const WINDOWS_RESERVED_NAMES: [&str; 22] = [
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Turns the given string (typically a title) into something that can safely
+/// be used as a file name: letters & digits of any script are lowercased &
+/// kept, and everything else is replaced by dashes. The result is at most
+/// 200 bytes long, and never a name reserved by windows.
+///
+/// ```rust
+/// use libwebnovel::slugify;
+/// assert_eq!(slugify("1. Good Morning Brother"), "1-good-morning-brother");
+/// assert_eq!(slugify("Re:Zero / Arc 1"), "re-zero-arc-1");
+/// ```
+pub fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.len() > MAX_SLUG_LENGTH {
+        let mut end = MAX_SLUG_LENGTH;
+        while !slug.is_char_boundary(end) {
+            end -= 1;
+        }
+        slug.truncate(end);
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "untitled".to_string()
+    } else if WINDOWS_RESERVED_NAMES.contains(&slug) {
+        format!("{slug}-")
+    } else {
+        slug.to_string()
     }
 }
 
@@ -63,7 +1691,329 @@ impl Iterator for FibonacciIterator {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::FibonacciIterator;
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, sleep};
+    use std::time::{Duration, Instant};
+
+    use chrono::{DateTime, TimeDelta, Utc};
+    use indoc::indoc;
+    use scraper::Html;
+
+    use crate::backends::BackendError;
+    use crate::test_support::MockServer;
+    use crate::utils::{
+        declared_language, extract_readable, get, guess_language, is_cloudflare_challenge,
+        minify_html, normalize_typography, parse_date, retry, set_rate_limit,
+        set_user_agent_rotation, slugify, strip_navigation, strip_phrases, CircuitBreaker,
+        CircuitBreakerConfig, ClientConfig, CloudflareClearance, DashStyle, FibonacciIterator,
+        QuoteStyle, RateLimit, RateLimiter, RetryPolicy, TypographyOptions, UserAgentRotation,
+        HTTP_CLIENT, MAX_CLOUDFLARE_CHALLENGE_RETRIES, MAX_SLUG_LENGTH, USER_AGENT,
+    };
+
+    #[test]
+    fn test_circuit_breaker() {
+        let mut breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_millis(100),
+        });
+        let host = "example.com:443";
+        breaker.record_failure(host);
+        breaker.record_failure(host);
+        breaker.record_success(host);
+        breaker.record_failure(host);
+        breaker.record_failure(host);
+        assert!(breaker.check(host).is_ok());
+        breaker.record_failure(host);
+        assert!(matches!(
+            breaker.check(host),
+            Err(BackendError::CircuitOpen { .. })
+        ));
+        assert!(breaker.check("example.org:443").is_ok());
+        sleep(Duration::from_millis(150));
+        assert!(breaker.check(host).is_ok());
+    }
+
+    #[test]
+    fn test_circuit_breaker_window() {
+        let mut breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            window: Duration::from_millis(50),
+            cooldown: Duration::from_secs(60),
+        });
+        breaker.record_failure("example.com:443");
+        sleep(Duration::from_millis(100));
+        breaker.record_failure("example.com:443");
+        assert!(breaker.check("example.com:443").is_ok());
+    }
+
+    #[test]
+    fn test_get_trips_circuit_breaker() {
+        let server = MockServer::start(&[("/down", 503, "down for maintenance")]);
+        let threshold = CircuitBreakerConfig::default().failure_threshold;
+        for _ in 0..threshold {
+            assert!(matches!(
+                get(server.url("/down")),
+                Err(BackendError::RequestFailed { .. })
+            ));
+        }
+        assert!(matches!(
+            get(server.url("/down")),
+            Err(BackendError::CircuitOpen { .. })
+        ));
+    }
+
+    #[test]
+    fn test_get_fails_promptly() {
+        let routes = [
+            ("/error", 500, "internal error"),
+            ("/gone", 410, "gone"),
+            ("/unauthorized", 401, "log in first"),
+        ];
+        let server = MockServer::start(&routes);
+        for (path, expected_status, expected_content) in routes {
+            let start = Instant::now();
+            match get(server.url(path)) {
+                Err(BackendError::RequestFailed {
+                    status, content, ..
+                }) => {
+                    assert_eq!(status, expected_status);
+                    assert_eq!(content, expected_content);
+                }
+                result => panic!("unexpected result for {path}: {result:?}"),
+            }
+            assert!(start.elapsed() < Duration::from_secs(5));
+        }
+        assert!(matches!(
+            get(server.url("/missing")),
+            Err(BackendError::UrlNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_cloudflare_challenge() {
+        const CHALLENGE: &str = "<html><head><title>Just a moment...</title></head></html>";
+        let requests = Arc::new(Mutex::new(0));
+        let server = MockServer::start_with_handler({
+            let requests = Arc::clone(&requests);
+            move |_, headers| {
+                *requests.lock().unwrap() += 1;
+                let cleared = headers.get("cookie").map(String::as_str)
+                    == Some("cf_clearance=cleared")
+                    && headers.get("user-agent").map(String::as_str) == Some("Browser/1.0");
+                if cleared {
+                    (200, "welcome".to_string())
+                } else {
+                    (503, CHALLENGE.to_string())
+                }
+            }
+        });
+        let url = server.url("/series/1/fiction/");
+        match get(&url) {
+            Err(BackendError::RequestFailed {
+                message, content, ..
+            }) => {
+                assert!(message.contains("Cloudflare"), "{message}");
+                assert_eq!(content, CHALLENGE);
+            }
+            result => panic!("unexpected result {result:?}"),
+        }
+        // retried, but not forever
+        assert_eq!(
+            *requests.lock().unwrap(),
+            1 + MAX_CLOUDFLARE_CHALLENGE_RETRIES
+        );
+
+        let clearance = CloudflareClearance {
+            cf_clearance: "cleared".to_string(),
+            user_agent: "Browser/1.0".to_string(),
+        };
+        let headers = clearance.headers();
+        let headers: Vec<(&str, &str)> = headers
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        let response = HTTP_CLIENT.get_with_headers(&url, &headers).unwrap();
+        assert_eq!(response.text().unwrap(), "welcome");
+        assert!(!is_cloudflare_challenge("<p>Just a moment, she said.</p>"));
+    }
+
+    #[test]
+    fn test_client_config() {
+        let server = MockServer::start_with_handler(|_, headers| {
+            let header = |name: &str| headers.get(name).cloned().unwrap_or_default();
+            (
+                200,
+                format!("{} {}", header("user-agent"), header("x-test")),
+            )
+        });
+        let response = get(server.url("/")).unwrap();
+        assert_eq!(response.text().unwrap(), format!("{USER_AGENT} "));
+
+        let config = ClientConfig {
+            user_agent: "Browser/2.0".to_string(),
+            headers: vec![("X-Test".to_string(), "yes".to_string())],
+            timeout: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+        let client = config.build().unwrap();
+        let response = client.get(server.url("/")).unwrap();
+        assert_eq!(response.text().unwrap(), "Browser/2.0 yes");
+        // headers given with the request still win
+        let response = client
+            .get_with_headers(server.url("/"), &[("X-Test", "no")])
+            .unwrap();
+        assert_eq!(response.text().unwrap(), "Browser/2.0 no");
+
+        for config in [
+            ClientConfig {
+                user_agent: "Browser/2.0\n".to_string(),
+                ..Default::default()
+            },
+            ClientConfig {
+                headers: vec![("X Test".to_string(), "yes".to_string())],
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                config.build(),
+                Err(BackendError::InvalidClientConfig(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_client_proxy() {
+        // a proxy gets the absolute URL of the requested page
+        let proxy = MockServer::start_with_handler(|path, _| (200, path.to_string()));
+        let client = ClientConfig {
+            proxy: Some(proxy.url("")),
+            ..Default::default()
+        }
+        .build()
+        .unwrap();
+        let response = client.get("http://fiction.invalid/chapter-1").unwrap();
+        assert_eq!(response.text().unwrap(), "http://fiction.invalid/chapter-1");
+
+        let config = ClientConfig {
+            proxy: Some("not a proxy".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.build(),
+            Err(BackendError::InvalidClientConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_user_agent_rotation() {
+        let server = MockServer::start_with_handler(|_, headers| {
+            match headers.get("user-agent").map(String::as_str) {
+                Some("second") => (200, "welcome".to_string()),
+                _ => (403, "go away".to_string()),
+            }
+        });
+        let url = server.url("/fiction");
+        assert!(matches!(
+            get(&url),
+            Err(BackendError::RequestFailed { status, .. }) if status == 403
+        ));
+        set_user_agent_rotation(UserAgentRotation {
+            enabled: true,
+            user_agents: vec!["first".to_string(), "second".to_string()],
+        });
+        let response = get(&url);
+        set_user_agent_rotation(UserAgentRotation {
+            enabled: true,
+            user_agents: vec!["first".to_string(), USER_AGENT.to_string()],
+        });
+        let exhausted = get(&url);
+        set_user_agent_rotation(UserAgentRotation::default());
+        assert_eq!(response.unwrap().text().unwrap(), "welcome");
+        assert!(matches!(
+            exhausted,
+            Err(BackendError::RequestFailed { status, .. }) if status == 403
+        ));
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let requested_at = Arc::new(Mutex::new(Vec::new()));
+        let server = MockServer::start_with_handler({
+            let requested_at = Arc::clone(&requested_at);
+            move |_, _| {
+                requested_at.lock().unwrap().push(Instant::now());
+                (200, "ok".to_string())
+            }
+        });
+        /// Removes the global limit, even if the test fails
+        struct ResetRateLimit;
+        impl Drop for ResetRateLimit {
+            fn drop(&mut self) {
+                set_rate_limit(None);
+            }
+        }
+        let reset_rate_limit = ResetRateLimit;
+        set_rate_limit(Some(20.0));
+        // two backends downloading from the same host at the same time
+        thread::scope(|scope| {
+            for backend in ["a", "b"] {
+                let url = server.url(&format!("/fiction-{backend}"));
+                scope.spawn(move || {
+                    for _ in 0..5 {
+                        get(&url).unwrap();
+                    }
+                });
+            }
+        });
+        drop(reset_rate_limit);
+        let requested_at = requested_at.lock().unwrap();
+        assert_eq!(requested_at.len(), 10);
+        // 10 requests at 20 per second take at least 9 intervals of 50ms
+        let elapsed = *requested_at.iter().max().unwrap() - *requested_at.iter().min().unwrap();
+        assert!(elapsed >= Duration::from_millis(400), "{elapsed:?}");
+
+        // hosts are forgotten once their interval is over
+        let mut rate_limiter = RateLimiter {
+            interval: Some(Duration::from_millis(10)),
+            ..Default::default()
+        };
+        rate_limiter.reserve("a");
+        sleep(Duration::from_millis(20));
+        rate_limiter.reserve("b");
+        assert_eq!(
+            rate_limiter.next_request_at.keys().collect::<Vec<_>>(),
+            vec!["b"]
+        );
+    }
+
+    #[test]
+    fn test_client_rate_limit() {
+        let server = MockServer::start(&[("/chapter", 200, "ok")]);
+        let client = ClientConfig {
+            rate_limit: RateLimit {
+                min_interval: Duration::from_millis(100),
+            },
+            ..Default::default()
+        }
+        .build()
+        .unwrap();
+        let start = Instant::now();
+        for _ in 0..4 {
+            client.get(server.url("/chapter")).unwrap();
+        }
+        // the first request is not delayed
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(300), "{elapsed:?}");
+
+        // clones share the limit, other clients don't
+        let start = Instant::now();
+        client.clone().get(server.url("/chapter")).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        let start = Instant::now();
+        get(server.url("/chapter")).unwrap();
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
 
     #[test]
     fn test_fibonacci() {
@@ -73,4 +2023,302 @@ mod tests {
             vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]
         );
     }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("1. Good Morning Brother"), "1-good-morning-brother");
+        assert_eq!(slugify("AC/DC: Back in Black"), "ac-dc-back-in-black");
+        assert_eq!(slugify("../../etc/passwd"), "etc-passwd");
+        assert_eq!(slugify("C:\\Windows\\System32"), "c-windows-system32");
+        assert_eq!(slugify("  Épisode 3 — L'été  "), "épisode-3-l-été");
+        assert_eq!(
+            slugify("転生したらスライムだった件: 第1話"),
+            "転生したらスライムだった件-第1話"
+        );
+        assert_eq!(slugify("???"), "untitled");
+        assert_eq!(slugify("CON"), "con-");
+        assert_eq!(slugify("lpt1"), "lpt1-");
+        assert_eq!(slugify("Console"), "console");
+
+        let long = slugify(&"長い".repeat(100));
+        assert!(long.len() <= MAX_SLUG_LENGTH);
+        assert!(long.chars().all(|c| c == '長' || c == 'い'));
+        let long = slugify(&"a ".repeat(150));
+        assert!(long.len() <= MAX_SLUG_LENGTH);
+        assert!(!long.ends_with('-'));
+    }
+
+    #[test]
+    fn test_minify_html() {
+        assert_eq!(
+            minify_html("<div class=\"\">\n  <p id=\"a\">Some   <b>bold</b> <i>text</i></p>\n  <!-- a comment -->\n  <p>Next</p>\n</div>"),
+            "<div><p id=\"a\">Some <b>bold</b> <i>text</i></p><p>Next</p></div>"
+        );
+        assert_eq!(
+            minify_html("<details open=\"\"><summary>S</summary>x</details><br>"),
+            "<details open><summary>S</summary>x</details><br>"
+        );
+        assert_eq!(
+            minify_html("<p>a</p>\n<pre>  keep\n    this </pre>"),
+            "<p>a</p><pre>  keep\n    this </pre>"
+        );
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let date = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+        assert_eq!(
+            parse_date("2023-11-14T22:13:20+00:00").unwrap(),
+            date("2023-11-14T22:13:20Z")
+        );
+        assert_eq!(
+            parse_date("2017-09-21T00:37:43.0000000Z").unwrap(),
+            date("2017-09-21T00:37:43Z")
+        );
+        assert_eq!(
+            parse_date("2023-11-15T00:13:20+02:00").unwrap(),
+            date("2023-11-14T22:13:20Z")
+        );
+        assert_eq!(
+            parse_date("2021-10-17T08:09:31").unwrap(),
+            date("2021-10-17T08:09:31Z")
+        );
+        assert_eq!(
+            parse_date(" 2021-10-17 08:09:31 ").unwrap(),
+            date("2021-10-17T08:09:31Z")
+        );
+        assert_eq!(
+            parse_date("2021-10-17 08:09").unwrap(),
+            date("2021-10-17T08:09:00Z")
+        );
+        assert_eq!(
+            parse_date("2021-10-17").unwrap(),
+            date("2021-10-17T00:00:00Z")
+        );
+
+        let relative = |s: &str, delta: TimeDelta| {
+            let before = Utc::now();
+            let date = parse_date(s).unwrap();
+            let after = Utc::now();
+            assert!(
+                before - delta <= date && date <= after - delta,
+                "{s:?} parsed as {date}"
+            );
+        };
+        relative("3 days ago", TimeDelta::days(3));
+        relative("An hour ago", TimeDelta::hours(1));
+        relative("1 week ago", TimeDelta::weeks(1));
+        relative("yesterday", TimeDelta::days(1));
+        relative("just now", TimeDelta::zero());
+
+        for invalid in ["", "not_found", "3 fortnights ago", "2021-13-45"] {
+            assert!(
+                matches!(parse_date(invalid), Err(BackendError::DateParseError(_))),
+                "{invalid:?} should not be parsed"
+            );
+        }
+        for too_far in [
+            "99999999999 years ago",
+            // wraps to 1 when truncated to 32 bits
+            "4294967297 days ago",
+            "99999999999999999999999 seconds ago",
+            "2147483647 years ago",
+        ] {
+            assert!(
+                matches!(parse_date(too_far), Err(BackendError::ParseError(..))),
+                "{too_far:?} should not be parsed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_language() {
+        let page = Html::parse_document(r#"<html lang="pt_BR"><body></body></html>"#);
+        assert_eq!(declared_language(&page), Some("pt-BR".to_string()));
+        assert_eq!(declared_language(&Html::parse_document("<p>Hi</p>")), None);
+
+        assert_eq!(guess_language("Once upon a time"), None);
+        assert_eq!(guess_language("很久很久以前，有一个国王。"), Some("zh"));
+        assert_eq!(
+            guess_language("むかしむかし、ある所に王様がいました。"),
+            Some("ja")
+        );
+        assert_eq!(guess_language("옛날 옛적에 왕이 살았습니다."), Some("ko"));
+        assert_eq!(guess_language("Μια φορά κι έναν καιρό"), Some("el"));
+        assert_eq!(guess_language("Жил-был король, Mr. Smith."), None);
+        assert_eq!(guess_language("روزی روزگاری پادشاهی بود"), None);
+    }
+
+    #[test]
+    fn test_strip_navigation() {
+        let html = indoc! {r#"
+            <nav><a href="/fiction">Index</a></nav>
+            <p>She remembered the <a href="/fiction/chapter-1">previous chapter</a> of her life.</p>
+            <p>See <a href="https://example.com/map">the map</a>.</p>
+            <p><a href="/fiction/chapter-1">« Previous Chapter</a> | <a href="/fiction">Table of Contents</a> | <a href="/fiction/chapter-3">Next Chapter »</a></p>"#};
+        assert_eq!(
+            strip_navigation(html),
+            indoc! {r#"
+                <p>She remembered the <a href="/fiction/chapter-1">previous chapter</a> of her life.</p>
+                <p>See <a href="https://example.com/map">the map</a>.</p>"#}
+        );
+        let without_navigation = r#"<p><a href="/fiction/chapter-3">Next</a> time, then.</p>"#;
+        assert_eq!(strip_navigation(without_navigation), without_navigation);
+    }
+
+    #[test]
+    fn test_strip_phrases() {
+        let html = indoc! {r#"
+            <p class="cjk">Did you know this story is from Royal Road?  Read the official version.</p>
+            <p>Did you know this story is from Royal Road? Not a notice, just a question.</p>
+            <p>A case of theft:&nbsp;report it.</p>"#};
+        assert_eq!(
+            strip_phrases(
+                html,
+                &[
+                    "Did you know this story is from Royal Road? Read the official version.",
+                    "A case of theft: report it.",
+                ]
+            ),
+            "<p>Did you know this story is from Royal Road? Not a notice, just a question.</p>"
+        );
+        assert_eq!(strip_phrases(html, &["Something else"]), html);
+    }
+
+    #[test]
+    fn test_normalize_typography() {
+        let html = "<p>&#8216;Wait&#8217; &mdash; he said &ndash; \u{201c}it\u{2019}s <em>\"mine\"</em>\u{201d}</p>\n<pre>\u{2019}--\u{2019}</pre>";
+        assert_eq!(
+            normalize_typography(html, TypographyOptions::default()),
+            "<p>\u{2018}Wait\u{2019} \u{2014} he said \u{2013} \u{201c}it\u{2019}s <em>\"mine\"</em>\u{201d}</p>\n<pre>\u{2019}--\u{2019}</pre>"
+        );
+        let straight = TypographyOptions {
+            quotes: QuoteStyle::Straight,
+            dashes: DashStyle::Ascii,
+        };
+        assert_eq!(
+            normalize_typography(html, straight),
+            "<p>'Wait' --- he said -- \"it's <em>\"mine\"</em>\"</p>\n<pre>\u{2019}--\u{2019}</pre>"
+        );
+        let curly = TypographyOptions {
+            quotes: QuoteStyle::Curly,
+            dashes: DashStyle::Unicode,
+        };
+        assert_eq!(
+            normalize_typography(&normalize_typography(html, straight), curly),
+            "<p>\u{2018}Wait\u{2019} \u{2014} he said \u{2013} \u{201c}it\u{2019}s <em>\u{201c}mine\u{201d}</em>\u{201d}</p>\n<pre>\u{2019}--\u{2019}</pre>"
+        );
+    }
+
+    #[test]
+    fn test_retry() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        };
+        let mut calls = 0;
+        let result = retry(
+            || {
+                calls += 1;
+                if calls < 3 {
+                    Err(BackendError::UrlNotFound)
+                } else {
+                    Ok(calls)
+                }
+            },
+            &RetryPolicy {
+                max_attempts: 5,
+                ..policy
+            },
+        );
+        // UrlNotFound is not retryable
+        assert!(matches!(result, Err(BackendError::UrlNotFound)));
+        assert_eq!(calls, 1);
+
+        let transient = || BackendError::RequestFailed {
+            message: "Could not fetch".to_string(),
+            status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            content: String::new(),
+        };
+        calls = 0;
+        let result = retry(
+            || {
+                calls += 1;
+                if calls < 3 {
+                    Err(transient())
+                } else {
+                    Ok(calls)
+                }
+            },
+            &policy,
+        );
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls, 3);
+
+        // gives up after max_attempts
+        calls = 0;
+        let result: Result<(), _> = retry(
+            || {
+                calls += 1;
+                Err(transient())
+            },
+            &policy,
+        );
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_extract_readable() {
+        let page = indoc! {r#"
+            <html><head><title>Chapter 12</title><script>var ads = [];</script></head>
+            <body>
+              <header><h1>Some Novel Site</h1></header>
+              <nav class="navbar"><ul>
+                <li><a href="/">Home</a></li><li><a href="/latest">Latest releases, updated daily</a></li>
+              </ul></nav>
+              <div id="wrapper">
+                <aside class="sidebar">
+                  <p>Popular this week, hand-picked by our editors, just for you:</p>
+                  <ul><li><a href="/novel/1">Another novel</a></li></ul>
+                </aside>
+                <div class="chapter-body">
+                  <h3>Chapter 12: The Bridge</h3>
+                  <p>The bridge creaked under their weight, but it held, at least for now.</p>
+                  <div class="ads-banner"><p>Support us on our Patreon, it really helps a lot!</p></div>
+                  <p>On the other side, the forest was silent, dark, and waiting for them.</p>
+                </div>
+                <div class="comments"><p>Great chapter, thanks for the translation, waiting for more!</p></div>
+              </div>
+              <footer><p>Copyright 2024, all rights reserved, do not copy this site.</p></footer>
+            </body></html>"#};
+        let content = extract_readable(page);
+        assert!(content.starts_with("<h3>Chapter 12: The Bridge</h3>"));
+        assert!(content.contains("<p>The bridge creaked under their weight"));
+        assert!(content.ends_with("waiting for them.</p>"));
+        for unwanted in [
+            "Home",
+            "Popular",
+            "Patreon",
+            "Great chapter",
+            "Copyright",
+            "ads",
+        ] {
+            assert!(!content.contains(unwanted), "{unwanted} in {content}");
+        }
+        assert_eq!(
+            extract_readable("<html><body><nav>Home</nav></body></html>"),
+            ""
+        );
+    }
 }